@@ -0,0 +1,88 @@
+use bevy_ecs::{
+    component::Component,
+    schedule::{ExecutorKind, Schedule},
+    system::Query,
+    world::World,
+};
+use criterion::Criterion;
+
+#[derive(Component)]
+struct A(f32);
+#[derive(Component)]
+struct B(f32);
+#[derive(Component)]
+struct C(f32);
+#[derive(Component)]
+struct D(f32);
+
+const EXECUTORS: [(&str, ExecutorKind); 4] = [
+    ("SingleThreaded", ExecutorKind::SingleThreaded),
+    ("Simple", ExecutorKind::Simple),
+    ("MultiThreaded", ExecutorKind::MultiThreaded),
+    ("JobGraph", ExecutorKind::JobGraph),
+];
+
+const SYSTEM_AMOUNTS: [usize; 4] = [10, 50, 100, 500];
+
+pub fn executors_independent_systems(criterion: &mut Criterion) {
+    fn empty() {}
+    let mut world = World::new();
+    let mut group = criterion.benchmark_group("executors/independent_systems");
+    group.warm_up_time(std::time::Duration::from_millis(500));
+    group.measurement_time(std::time::Duration::from_secs(3));
+    for (name, kind) in EXECUTORS {
+        for amount in SYSTEM_AMOUNTS {
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(kind);
+            for _ in 0..amount {
+                schedule.add_systems(empty);
+            }
+            // run once to initialize systems
+            schedule.run(&mut world);
+            group.bench_function(format!("{name}/{amount:03}_systems"), |bencher| {
+                bencher.iter(|| {
+                    schedule.run(&mut world);
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+pub fn executors_conflicting_systems(criterion: &mut Criterion) {
+    fn ab(mut q: Query<(&mut A, &B)>) {
+        q.iter_mut().for_each(|(mut a, b)| a.0 += b.0);
+    }
+    fn ba(mut q: Query<(&A, &mut B)>) {
+        q.iter_mut().for_each(|(a, mut b)| b.0 += a.0);
+    }
+    fn cd(mut q: Query<(&mut C, &D)>) {
+        q.iter_mut().for_each(|(mut c, d)| c.0 += d.0);
+    }
+    fn dc(mut q: Query<(&C, &mut D)>) {
+        q.iter_mut().for_each(|(c, mut d)| d.0 += c.0);
+    }
+    let mut world = World::new();
+    world.spawn_batch((0..100).map(|_| (A(0.0), B(1.0))));
+    world.spawn_batch((0..100).map(|_| (C(0.0), D(1.0))));
+    let mut group = criterion.benchmark_group("executors/conflicting_systems");
+    group.warm_up_time(std::time::Duration::from_millis(500));
+    group.measurement_time(std::time::Duration::from_secs(3));
+    for (name, kind) in EXECUTORS {
+        for amount in SYSTEM_AMOUNTS {
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(kind);
+            for _ in 0..amount / 4 {
+                schedule.add_systems((ab, ba, cd, dc));
+            }
+            // run once to initialize systems
+            schedule.run(&mut world);
+            group.bench_function(format!("{name}/{amount:03}_systems"), |bencher| {
+                bencher.iter(|| {
+                    schedule.run(&mut world);
+                });
+            });
+        }
+    }
+    group.finish();
+}
@@ -1,9 +1,11 @@
 use criterion::criterion_group;
 
+mod executors;
 mod run_condition;
 mod running_systems;
 mod schedule;
 
+use executors::*;
 use run_condition::*;
 use running_systems::*;
 use schedule::*;
@@ -20,4 +22,6 @@ criterion_group!(
     schedule,
     build_schedule,
     empty_schedule_run,
+    executors_independent_systems,
+    executors_conflicting_systems,
 );
@@ -141,5 +141,11 @@ pub fn empty_schedule_run(criterion: &mut Criterion) {
     group.bench_function("Simple", |bencher| {
         bencher.iter(|| schedule.run(app.world_mut()));
     });
+
+    let mut schedule = Schedule::default();
+    schedule.set_executor_kind(bevy_ecs::schedule::ExecutorKind::JobGraph);
+    group.bench_function("JobGraph", |bencher| {
+        bencher.iter(|| schedule.run(app.world_mut()));
+    });
     group.finish();
 }
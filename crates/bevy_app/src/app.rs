@@ -4,10 +4,10 @@ use crate::{
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventRetention},
     intern::Interned,
     prelude::*,
-    schedule::{ScheduleBuildSettings, ScheduleLabel},
+    schedule::{ScheduleBuildError, ScheduleBuildSettings, ScheduleGraphExport, ScheduleLabel},
     system::{IntoObserverSystem, SystemId},
 };
 #[cfg(feature = "trace")]
@@ -342,6 +342,35 @@ impl App {
         self
     }
 
+    /// Initializes `T` as a consumable event by inserting an [`EventChannel::<T>`](bevy_ecs::event::EventChannel)
+    /// resource with the given [`EventRetention`], and updating it in [`First`] along with the other events.
+    ///
+    /// Unlike [`add_event`](Self::add_event), readers of an event channel are ordered by priority
+    /// and can consume events so that lower priority readers don't see them.
+    /// Use [`EventChannelReader`](bevy_ecs::event::EventChannelReader) and
+    /// [`EventChannelWriter`](bevy_ecs::event::EventChannelWriter) to access it from systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::event::EventRetention;
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_event_channel::<MyEvent>(EventRetention::UntilRead);
+    /// ```
+    pub fn add_event_channel<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        self.main_mut().add_event_channel::<T>(retention);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -705,6 +734,30 @@ impl App {
         self
     }
 
+    /// Builds the [`Schedule`] associated with `label` if needed, and exports its system graph
+    /// to debug or visualize the order of its systems.
+    ///
+    /// See [`Schedule::export_graph`] for more details.
+    ///
+    /// ```no_run
+    /// # use bevy_app::prelude::*;
+    /// let mut app = App::new();
+    /// // add plugins and systems...
+    ///
+    /// let export = app.export_schedule_graph(Update).unwrap();
+    /// std::fs::write("update.dot", export.to_dot()).unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule does not exist.
+    pub fn export_schedule_graph(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> Result<ScheduleGraphExport, ScheduleBuildError> {
+        self.main_mut().export_schedule_graph(label)
+    }
+
     /// Applies the provided [`ScheduleBuildSettings`] to all schedules.
     pub fn configure_schedules(
         &mut self,
@@ -937,7 +990,7 @@ mod tests {
         change_detection::{DetectChanges, ResMut},
         component::Component,
         entity::Entity,
-        event::{Event, EventWriter, Events},
+        event::{Event, EventChannel, EventRetention, EventWriter, Events},
         query::With,
         removal_detection::RemovedComponents,
         schedule::{IntoSystemConfigs, ScheduleLabel},
@@ -1302,6 +1355,28 @@ mod tests {
 
         App::new().add_plugins(Foo);
     }
+    #[test]
+    fn event_channels_should_be_updated_every_update() {
+        #[derive(Event, Clone)]
+        struct TestEvent;
+
+        let mut app = App::new();
+        app.add_event_channel::<TestEvent>(EventRetention::Frames(3));
+
+        app.world_mut()
+            .resource_mut::<EventChannel<TestEvent>>()
+            .send(TestEvent);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<EventChannel<TestEvent>>().len(), 1);
+
+        // the channel was left unchanged, but still counts frames
+        app.update();
+        let channel = app.world().resource::<EventChannel<TestEvent>>();
+        assert!(channel.is_empty());
+        assert_eq!(channel.diagnostics().read, 1);
+    }
+
     #[test]
     fn events_should_be_updated_once_per_update() {
         #[derive(Event, Clone)]
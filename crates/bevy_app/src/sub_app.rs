@@ -1,8 +1,11 @@
 use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventRetention},
     prelude::*,
-    schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        InternedScheduleLabel, ScheduleBuildError, ScheduleBuildSettings, ScheduleGraphExport,
+        ScheduleLabel,
+    },
     system::SystemId,
 };
 
@@ -257,6 +260,15 @@ impl SubApp {
         self
     }
 
+    /// See [`App::export_schedule_graph`].
+    pub fn export_schedule_graph(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> Result<ScheduleGraphExport, ScheduleBuildError> {
+        self.world
+            .schedule_scope(label, |world, schedule| schedule.export_graph(world))
+    }
+
     /// See [`App::configure_schedules`].
     pub fn configure_schedules(
         &mut self,
@@ -312,6 +324,16 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_channel`].
+    pub fn add_event_channel<T>(&mut self, retention: EventRetention) -> &mut Self
+    where
+        T: Event,
+    {
+        EventRegistry::register_channel::<T>(self.world_mut(), retention);
+
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         self.run_as_app(|app| plugins.add_to_app(app));
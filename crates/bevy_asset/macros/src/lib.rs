@@ -5,13 +5,14 @@
 use bevy_macro_utils::BevyManifest;
 use proc_macro::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Path};
+use syn::{parse_macro_input, Data, DeriveInput, LitStr, Path};
 
 pub(crate) fn bevy_asset_path() -> Path {
     BevyManifest::default().get_path("bevy_asset")
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
 
 #[proc_macro_derive(Asset, attributes(dependency))]
 pub fn derive_asset(input: TokenStream) -> TokenStream {
@@ -41,6 +42,102 @@ pub fn derive_asset_dependency_visitor(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// How a field of an asset collection is loaded.
+enum CollectionField {
+    Path(LitStr),
+    Folder(LitStr),
+    Default,
+}
+
+fn parse_collection_field(field: &syn::Field) -> Result<CollectionField, syn::Error> {
+    let mut kind = CollectionField::Default;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !matches!(kind, CollectionField::Default) {
+                return Err(meta.error("an asset can only have one path or folder"));
+            }
+            if meta.path.is_ident("path") {
+                kind = CollectionField::Path(meta.value()?.parse()?);
+            } else if meta.path.is_ident("folder") {
+                kind = CollectionField::Folder(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `path` or `folder`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(kind)
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs",
+        ));
+    };
+
+    let mut loads = Vec::new();
+    let mut visitors = Vec::new();
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        };
+        let load = match parse_collection_field(field)? {
+            CollectionField::Path(path) => quote!(asset_server.load(#path)),
+            CollectionField::Folder(path) => quote!(asset_server.load_folder(#path)),
+            CollectionField::Default => {
+                loads.push(quote!(#member: ::core::default::Default::default()));
+                continue;
+            }
+        };
+        loads.push(quote!(#member: #load));
+        visitors.push(
+            quote!(visit(::core::convert::Into::into(::core::clone::Clone::clone(&self.#member)));),
+        );
+    }
+
+    // prevent unused variable warning in case there are no assets
+    let visit = if visitors.is_empty() {
+        quote! { _visit }
+    } else {
+        quote! { visit }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self { #(#loads,)* }
+            }
+
+            fn visit_handles(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedHandle)) {
+                #(#visitors)*
+            }
+        }
+    })
+}
+
 fn derive_dependency_visitor_internal(
     ast: &DeriveInput,
     bevy_asset_path: &Path,
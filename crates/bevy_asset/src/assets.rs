@@ -1,5 +1,8 @@
 use crate::{self as bevy_asset};
-use crate::{Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer, Handle, UntypedHandle};
+use crate::{
+    server::AssetInfos, Asset, AssetCache, AssetEvent, AssetHandleProvider, AssetId, AssetServer,
+    Handle, UntypedHandle,
+};
 use bevy_ecs::{
     prelude::EventWriter,
     system::{Res, ResMut, Resource},
@@ -292,6 +295,8 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    /// Keeps unused assets loaded, if enabled.
+    cache: Option<AssetCache<A>>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -305,6 +310,7 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            cache: None,
         }
     }
 }
@@ -540,12 +546,33 @@ impl<A: Asset> Assets<A> {
         // re-loads are kicked off appropriately. This function must be "transactional" relative
         // to other asset info operations
         let mut infos = asset_server.data.infos.write();
-        while let Ok(drop_event) = assets.handle_provider.drop_receiver.try_recv() {
+        assets.process_drop_events(&mut infos);
+
+        if let Some(mut cache) = assets.cache.take() {
+            cache.evict(assets);
+            assets.cache = Some(cache);
+            // unload the evicted assets right away
+            assets.process_drop_events(&mut infos);
+        }
+    }
+
+    fn process_drop_events(&mut self, infos: &mut AssetInfos) {
+        while let Ok(drop_event) = self.handle_provider.drop_receiver.try_recv() {
             let id = drop_event.id.typed();
 
             if drop_event.asset_server_managed {
                 let untyped_id = id.untyped();
 
+                if let Some(cache) = &mut self.cache {
+                    // instead of unloading the asset, keep it alive with a new handle
+                    if !cache.evicted.remove(&id) {
+                        if let Some(handle) = infos.revive_dropped_handle(untyped_id) {
+                            cache.insert(id, handle);
+                            continue;
+                        }
+                    }
+                }
+
                 // the process_handle_drop call checks whether new handles have been created since the drop event was fired, before removing the asset
                 if !infos.process_handle_drop(untyped_id) {
                     // a new handle has been created, or the asset doesn't exist
@@ -553,11 +580,32 @@ impl<A: Asset> Assets<A> {
                 }
             }
 
-            assets.queued_events.push(AssetEvent::Unused { id });
-            assets.remove_dropped(id);
+            self.queued_events.push(AssetEvent::Unused { id });
+            self.remove_dropped(id);
         }
     }
 
+    /// Sets the [`AssetCache`] that keeps unused assets of this type loaded, or disables caching
+    /// if `cache` is `None`.
+    ///
+    /// Unused assets kept by a previous cache are moved to the new one, or unloaded if caching is disabled.
+    pub fn set_cache(&mut self, cache: Option<AssetCache<A>>) {
+        let previous = std::mem::replace(&mut self.cache, cache);
+        if let (Some(previous), Some(cache)) = (previous, &mut self.cache) {
+            cache.take_entries(previous);
+        }
+    }
+
+    /// Returns the [`AssetCache`] that keeps unused assets of this type loaded, if any.
+    pub fn cache(&self) -> Option<&AssetCache<A>> {
+        self.cache.as_ref()
+    }
+
+    /// Returns the [`AssetCache`] that keeps unused assets of this type loaded, if any.
+    pub fn cache_mut(&mut self) -> Option<&mut AssetCache<A>> {
+        self.cache.as_mut()
+    }
+
     /// A system that applies accumulated asset change events to the [`Events`] resource.
     ///
     /// [`Events`]: bevy_ecs::event::Events
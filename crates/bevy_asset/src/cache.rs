@@ -0,0 +1,152 @@
+use crate::{Asset, AssetId, Assets, StrongHandle};
+use bevy_utils::HashSet;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Keeps unused assets of one type loaded until the assets of that type use more memory than a
+/// budget, at which point the least recently used ones are unloaded.
+///
+/// Without a cache, an asset is unloaded as soon as its last strong [`Handle`](crate::Handle) is
+/// dropped, so assets that are briefly unused, for example while streaming levels, are loaded
+/// again and again. With a cache, such assets are kept and loading their path with the
+/// [`AssetServer`](crate::AssetServer) again returns a handle to the loaded asset right away.
+///
+/// Only assets loaded by the [`AssetServer`](crate::AssetServer) are cached, because assets added
+/// directly to [`Assets`] can't be requested again once all of their handles are dropped.
+///
+/// The memory an asset uses is estimated by a function given to
+/// [`with_size_estimate`](Self::with_size_estimate), which defaults to the size of the asset
+/// type itself. The estimates are updated once per frame.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::{Asset, AssetApp, AssetCache};
+/// # use bevy_reflect::TypePath;
+/// #[derive(Asset, TypePath)]
+/// struct Level {
+///     tiles: Vec<u8>,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((bevy_core::TaskPoolPlugin::default(), bevy_asset::AssetPlugin::default()));
+/// app.init_asset::<Level>().set_asset_cache(
+///     AssetCache::<Level>::new(64 * 1024 * 1024).with_size_estimate(|level| level.tiles.len()),
+/// );
+/// ```
+pub struct AssetCache<A: Asset> {
+    budget: usize,
+    size_estimate: fn(&A) -> usize,
+    /// The unused assets, from least to most recently used.
+    entries: VecDeque<(AssetId<A>, Arc<StrongHandle>)>,
+    /// Assets whose cached handle was dropped, which must be unloaded instead of cached again.
+    pub(crate) evicted: HashSet<AssetId<A>>,
+    usage: AssetMemoryUsage,
+}
+
+impl<A: Asset> AssetCache<A> {
+    /// Creates a cache that unloads unused assets while the assets of this type use more than
+    /// `budget` bytes.
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            size_estimate: |_| std::mem::size_of::<A>(),
+            entries: VecDeque::new(),
+            evicted: HashSet::new(),
+            usage: AssetMemoryUsage::default(),
+        }
+    }
+
+    /// Estimates the memory used by an asset with `size_estimate`, in bytes.
+    pub fn with_size_estimate(mut self, size_estimate: fn(&A) -> usize) -> Self {
+        self.size_estimate = size_estimate;
+        self
+    }
+
+    /// Returns the memory budget of this asset type, in bytes.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Sets the memory budget of this asset type, in bytes. Unused assets over the budget are
+    /// unloaded during the next update.
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+    }
+
+    /// Returns the estimated memory usage of this asset type as of the last update.
+    pub fn usage(&self) -> AssetMemoryUsage {
+        self.usage
+    }
+
+    /// Returns the number of unused assets kept by this cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this cache doesn't keep any unused asset.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the asset with the given `id` is unused and kept by this cache.
+    pub fn contains(&self, id: impl Into<AssetId<A>>) -> bool {
+        let id = id.into();
+        self.entries.iter().any(|(entry, _)| *entry == id)
+    }
+
+    /// Moves the assets kept by `previous` to this cache.
+    pub(crate) fn take_entries(&mut self, previous: Self) {
+        self.entries.extend(previous.entries);
+        self.evicted.extend(previous.evicted);
+    }
+
+    /// Keeps an asset whose last handle was dropped, using a new strong `handle` to it.
+    pub(crate) fn insert(&mut self, id: AssetId<A>, handle: Arc<StrongHandle>) {
+        self.entries.push_back((id, handle));
+    }
+
+    /// Updates the memory usage and unloads the least recently used assets while over budget.
+    pub(crate) fn evict(&mut self, assets: &Assets<A>) {
+        // Assets that are used again are no longer cached. When they become unused again, they
+        // are cached as the most recently used asset.
+        self.entries
+            .retain(|(_, handle)| Arc::strong_count(handle) == 1);
+
+        let mut usage = AssetMemoryUsage {
+            total_bytes: assets
+                .iter()
+                .map(|(_, asset)| (self.size_estimate)(asset))
+                .sum(),
+            ..Default::default()
+        };
+        let mut cached_bytes = Vec::with_capacity(self.entries.len());
+        for (id, _) in &self.entries {
+            let bytes = assets.get(*id).map_or(0, self.size_estimate);
+            cached_bytes.push(bytes);
+            usage.cached_bytes += bytes;
+        }
+
+        let mut evicted = 0;
+        while usage.total_bytes > self.budget && evicted < cached_bytes.len() {
+            usage.total_bytes -= cached_bytes[evicted];
+            usage.cached_bytes -= cached_bytes[evicted];
+            evicted += 1;
+        }
+        // Dropping the handles sends drop events, which are marked to be unloaded.
+        for (id, _) in self.entries.drain(..evicted) {
+            self.evicted.insert(id);
+        }
+        usage.cached_assets = self.entries.len();
+        self.usage = usage;
+    }
+}
+
+/// The estimated memory usage of an asset type with an [`AssetCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetMemoryUsage {
+    /// The memory used by all assets of this type, in bytes.
+    pub total_bytes: usize,
+    /// The memory used by unused assets that are kept by the cache, in bytes.
+    pub cached_bytes: usize,
+    /// The number of unused assets kept by the cache.
+    pub cached_assets: usize,
+}
@@ -0,0 +1,74 @@
+//! Diagnostics for the memory usage of assets.
+
+use crate::{Asset, Assets};
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::Res;
+use std::marker::PhantomData;
+
+/// Adds the estimated memory usage of the asset type `A` to an App.
+///
+/// The measurements are taken from the [`AssetCache`](crate::AssetCache) of the asset type, so
+/// nothing is measured unless one is set with [`AssetApp::set_asset_cache`](crate::AssetApp::set_asset_cache).
+///
+/// The diagnostics are named after the short type path of the asset type, for example
+/// `assets/Image/total_bytes`.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](bevy_diagnostic::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct AssetCacheDiagnosticsPlugin<A: Asset> {
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Asset> Default for AssetCacheDiagnosticsPlugin<A> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset> Plugin for AssetCacheDiagnosticsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::total_bytes_path()).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::cached_bytes_path()).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::cached_assets_path()))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+impl<A: Asset> AssetCacheDiagnosticsPlugin<A> {
+    /// The memory used by all assets of type `A`.
+    pub fn total_bytes_path() -> DiagnosticPath {
+        Self::path("total_bytes")
+    }
+
+    /// The memory used by unused assets of type `A` that are kept by the cache.
+    pub fn cached_bytes_path() -> DiagnosticPath {
+        Self::path("cached_bytes")
+    }
+
+    /// The number of unused assets of type `A` that are kept by the cache.
+    pub fn cached_assets_path() -> DiagnosticPath {
+        Self::path("cached_assets")
+    }
+
+    fn path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["assets", A::short_type_path(), name])
+    }
+
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, assets: Res<Assets<A>>) {
+        let Some(cache) = assets.cache() else {
+            return;
+        };
+        let usage = cache.usage();
+        diagnostics.add_measurement(&Self::total_bytes_path(), || usage.total_bytes as f64);
+        diagnostics.add_measurement(&Self::cached_bytes_path(), || usage.cached_bytes as f64);
+        diagnostics.add_measurement(&Self::cached_assets_path(), || usage.cached_assets as f64);
+    }
+}
@@ -0,0 +1,663 @@
+//! An [`AssetReader`] that serves assets out of zip and pak archives.
+//!
+//! Shipping a game as tens of thousands of loose files makes it slow to install and to patch.
+//! [`ArchiveAssetReader`] instead reads assets out of one or more [`Archive`]s, which are layered by
+//! priority: when several archives contain the same path, the one with the highest priority wins.
+//! This makes it possible to ship a base archive and override some of its assets with patches,
+//! DLC or mods without rebuilding it.
+//!
+//! Two archive formats are supported:
+//! - Zip archives, with stored (uncompressed) entries, and deflate-compressed entries when the
+//!   `zip_deflate` feature is enabled.
+//! - Pak archives, a simple uncompressed format that can be written with [`PakWriter`].
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSource, archive::{Archive, ArchiveAssetReader}}};
+//! let reader = ArchiveAssetReader::new()
+//!     .with_archive(Archive::open("assets.pak").unwrap(), 0)
+//!     .with_archive(Archive::open("patch_1.zip").unwrap(), 10);
+//!
+//! App::new().register_asset_source(
+//!     "pak",
+//!     AssetSource::build().with_reader(move || Box::new(reader.clone())),
+//! );
+//! // Assets can now be loaded with paths like `pak://textures/player.png`.
+//! ```
+
+mod pak;
+mod zip;
+
+pub use pak::PakWriter;
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, PathStream, Reader, SliceReader, VecReader,
+};
+use bevy_utils::{HashMap, HashSet};
+use std::{
+    io::{Cursor, Read, Seek},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// Errors that occur while opening an [`Archive`].
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    /// Encountered an I/O error while reading the archive.
+    #[error("encountered an io error while reading the archive: {0}")]
+    Io(#[from] std::io::Error),
+    /// The data is neither a zip nor a pak archive.
+    #[error("the data is neither a zip nor a pak archive")]
+    UnknownFormat,
+    /// The archive is truncated or its index is corrupted.
+    #[error("the archive is malformed: {0}")]
+    Malformed(&'static str),
+    /// The archive uses a pak format version this version of Bevy can't read.
+    #[error("unsupported pak version {0}")]
+    UnsupportedVersion(u32),
+    /// An entry of the archive is compressed with a method that can't be read.
+    #[error("entry {path:?} uses unsupported compression method {method}")]
+    UnsupportedCompression {
+        /// The path of the entry.
+        path: PathBuf,
+        /// The zip compression method of the entry.
+        method: u16,
+    },
+    /// An entry of the archive is encrypted.
+    #[error("entry {0:?} is encrypted")]
+    Encrypted(PathBuf),
+}
+
+/// The format of an [`Archive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// A zip archive.
+    Zip,
+    /// A pak archive, as written by [`PakWriter`].
+    Pak,
+}
+
+/// How the bytes of an archive entry are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    Stored,
+    #[cfg(feature = "zip_deflate")]
+    Deflate,
+}
+
+/// The location of a single file in an archive.
+#[derive(Debug, Clone, Copy)]
+struct ArchiveEntry {
+    /// The offset of the data, or of the local file header for zip archives.
+    offset: u64,
+    compressed_size: u64,
+    size: u64,
+    compression: Compression,
+}
+
+/// The files and directories of an archive.
+#[derive(Debug, Default)]
+struct ArchiveIndex {
+    entries: HashMap<PathBuf, ArchiveEntry>,
+    directories: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ArchiveIndex {
+    fn insert_file(&mut self, path: PathBuf, entry: ArchiveEntry) {
+        if self.entries.insert(path.clone(), entry).is_none() {
+            self.insert_parents(&path);
+        }
+    }
+
+    fn insert_directory(&mut self, path: PathBuf) {
+        if !self.directories.contains_key(&path) {
+            self.directories.insert(path.clone(), Vec::new());
+            self.insert_parents(&path);
+        }
+    }
+
+    /// Registers `path` as a child of its parent directory, creating parents as needed.
+    fn insert_parents(&mut self, mut path: &Path) {
+        while let Some(parent) = path.parent() {
+            let is_new = !self.directories.contains_key(parent);
+            self.directories
+                .entry(parent.to_path_buf())
+                .or_default()
+                .push(path.to_path_buf());
+            if !is_new {
+                break;
+            }
+            path = parent;
+        }
+    }
+}
+
+/// Where the bytes of an archive are read from.
+#[derive(Debug)]
+enum ArchiveData {
+    Memory(Arc<[u8]>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+struct ArchiveInner {
+    format: ArchiveFormat,
+    data: ArchiveData,
+    index: ArchiveIndex,
+}
+
+/// A zip or pak archive that assets can be read from with an [`ArchiveAssetReader`].
+///
+/// Opening an archive only reads its index. The contents of the files are read when they are loaded.
+/// This type is cheap to clone, as the index is shared.
+#[derive(Debug, Clone)]
+pub struct Archive(Arc<ArchiveInner>);
+
+impl Archive {
+    /// Opens the archive stored in the file at `path`. The format is detected from the contents of the file.
+    ///
+    /// This reads the index of the archive synchronously, so it is intended to be called during startup.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ArchiveError> {
+        let path = path.as_ref();
+        let mut file = std::fs::File::open(path)?;
+        let (format, index) = read_index(&mut file)?;
+        Ok(Self(Arc::new(ArchiveInner {
+            format,
+            data: ArchiveData::File(path.to_path_buf()),
+            index,
+        })))
+    }
+
+    /// Creates an archive from its bytes, for example from an archive embedded in the executable
+    /// or downloaded at runtime. The format is detected from the contents.
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self, ArchiveError> {
+        let bytes = bytes.into();
+        let (format, index) = read_index(&mut Cursor::new(&*bytes))?;
+        Ok(Self(Arc::new(ArchiveInner {
+            format,
+            data: ArchiveData::Memory(bytes),
+            index,
+        })))
+    }
+
+    /// Returns the format of this archive.
+    pub fn format(&self) -> ArchiveFormat {
+        self.0.format
+    }
+
+    /// Returns the number of files in this archive.
+    pub fn len(&self) -> usize {
+        self.0.index.entries.len()
+    }
+
+    /// Returns `true` if this archive contains no files.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if this archive contains a file at `path`.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.entry(path).is_some()
+    }
+
+    /// Returns `true` if this archive contains a directory at `path`.
+    /// The root of the archive is the empty path.
+    pub fn is_directory(&self, path: &Path) -> bool {
+        self.0.index.directories.contains_key(&normalize(path))
+    }
+
+    /// Returns an iterator over the paths of all files in this archive, in no particular order.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.index.entries.keys().map(PathBuf::as_path)
+    }
+
+    fn entry(&self, path: &Path) -> Option<&ArchiveEntry> {
+        self.0.index.entries.get(&normalize(path))
+    }
+
+    fn children(&self, path: &Path) -> Option<&[PathBuf]> {
+        self.0
+            .index
+            .directories
+            .get(&normalize(path))
+            .map(Vec::as_slice)
+    }
+
+    /// Reads the contents of `entry`.
+    async fn read_entry<'a>(
+        &'a self,
+        entry: &ArchiveEntry,
+    ) -> Result<Box<dyn Reader + 'a>, AssetReaderError> {
+        let offset = match self.0.format {
+            ArchiveFormat::Zip => {
+                let header = self.read_range(entry.offset, zip::LOCAL_HEADER_LEN).await?;
+                entry.offset + zip::local_header_len(&header)?
+            }
+            ArchiveFormat::Pak => entry.offset,
+        };
+
+        match (&self.0.data, entry.compression) {
+            (ArchiveData::Memory(bytes), Compression::Stored) => {
+                let range = slice_range(bytes, offset, entry.size)?;
+                Ok(Box::new(SliceReader::new(&bytes[range])))
+            }
+            // Only in-memory archives on the web without `zip_deflate` never get here.
+            #[allow(unreachable_patterns)]
+            (_, compression) => {
+                let bytes = self.read_range(offset, entry.compressed_size).await?;
+                let bytes = match compression {
+                    Compression::Stored => bytes,
+                    #[cfg(feature = "zip_deflate")]
+                    Compression::Deflate => zip::inflate(&bytes, entry.size)?,
+                };
+                Ok(Box::new(VecReader::new(bytes)))
+            }
+        }
+    }
+
+    /// Reads `len` bytes of the archive, starting at `offset`.
+    async fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>, AssetReaderError> {
+        match &self.0.data {
+            ArchiveData::Memory(bytes) => Ok(bytes[slice_range(bytes, offset, len)?].to_vec()),
+            #[cfg(not(target_arch = "wasm32"))]
+            ArchiveData::File(path) => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+                let mut file = async_fs::File::open(path).await?;
+                file.seek(std::io::SeekFrom::Start(offset)).await?;
+                let mut bytes = vec![0; to_usize(len)?];
+                file.read_exact(&mut bytes).await?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+/// Detects the format of an archive and reads its index.
+fn read_index(
+    reader: &mut (impl Read + Seek),
+) -> Result<(ArchiveFormat, ArchiveIndex), ArchiveError> {
+    if pak::is_pak(reader)? {
+        Ok((ArchiveFormat::Pak, pak::read_index(reader)?))
+    } else if let Some(index) = zip::read_index(reader)? {
+        Ok((ArchiveFormat::Zip, index))
+    } else {
+        Err(ArchiveError::UnknownFormat)
+    }
+}
+
+/// Turns a path from an archive or from an asset path into the form used as a key in the [`ArchiveIndex`].
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect()
+}
+
+fn to_usize(value: u64) -> Result<usize, AssetReaderError> {
+    usize::try_from(value).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "archive entry is too large to be read on this platform",
+        )
+        .into()
+    })
+}
+
+fn slice_range(
+    bytes: &[u8],
+    offset: u64,
+    len: u64,
+) -> Result<std::ops::Range<usize>, AssetReaderError> {
+    let start = to_usize(offset)?;
+    let end = start.saturating_add(to_usize(len)?);
+    if end > bytes.len() {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(start..end)
+}
+
+/// An [`AssetReader`] that reads assets from a stack of [`Archive`]s.
+///
+/// When several archives contain the same path, the archive with the highest priority is used.
+/// Directories are merged, so [`AssetServer::load_folder`](crate::AssetServer::load_folder) sees
+/// the files of every archive. Meta files are looked up in the same archive as their asset, as a
+/// `.meta` file next to it.
+///
+/// This type is cheap to clone, so it can be used to build an [`AssetSource`](crate::io::AssetSource)
+/// directly. See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveAssetReader {
+    /// The archives, from highest to lowest priority.
+    layers: Vec<(i32, Archive)>,
+}
+
+impl ArchiveAssetReader {
+    /// Creates a new [`ArchiveAssetReader`] without any archives.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `archive` to this reader with the given `priority` and returns the reader.
+    ///
+    /// See [`ArchiveAssetReader::add_archive`].
+    pub fn with_archive(mut self, archive: Archive, priority: i32) -> Self {
+        self.add_archive(archive, priority);
+        self
+    }
+
+    /// Adds `archive` to this reader with the given `priority`.
+    ///
+    /// Files in archives with a higher priority override the files with the same path in archives
+    /// with a lower priority. If two archives have the same priority, the one added last wins.
+    pub fn add_archive(&mut self, archive: Archive, priority: i32) {
+        let index = self
+            .layers
+            .iter()
+            .position(|(layer_priority, _)| *layer_priority <= priority)
+            .unwrap_or(self.layers.len());
+        self.layers.insert(index, (priority, archive));
+    }
+
+    /// Returns the archives of this reader, in the order they are searched.
+    pub fn archives(&self) -> impl Iterator<Item = &Archive> {
+        self.layers.iter().map(|(_, archive)| archive)
+    }
+
+    /// Returns the archive that `path` is read from, if any.
+    pub fn archive_for(&self, path: &Path) -> Option<&Archive> {
+        self.archives().find(|archive| archive.contains(path))
+    }
+
+    async fn read_file<'a>(
+        &'a self,
+        path: &Path,
+    ) -> Result<Box<dyn Reader + 'a>, AssetReaderError> {
+        for archive in self.archives() {
+            if let Some(entry) = archive.entry(path) {
+                return archive.read_entry(entry).await;
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_path_buf()))
+    }
+}
+
+impl AssetReader for ArchiveAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_file(path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let meta_path = get_meta_path(path);
+        // Only use the meta file of the archive the asset is read from, so that an archive overriding
+        // an asset without its meta file doesn't get the meta file of the overridden asset.
+        let Some((archive, entry)) = self
+            .archive_for(path)
+            .and_then(|archive| Some((archive, archive.entry(&meta_path)?)))
+        else {
+            return Err(AssetReaderError::NotFound(meta_path));
+        };
+        archive.read_entry(entry).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut seen = HashSet::new();
+        let mut paths = Vec::new();
+        for children in self.archives().filter_map(|archive| archive.children(path)) {
+            found = true;
+            for child in children {
+                // filter out meta files as they are not considered assets
+                if let Some(ext) = child.extension().and_then(|e| e.to_str()) {
+                    if ext.eq_ignore_ascii_case("meta") {
+                        continue;
+                    }
+                }
+                if seen.insert(child) {
+                    paths.push(child.clone());
+                }
+            }
+        }
+
+        if found {
+            let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(paths));
+            Ok(stream)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_path_buf()))
+        }
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.archives().any(|archive| archive.is_directory(path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, StreamExt};
+
+    fn read(reader: &ArchiveAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(bytes)
+        })
+    }
+
+    fn read_meta(reader: &ArchiveAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read_meta(Path::new(path)).await?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(bytes)
+        })
+    }
+
+    fn read_directory(reader: &ArchiveAssetReader, path: &str) -> Vec<PathBuf> {
+        block_on(async {
+            let mut paths: Vec<_> = reader
+                .read_directory(Path::new(path))
+                .await
+                .unwrap()
+                .collect()
+                .await;
+            paths.sort();
+            paths
+        })
+    }
+
+    /// Writes a zip archive with stored entries.
+    fn write_zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        write_zip_entries(
+            files
+                .iter()
+                .map(|&(name, data)| (name, 0, data.to_vec(), data.len())),
+        )
+    }
+
+    /// Writes a zip archive from `(name, compression method, data, uncompressed size)` entries.
+    fn write_zip_entries<'a>(
+        files: impl IntoIterator<Item = (&'a str, u16, Vec<u8>, usize)>,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut central_directory = Vec::new();
+        let mut count = 0u16;
+        for (name, method, data, size) in files {
+            let offset = bytes.len() as u32;
+            bytes.extend_from_slice(&0x04034b50u32.to_le_bytes());
+            bytes.extend_from_slice(&[20, 0, 0, 0]);
+            bytes.extend_from_slice(&method.to_le_bytes());
+            bytes.extend_from_slice(&[0; 8]);
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(size as u32).to_le_bytes());
+            bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&0u16.to_le_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(&data);
+
+            central_directory.extend_from_slice(&0x02014b50u32.to_le_bytes());
+            central_directory.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+            central_directory.extend_from_slice(&method.to_le_bytes());
+            central_directory.extend_from_slice(&[0; 8]);
+            central_directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(size as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            central_directory.extend_from_slice(&[0; 12]);
+            central_directory.extend_from_slice(&offset.to_le_bytes());
+            central_directory.extend_from_slice(name.as_bytes());
+            count += 1;
+        }
+        let central_directory_offset = bytes.len() as u32;
+        bytes.extend_from_slice(&central_directory);
+        bytes.extend_from_slice(&0x06054b50u32.to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&count.to_le_bytes());
+        bytes.extend_from_slice(&count.to_le_bytes());
+        bytes.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&central_directory_offset.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn zip_archive() {
+        let archive = Archive::from_bytes(write_zip(&[
+            ("a.txt", b"a"),
+            ("textures/", b""),
+            ("textures/b.png", b"b"),
+            ("textures/b.png.meta", b"b meta"),
+            ("textures/empty/", b""),
+        ]))
+        .unwrap();
+        assert_eq!(archive.format(), ArchiveFormat::Zip);
+        assert_eq!(archive.len(), 3);
+        assert!(archive.is_directory(Path::new("textures/empty")));
+
+        let reader = ArchiveAssetReader::new().with_archive(archive, 0);
+        assert_eq!(read(&reader, "a.txt").unwrap(), b"a");
+        assert_eq!(read(&reader, "./textures/b.png").unwrap(), b"b");
+        let meta = block_on(reader.read_meta_bytes(Path::new("textures/b.png"))).unwrap();
+        assert_eq!(meta, b"b meta");
+        assert_eq!(
+            read(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(PathBuf::from("missing.txt")))
+        );
+
+        assert_eq!(
+            read_directory(&reader, "textures"),
+            [
+                PathBuf::from("textures/b.png"),
+                PathBuf::from("textures/empty")
+            ]
+        );
+        assert!(block_on(reader.is_directory(Path::new("textures"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("a.txt"))).unwrap());
+    }
+
+    #[test]
+    fn archives_are_layered_by_priority() {
+        let mut base = PakWriter::new();
+        base.add("a.txt", "base a");
+        base.add("b.txt", "base b");
+        base.add("levels/1.level", "level 1");
+        let base = Archive::from_bytes(base.to_bytes()).unwrap();
+        assert_eq!(base.format(), ArchiveFormat::Pak);
+
+        let mut patch = PakWriter::new();
+        patch.add("a.txt", "patched a");
+        patch.add("levels/2.level", "level 2");
+        let patch = Archive::from_bytes(patch.to_bytes()).unwrap();
+
+        let mut reader = ArchiveAssetReader::new()
+            .with_archive(patch.clone(), 10)
+            .with_archive(base, 0);
+        assert_eq!(read(&reader, "a.txt").unwrap(), b"patched a");
+        assert_eq!(read(&reader, "b.txt").unwrap(), b"base b");
+        assert_eq!(
+            read_directory(&reader, "levels"),
+            [
+                PathBuf::from("levels/1.level"),
+                PathBuf::from("levels/2.level")
+            ]
+        );
+        assert_eq!(
+            read_directory(&reader, ""),
+            [
+                PathBuf::from("a.txt"),
+                PathBuf::from("b.txt"),
+                PathBuf::from("levels")
+            ]
+        );
+
+        // With equal priorities, the archive added last wins.
+        let zip = Archive::from_bytes(write_zip(&[("a.txt", b"zip a")])).unwrap();
+        reader.add_archive(zip, 10);
+        assert_eq!(read(&reader, "a.txt").unwrap(), b"zip a");
+        assert_eq!(read(&reader, "levels/2.level").unwrap(), b"level 2");
+    }
+
+    #[test]
+    fn meta_files_are_read_from_the_archive_of_their_asset() {
+        let mut base = PakWriter::new();
+        base.add("a.png", "base a");
+        base.add("a.png.meta", "base a meta");
+        base.add("b.png", "base b");
+        base.add("b.png.meta", "base b meta");
+        let base = Archive::from_bytes(base.to_bytes()).unwrap();
+
+        let mut patch = PakWriter::new();
+        patch.add("a.png", "patched a");
+        patch.add("c.png.meta", "orphan meta");
+        let patch = Archive::from_bytes(patch.to_bytes()).unwrap();
+
+        let reader = ArchiveAssetReader::new()
+            .with_archive(patch, 10)
+            .with_archive(base, 0);
+        assert_eq!(read(&reader, "a.png").unwrap(), b"patched a");
+        // The patched asset doesn't have a meta file, so the one of the base asset must not be used.
+        assert!(matches!(
+            read_meta(&reader, "a.png"),
+            Err(AssetReaderError::NotFound(path)) if path == Path::new("a.png.meta")
+        ));
+        assert_eq!(read_meta(&reader, "b.png").unwrap(), b"base b meta");
+        assert!(matches!(
+            read_meta(&reader, "c.png"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+    }
+
+    #[cfg(feature = "zip_deflate")]
+    #[test]
+    fn zip_archive_deflate() {
+        use std::io::Write;
+
+        let data = "compressible ".repeat(100);
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert!(compressed.len() < data.len());
+
+        let archive =
+            Archive::from_bytes(write_zip_entries([("text.txt", 8, compressed, data.len())]))
+                .unwrap();
+        let reader = ArchiveAssetReader::new().with_archive(archive, 0);
+        assert_eq!(read(&reader, "text.txt").unwrap(), data.as_bytes());
+    }
+
+    #[test]
+    fn unsupported_compression_is_rejected() {
+        let zip = write_zip_entries([("a.txt", 14, b"lzma".to_vec(), 4)]);
+        assert!(matches!(
+            Archive::from_bytes(zip),
+            Err(ArchiveError::UnsupportedCompression { method: 14, .. })
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+//! The pak archive format.
+//!
+//! A pak archive is a small index followed by the uncompressed contents of its files.
+//! All integers are little-endian:
+//!
+//! ```text
+//! magic:       b"BEVYPAK\0"
+//! version:     u32 (currently 1)
+//! entry count: u32
+//! entries:     entry count times {
+//!     path length: u32
+//!     path:        UTF-8, with `/` separated components
+//!     offset:      u64, from the start of the archive
+//!     size:        u64
+//! }
+//! data
+//! ```
+
+use super::{normalize, ArchiveEntry, ArchiveError, ArchiveIndex, Compression};
+use std::{
+    collections::BTreeMap,
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Component, Path},
+};
+
+const MAGIC: &[u8; 8] = b"BEVYPAK\0";
+const VERSION: u32 = 1;
+const HEADER_LEN: u64 = 16;
+const ENTRY_LEN: u64 = 20;
+
+/// Returns `true` if `reader` starts with the pak magic bytes.
+pub(super) fn is_pak(reader: &mut (impl Read + Seek)) -> Result<bool, ArchiveError> {
+    let mut magic = [0; 8];
+    reader.seek(SeekFrom::Start(0))?;
+    match reader.read_exact(&mut magic) {
+        Ok(()) => Ok(&magic == MAGIC),
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(error) => Err(error.into()),
+    }
+}
+
+pub(super) fn read_index(reader: &mut (impl Read + Seek)) -> Result<ArchiveIndex, ArchiveError> {
+    let len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(MAGIC.len() as u64))?;
+    let version = read_u32(reader)?;
+    if version != VERSION {
+        return Err(ArchiveError::UnsupportedVersion(version));
+    }
+
+    let count = read_u32(reader)?;
+    let mut index = ArchiveIndex::default();
+    for _ in 0..count {
+        let path_len = read_u32(reader)? as u64;
+        if path_len > len {
+            return Err(ArchiveError::Malformed("pak entry path is out of bounds"));
+        }
+        let mut path = vec![0; path_len as usize];
+        reader.read_exact(&mut path)?;
+        let path = String::from_utf8(path)
+            .map_err(|_| ArchiveError::Malformed("pak entry path is not valid UTF-8"))?;
+        let offset = read_u64(reader)?;
+        let size = read_u64(reader)?;
+        if !matches!(offset.checked_add(size), Some(end) if end <= len) {
+            return Err(ArchiveError::Malformed("pak entry data is out of bounds"));
+        }
+        index.insert_file(
+            normalize(Path::new(&path)),
+            ArchiveEntry {
+                offset,
+                compressed_size: size,
+                size,
+                compression: Compression::Stored,
+            },
+        );
+    }
+    Ok(index)
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Builds a pak archive that can be read with an [`Archive`](super::Archive).
+///
+/// Files are kept in memory until the archive is written, sorted by path so that writing the
+/// same files always produces the same archive.
+///
+/// ```
+/// # use bevy_asset::io::archive::{Archive, PakWriter};
+/// let mut writer = PakWriter::new();
+/// writer.add("textures/player.png", vec![0u8; 16]);
+/// writer.add("levels/1.level", "level data");
+///
+/// let archive = Archive::from_bytes(writer.to_bytes()).unwrap();
+/// assert_eq!(archive.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PakWriter {
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+impl PakWriter {
+    /// Creates a new, empty [`PakWriter`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file with the given `path` and contents. If a file was already added at `path`, it is replaced.
+    pub fn add(&mut self, path: impl AsRef<Path>, bytes: impl Into<Vec<u8>>) {
+        let path = path
+            .as_ref()
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        self.files.insert(path, bytes.into());
+    }
+
+    /// Returns the number of files added to this writer.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns `true` if no files were added to this writer.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the path, offset and size of every file, in the order they will be written.
+    ///
+    /// Paths use `/` as separator. Offsets are from the start of the archive.
+    pub fn layout(&self) -> impl Iterator<Item = (&str, u64, u64)> {
+        let index_len = self
+            .files
+            .keys()
+            .map(|path| ENTRY_LEN + path.len() as u64)
+            .sum::<u64>();
+        self.files
+            .iter()
+            .scan(HEADER_LEN + index_len, |offset, (path, bytes)| {
+                let size = bytes.len() as u64;
+                let entry = (path.as_str(), *offset, size);
+                *offset += size;
+                Some(entry)
+            })
+    }
+
+    /// Writes the archive to `writer`.
+    pub fn write(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.files.len() as u32).to_le_bytes())?;
+        for (path, offset, size) in self.layout() {
+            writer.write_all(&(path.len() as u32).to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&size.to_le_bytes())?;
+        }
+        for bytes in self.files.values() {
+            writer.write_all(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the archive to a new [`Vec<u8>`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // Writing to a `Vec` can't fail.
+        self.write(&mut bytes).unwrap();
+        bytes
+    }
+}
@@ -0,0 +1,209 @@
+//! Reading the index of zip archives.
+//!
+//! Only the central directory is read when the archive is opened. The local file header of an
+//! entry, which determines where its data starts, is read when the entry itself is read.
+
+use super::{normalize, ArchiveEntry, ArchiveError, ArchiveIndex, Compression};
+use crate::io::AssetReaderError;
+use std::{
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+pub(super) const LOCAL_HEADER_LEN: u64 = 30;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_HEADER_LEN: usize = 46;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_LEN: u64 = 22;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const ZIP64_LOCATOR_LEN: u64 = 20;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_LEN: usize = 56;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06064b50;
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+
+/// Reads the central directory of a zip archive.
+///
+/// Returns `None` if `reader` doesn't contain a zip archive.
+pub(super) fn read_index(
+    reader: &mut (impl Read + Seek),
+) -> Result<Option<ArchiveIndex>, ArchiveError> {
+    let len = reader.seek(SeekFrom::End(0))?;
+    if len < END_OF_CENTRAL_DIRECTORY_LEN {
+        return Ok(None);
+    }
+
+    // The end of central directory record is followed by a comment of up to 64 KiB, so it has to
+    // be searched for backwards.
+    let tail_len = len.min(END_OF_CENTRAL_DIRECTORY_LEN + u16::MAX as u64);
+    let tail_start = len - tail_len;
+    let tail = read_at(reader, tail_start, tail_len)?;
+    let Some(record) = (0..=tail.len() - END_OF_CENTRAL_DIRECTORY_LEN as usize)
+        .rev()
+        .find(|&i| u32_at(&tail, i) == END_OF_CENTRAL_DIRECTORY_SIGNATURE)
+    else {
+        return Ok(None);
+    };
+    let record_start = tail_start + record as u64;
+    let record = &tail[record..];
+
+    let mut count = u16_at(record, 10) as u64;
+    let mut central_directory_len = u32_at(record, 12) as u64;
+    let mut central_directory_start = u32_at(record, 16) as u64;
+    if count == u16::MAX as u64
+        || central_directory_len == u32::MAX as u64
+        || central_directory_start == u32::MAX as u64
+    {
+        let record = read_zip64_end_of_central_directory(reader, record_start)?;
+        count = u64_at(&record, 32);
+        central_directory_len = u64_at(&record, 40);
+        central_directory_start = u64_at(&record, 48);
+    }
+
+    let central_directory_end = central_directory_start.checked_add(central_directory_len);
+    if !matches!(central_directory_end, Some(end) if end <= record_start) {
+        return Err(ArchiveError::Malformed(
+            "zip central directory is out of bounds",
+        ));
+    }
+    let central_directory = read_at(reader, central_directory_start, central_directory_len)?;
+
+    let mut index = ArchiveIndex::default();
+    let mut header = central_directory.as_slice();
+    for _ in 0..count {
+        if header.len() < CENTRAL_HEADER_LEN || u32_at(header, 0) != CENTRAL_HEADER_SIGNATURE {
+            return Err(ArchiveError::Malformed(
+                "invalid zip central directory entry",
+            ));
+        }
+        let flags = u16_at(header, 8);
+        let method = u16_at(header, 10);
+        let mut compressed_size = u32_at(header, 20) as u64;
+        let mut size = u32_at(header, 24) as u64;
+        let name_len = u16_at(header, 28) as usize;
+        let extra_len = u16_at(header, 30) as usize;
+        let comment_len = u16_at(header, 32) as usize;
+        let mut offset = u32_at(header, 42) as u64;
+
+        let extra_start = CENTRAL_HEADER_LEN + name_len;
+        let header_len = extra_start + extra_len + comment_len;
+        if header.len() < header_len {
+            return Err(ArchiveError::Malformed(
+                "invalid zip central directory entry",
+            ));
+        }
+        let name = String::from_utf8_lossy(&header[CENTRAL_HEADER_LEN..extra_start]);
+        let path = normalize(Path::new(name.as_ref()));
+
+        // Sizes and offsets that don't fit in 32 bits are moved to the zip64 extra field.
+        let mut extra = &header[extra_start..extra_start + extra_len];
+        while extra.len() >= 4 {
+            let id = u16_at(extra, 0);
+            let data_len = u16_at(extra, 2) as usize;
+            let data = extra
+                .get(4..4 + data_len)
+                .ok_or(ArchiveError::Malformed("invalid zip extra field"))?;
+            if id == ZIP64_EXTRA_FIELD_ID {
+                let mut fields = data.chunks_exact(8).map(|field| u64_at(field, 0));
+                for value in [&mut size, &mut compressed_size, &mut offset] {
+                    if *value == u32::MAX as u64 {
+                        *value = fields
+                            .next()
+                            .ok_or(ArchiveError::Malformed("invalid zip64 extra field"))?;
+                    }
+                }
+            }
+            extra = &extra[4 + data_len..];
+        }
+        header = &header[header_len..];
+
+        if name.ends_with('/') {
+            index.insert_directory(path);
+            continue;
+        }
+        if flags & 1 != 0 {
+            return Err(ArchiveError::Encrypted(path));
+        }
+        let compression = match method {
+            0 => Compression::Stored,
+            #[cfg(feature = "zip_deflate")]
+            8 => Compression::Deflate,
+            _ => return Err(ArchiveError::UnsupportedCompression { path, method }),
+        };
+        index.insert_file(
+            path,
+            ArchiveEntry {
+                offset,
+                compressed_size,
+                size,
+                compression,
+            },
+        );
+    }
+    Ok(Some(index))
+}
+
+fn read_zip64_end_of_central_directory(
+    reader: &mut (impl Read + Seek),
+    record_start: u64,
+) -> Result<Vec<u8>, ArchiveError> {
+    let locator_start = record_start
+        .checked_sub(ZIP64_LOCATOR_LEN)
+        .ok_or(ArchiveError::Malformed("missing zip64 locator"))?;
+    let locator = read_at(reader, locator_start, ZIP64_LOCATOR_LEN)?;
+    if u32_at(&locator, 0) != ZIP64_LOCATOR_SIGNATURE {
+        return Err(ArchiveError::Malformed("missing zip64 locator"));
+    }
+    let record = read_at(
+        reader,
+        u64_at(&locator, 8),
+        ZIP64_END_OF_CENTRAL_DIRECTORY_LEN as u64,
+    )?;
+    if u32_at(&record, 0) != ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE {
+        return Err(ArchiveError::Malformed(
+            "invalid zip64 end of central directory record",
+        ));
+    }
+    Ok(record)
+}
+
+/// Returns the length of the local file header at the start of `header`, which is where the data of the entry starts.
+pub(super) fn local_header_len(header: &[u8]) -> Result<u64, AssetReaderError> {
+    if u32_at(header, 0) != LOCAL_HEADER_SIGNATURE {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "invalid zip local file header",
+        )
+        .into());
+    }
+    Ok(LOCAL_HEADER_LEN + u16_at(header, 26) as u64 + u16_at(header, 28) as u64)
+}
+
+/// Decompresses the deflate-compressed data of an entry.
+#[cfg(feature = "zip_deflate")]
+pub(super) fn inflate(bytes: &[u8], size: u64) -> Result<Vec<u8>, AssetReaderError> {
+    let mut decompressed = Vec::with_capacity(super::to_usize(size)?);
+    flate2::read::DeflateDecoder::new(bytes).read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+fn read_at(reader: &mut (impl Read + Seek), offset: u64, len: u64) -> std::io::Result<Vec<u8>> {
+    let len =
+        usize::try_from(len).map_err(|_| std::io::Error::from(std::io::ErrorKind::OutOfMemory))?;
+    let mut bytes = vec![0; len];
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
@@ -0,0 +1,305 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+/// A GET request made by an [`HttpAssetReader`](super::HttpAssetReader).
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// The full URL of the request.
+    pub url: String,
+    /// Additional request headers, such as the cache validators `If-None-Match` and `If-Modified-Since`.
+    pub headers: Vec<(String, String)>,
+}
+
+/// The response to an [`HttpRequest`].
+pub struct HttpResponse {
+    /// The [HTTP response status code](https://developer.mozilla.org/en-US/docs/Web/HTTP/Status).
+    pub status: u16,
+    /// The response headers.
+    pub headers: Vec<(String, String)>,
+    /// The response body, which is read as it is downloaded.
+    pub body: Box<dyn Read + Send>,
+}
+
+impl HttpResponse {
+    /// Returns the value of the header with the given `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Performs the HTTP requests of an [`HttpAssetReader`](super::HttpAssetReader).
+///
+/// Requests are made from a blocking thread, so implementations can use a blocking HTTP client.
+/// The default implementation, [`TcpHttpClient`], only supports plain `http://` URLs. Implement this
+/// trait on top of a TLS-capable HTTP client to load assets from `https://` URLs.
+pub trait HttpClient: Send + Sync + 'static {
+    /// Performs a GET `request`. Redirects should be followed.
+    ///
+    /// Errors should only be returned if no response was received, for example because the server
+    /// is unreachable. Those are treated as the reader being offline, except for errors of kind
+    /// [`Unsupported`](std::io::ErrorKind::Unsupported), which should be returned for URLs the client
+    /// can't handle and are reported as is.
+    fn get(&self, request: &HttpRequest) -> std::io::Result<HttpResponse>;
+}
+
+/// A minimal HTTP/1.1 [`HttpClient`] built on [`TcpStream`], supporting plain `http://` URLs.
+#[derive(Debug, Clone)]
+pub struct TcpHttpClient {
+    /// The timeout for connecting to the server and for each read of the response.
+    pub timeout: Duration,
+    /// The maximum number of redirects that are followed for a single request.
+    pub max_redirects: usize,
+}
+
+impl Default for TcpHttpClient {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_redirects: 5,
+        }
+    }
+}
+
+impl HttpClient for TcpHttpClient {
+    fn get(&self, request: &HttpRequest) -> std::io::Result<HttpResponse> {
+        let mut url = request.url.clone();
+        for _ in 0..=self.max_redirects {
+            let response = self.get_once(&url, &request.headers)?;
+            if matches!(response.status, 301 | 302 | 303 | 307 | 308) {
+                if let Some(location) = response.header("location") {
+                    url = resolve_redirect(&url, location);
+                    continue;
+                }
+            }
+            return Ok(response);
+        }
+        Err(std::io::Error::other(format!(
+            "too many redirects when requesting {}",
+            request.url
+        )))
+    }
+}
+
+impl TcpHttpClient {
+    fn get_once(&self, url: &str, headers: &[(String, String)]) -> std::io::Result<HttpResponse> {
+        let (host, port, path) = parse_url(url)?;
+        let address = (host, port).to_socket_addrs()?.next().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("could not resolve host {host}"),
+            )
+        })?;
+        let mut stream = TcpStream::connect_timeout(&address, self.timeout)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut head = format!("GET {path} HTTP/1.1\r\nHost: {host}");
+        if port != 80 {
+            head.push_str(&format!(":{port}"));
+        }
+        head.push_str(
+            "\r\nConnection: close\r\nAccept-Encoding: identity\r\nUser-Agent: bevy_asset\r\n",
+        );
+        for (name, value) in headers {
+            head.push_str(&format!("{name}: {value}\r\n"));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let status = line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| invalid_data("invalid HTTP status line"))?;
+
+        let mut response_headers = Vec::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                return Err(invalid_data("unexpected end of HTTP headers"));
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header
+                .split_once(':')
+                .ok_or_else(|| invalid_data("invalid HTTP header"))?;
+            response_headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+
+        let mut response = HttpResponse {
+            status,
+            headers: response_headers,
+            body: Box::new(std::io::empty()),
+        };
+        response.body = if response
+            .header("transfer-encoding")
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"))
+        {
+            Box::new(ChunkedReader::new(reader))
+        } else if let Some(len) = response
+            .header("content-length")
+            .and_then(|len| len.parse().ok())
+        {
+            Box::new(reader.take(len))
+        } else {
+            Box::new(reader)
+        };
+        Ok(response)
+    }
+}
+
+/// Splits an `http://` URL into its host, port and path.
+fn parse_url(url: &str) -> std::io::Result<(&str, u16, &str)> {
+    let Some(rest) = url.strip_prefix("http://") else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!(
+                "TcpHttpClient only supports http:// URLs, use a TLS-capable HttpClient for {url}"
+            ),
+        ));
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse()
+                .map_err(|_| invalid_data("invalid port in URL"))?,
+        ),
+        None => (authority, 80),
+    };
+    Ok((host, port, path))
+}
+
+/// Resolves the `location` of a redirect relative to the `url` that was requested.
+fn resolve_redirect(url: &str, location: &str) -> String {
+    if location.contains("://") {
+        return location.to_string();
+    }
+    let scheme_end = url.find("://").map_or(0, |index| index + 3);
+    if location.starts_with('/') {
+        let authority_end = url[scheme_end..]
+            .find('/')
+            .map_or(url.len(), |index| scheme_end + index);
+        format!("{}{location}", &url[..authority_end])
+    } else {
+        let directory_end = url.rfind('/').filter(|&index| index >= scheme_end);
+        match directory_end {
+            Some(index) => format!("{}{location}", &url[..=index]),
+            None => format!("{url}/{location}"),
+        }
+    }
+}
+
+fn invalid_data(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// Decodes a body sent with `Transfer-Encoding: chunked`.
+struct ChunkedReader<R> {
+    reader: R,
+    /// The number of bytes left in the current chunk, or `None` once the last chunk was read.
+    remaining: Option<u64>,
+}
+
+impl<R: BufRead> ChunkedReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            remaining: Some(0),
+        }
+    }
+
+    fn read_line(&mut self) -> std::io::Result<String> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(line.trim_end().to_string())
+    }
+}
+
+impl<R: BufRead> Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            match self.remaining {
+                None => return Ok(0),
+                Some(0) => {
+                    let line = self.read_line()?;
+                    let size = line.split(';').next().unwrap_or_default().trim();
+                    let size = u64::from_str_radix(size, 16)
+                        .map_err(|_| invalid_data("invalid HTTP chunk size"))?;
+                    if size == 0 {
+                        // Skip the trailer.
+                        while !self.read_line()?.is_empty() {}
+                        self.remaining = None;
+                    } else {
+                        self.remaining = Some(size);
+                    }
+                }
+                Some(remaining) => {
+                    let len = buf
+                        .len()
+                        .min(usize::try_from(remaining).unwrap_or(usize::MAX));
+                    let read = self.reader.read(&mut buf[..len])?;
+                    if read == 0 {
+                        return Err(std::io::ErrorKind::UnexpectedEof.into());
+                    }
+                    let remaining = remaining - read as u64;
+                    if remaining == 0 {
+                        // Every chunk is followed by a line break.
+                        self.read_line()?;
+                    }
+                    self.remaining = Some(remaining);
+                    return Ok(read);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunked_body() {
+        let body =
+            "4\r\nWiki\r\n7;ext=1\r\npedia i\r\nB\r\nn \r\nchunks.\r\n0\r\nTrailer: x\r\n\r\n";
+        let mut decoded = String::new();
+        ChunkedReader::new(body.as_bytes())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "Wikipedia in \r\nchunks.");
+    }
+
+    #[test]
+    fn redirects() {
+        let url = "http://example.com/assets/a.png";
+        assert_eq!(
+            resolve_redirect(url, "http://cdn.example.com/a.png"),
+            "http://cdn.example.com/a.png"
+        );
+        assert_eq!(
+            resolve_redirect(url, "/v2/a.png"),
+            "http://example.com/v2/a.png"
+        );
+        assert_eq!(
+            resolve_redirect(url, "b.png"),
+            "http://example.com/assets/b.png"
+        );
+    }
+}
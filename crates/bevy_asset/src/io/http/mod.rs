@@ -0,0 +1,699 @@
+//! Loading assets over HTTP, with a local cache.
+//!
+//! The [`HttpAssetReader`] is meant to be registered as an [`AssetSource`](crate::io::AssetSource),
+//! so that assets can be loaded from a server with paths like `remote://levels/1.level`.
+
+mod client;
+
+pub use client::*;
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, EmptyPathStream, PathStream, Reader, VecReader,
+};
+use bevy_utils::{
+    tracing::{error, warn},
+    HashMap,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    io::Read,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+/// The size of the buffer the body of a response is read with, which is how often progress is updated.
+const READ_CHUNK_LEN: usize = 64 * 1024;
+
+/// An [`AssetReader`] that fetches assets over HTTP from a base URL.
+///
+/// The path of an asset is appended to the base URL, so with the base URL
+/// `http://localhost:8080/assets` the asset `textures/player.png` is fetched from
+/// `http://localhost:8080/assets/textures/player.png`.
+///
+/// If a cache directory is set with [`with_cache`](Self::with_cache), every downloaded asset is
+/// stored there together with its `ETag` and `Last-Modified` headers. Later requests send those
+/// back, so the server can answer with `304 Not Modified` instead of sending the asset again.
+/// If the server can't be reached or fails with a server error, cached assets are used instead,
+/// which allows running offline after the assets were loaded once. Assets the server doesn't have,
+/// like missing `.meta` files, are remembered in the cache too, so they are still reported as
+/// [`AssetReaderError::NotFound`] offline.
+///
+/// Like the `HttpWasmAssetReader`, a `404` response is reported as
+/// [`AssetReaderError::NotFound`] and any other unexpected status as
+/// [`AssetReaderError::HttpError`]. Directories are not supported.
+///
+/// Requests are made by an [`HttpClient`], which is a [`TcpHttpClient`] by default.
+///
+/// **The default client does not support TLS**, so it can only load assets from plain `http://` URLs.
+/// To load assets from `https://` URLs, like most CDNs require, provide a TLS-capable client with
+/// [`with_client`](Self::with_client). With the default client, every request to an `https://` URL
+/// fails with an [`Unsupported`](std::io::ErrorKind::Unsupported) IO error, even if the asset is cached.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::io::{AssetSource, AssetSourceId, http::HttpAssetReader};
+/// # use bevy_asset::AssetApp;
+/// let reader = HttpAssetReader::new("http://localhost:8080/assets").with_cache("asset_cache");
+/// let progress = reader.progress();
+///
+/// App::new().register_asset_source(
+///     AssetSourceId::from("remote"),
+///     AssetSource::build().with_reader(move || Box::new(reader.clone())),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct HttpAssetReader {
+    base_url: String,
+    cache: Option<PathBuf>,
+    client: Arc<dyn HttpClient>,
+    progress: HttpProgress,
+}
+
+impl HttpAssetReader {
+    /// Creates a new [`HttpAssetReader`] that fetches assets relative to `base_url`, without a cache.
+    ///
+    /// The reader uses a [`TcpHttpClient`], so an `https://` base URL requires calling
+    /// [`with_client`](Self::with_client) with a TLS-capable client.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        Self {
+            base_url,
+            cache: None,
+            client: Arc::new(TcpHttpClient::default()),
+            progress: HttpProgress::default(),
+        }
+    }
+
+    /// Caches downloaded assets in the directory at `path`, which is created if it doesn't exist.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache = Some(path.into());
+        self
+    }
+
+    /// Makes requests with the given [`HttpClient`] instead of a [`TcpHttpClient`].
+    pub fn with_client(mut self, client: impl HttpClient) -> Self {
+        self.client = Arc::new(client);
+        self
+    }
+
+    /// Returns the base URL assets are fetched from. It always ends with a `/`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the directory downloaded assets are cached in, if any.
+    pub fn cache(&self) -> Option<&Path> {
+        self.cache.as_deref()
+    }
+
+    /// Returns a handle to the progress of the downloads of this reader and all of its clones.
+    pub fn progress(&self) -> HttpProgress {
+        self.progress.clone()
+    }
+
+    /// Returns the URL the asset at `path` is fetched from.
+    pub fn url(&self, path: &Path) -> String {
+        let mut url = self.base_url.clone();
+        for (i, name) in normal_components(path).enumerate() {
+            if i > 0 {
+                url.push('/');
+            }
+            percent_encode(&name.to_string_lossy(), &mut url);
+        }
+        url
+    }
+
+    async fn fetch(&self, path: PathBuf) -> Result<VecReader, AssetReaderError> {
+        let fetch = Fetch {
+            url: self.url(&path),
+            cache: self
+                .cache
+                .as_ref()
+                .map(|cache| cache.join(normal_components(&path).collect::<PathBuf>())),
+            client: self.client.clone(),
+            progress: self.progress.clone(),
+            path,
+        };
+        blocking::unblock(move || fetch.run())
+            .await
+            .map(VecReader::new)
+    }
+}
+
+impl AssetReader for HttpAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch(path.to_owned()).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch(get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let stream: Box<PathStream> = Box::new(EmptyPathStream);
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> std::result::Result<bool, AssetReaderError> {
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(false)
+    }
+}
+
+/// A single request of an [`HttpAssetReader`], which runs on a blocking thread.
+struct Fetch {
+    path: PathBuf,
+    url: String,
+    /// The path the asset is cached at, if the reader has a cache.
+    cache: Option<PathBuf>,
+    client: Arc<dyn HttpClient>,
+    progress: HttpProgress,
+}
+
+impl Fetch {
+    fn run(self) -> Result<Vec<u8>, AssetReaderError> {
+        let validators = self.cache.as_deref().and_then(CacheValidators::load);
+        let mut request = HttpRequest {
+            url: self.url.clone(),
+            headers: Vec::new(),
+        };
+        if let Some(validators) = &validators {
+            if let Some(etag) = &validators.etag {
+                request.headers.push(("If-None-Match".into(), etag.clone()));
+            }
+            if let Some(last_modified) = &validators.last_modified {
+                request
+                    .headers
+                    .push(("If-Modified-Since".into(), last_modified.clone()));
+            }
+        }
+
+        let response = match self.client.get(&request) {
+            Ok(response) => response,
+            // The client can't handle the URL at all, which is a configuration error rather than the
+            // server being unreachable.
+            Err(error) if error.kind() == std::io::ErrorKind::Unsupported => {
+                error!("Failed to fetch {}: {error}", self.url);
+                return Err(error.into());
+            }
+            Err(error) => return self.offline_fallback(error.into()),
+        };
+        match response.status {
+            200 => match self.download(response) {
+                Ok(bytes) => Ok(bytes),
+                Err(error) => self.offline_fallback(error.into()),
+            },
+            304 => match (&self.cache, validators) {
+                (Some(cache), Some(_)) => Ok(std::fs::read(cache)?),
+                _ => Err(AssetReaderError::HttpError(304)),
+            },
+            404 => {
+                if let Some(cache) = &self.cache {
+                    if let Err(error) = CacheValidators::store_not_found(cache) {
+                        warn!(
+                            "Failed to cache missing asset {:?} at {:?}: {error}",
+                            self.path, cache
+                        );
+                    }
+                }
+                Err(AssetReaderError::NotFound(self.path))
+            }
+            status @ 500..=599 => self.offline_fallback(AssetReaderError::HttpError(status)),
+            status => Err(AssetReaderError::HttpError(status)),
+        }
+    }
+
+    /// Reads the body of a successful `response` and stores it in the cache.
+    fn download(&self, mut response: HttpResponse) -> std::io::Result<Vec<u8>> {
+        let total_bytes = response
+            .header("content-length")
+            .and_then(|len| len.parse().ok());
+        let _download = self.progress.start(&self.path, total_bytes);
+
+        let mut bytes = Vec::new();
+        let mut chunk = vec![0; READ_CHUNK_LEN];
+        loop {
+            let read = response.body.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            bytes.extend_from_slice(&chunk[..read]);
+            self.progress.update(&self.path, bytes.len() as u64);
+        }
+
+        if let Some(cache) = &self.cache {
+            let validators = CacheValidators {
+                etag: response.header("etag").map(ToString::to_string),
+                last_modified: response.header("last-modified").map(ToString::to_string),
+            };
+            if let Err(error) = validators.store(cache, &bytes) {
+                warn!(
+                    "Failed to cache asset {:?} at {:?}: {error}",
+                    self.path, cache
+                );
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the cached asset if there is one, [`AssetReaderError::NotFound`] if the server
+    /// didn't have the asset when it was last fetched, and `error` otherwise.
+    fn offline_fallback(&self, error: AssetReaderError) -> Result<Vec<u8>, AssetReaderError> {
+        let Some(cache) = self.cache.as_deref() else {
+            return Err(error);
+        };
+        let cached = if CacheValidators::path(cache).exists() {
+            std::fs::read(cache).ok()
+        } else {
+            None
+        };
+        match cached {
+            Some(bytes) => {
+                warn!(
+                    "Failed to fetch {}, using the cached asset {:?} instead: {error}",
+                    self.url, self.path
+                );
+                Ok(bytes)
+            }
+            None if CacheValidators::not_found_path(cache).exists() => {
+                Err(AssetReaderError::NotFound(self.path.clone()))
+            }
+            None => Err(error),
+        }
+    }
+}
+
+/// The headers of a cached response that are used to check whether the cached asset is up to date.
+///
+/// They are stored next to the cached asset, which is written first. So if they exist, the cached
+/// asset is complete. Assets the server answered with `404 Not Found` are marked with an empty
+/// file next to where they would be cached instead.
+#[derive(Serialize, Deserialize, Default)]
+struct CacheValidators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    fn path(cache: &Path) -> PathBuf {
+        let mut path = cache.as_os_str().to_owned();
+        path.push(".http_cache");
+        path.into()
+    }
+
+    fn not_found_path(cache: &Path) -> PathBuf {
+        let mut path = cache.as_os_str().to_owned();
+        path.push(".http_not_found");
+        path.into()
+    }
+
+    fn load(cache: &Path) -> Option<Self> {
+        let validators = std::fs::read_to_string(Self::path(cache)).ok()?;
+        ron::from_str(&validators).ok()
+    }
+
+    fn store(&self, cache: &Path, bytes: &[u8]) -> std::io::Result<()> {
+        if let Some(parent) = cache.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Remove the old validators first, so that they never describe a partially written asset.
+        remove_if_exists(&Self::path(cache))?;
+        remove_if_exists(&Self::not_found_path(cache))?;
+        std::fs::write(cache, bytes)?;
+        let validators = ron::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(Self::path(cache), validators)
+    }
+
+    /// Marks the asset cached at `cache` as missing on the server, discarding any cached version.
+    fn store_not_found(cache: &Path) -> std::io::Result<()> {
+        if let Some(parent) = cache.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        remove_if_exists(&Self::path(cache))?;
+        remove_if_exists(cache)?;
+        std::fs::write(Self::not_found_path(cache), [])
+    }
+}
+
+fn remove_if_exists(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// The progress of a single download of an [`HttpAssetReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DownloadProgress {
+    /// The number of bytes that were downloaded so far.
+    pub received_bytes: u64,
+    /// The size of the asset, if the server sent it.
+    pub total_bytes: Option<u64>,
+}
+
+/// A shared handle to the downloads in progress of an [`HttpAssetReader`].
+///
+/// Downloads are listed from when the response headers are received until the whole asset was
+/// downloaded. Cached assets that are still up to date are never listed.
+#[derive(Debug, Clone, Default)]
+pub struct HttpProgress(Arc<Mutex<HashMap<PathBuf, DownloadProgress>>>);
+
+impl HttpProgress {
+    /// Returns the progress of the download of the asset at `path`, if it is in progress.
+    pub fn get(&self, path: &Path) -> Option<DownloadProgress> {
+        self.0.lock().get(path).copied()
+    }
+
+    /// Returns the paths and progress of all downloads in progress.
+    pub fn downloads(&self) -> Vec<(PathBuf, DownloadProgress)> {
+        self.0
+            .lock()
+            .iter()
+            .map(|(path, progress)| (path.clone(), *progress))
+            .collect()
+    }
+
+    /// Returns the combined progress of all downloads in progress.
+    ///
+    /// The total is `None` if the size of any of the assets is unknown.
+    pub fn total(&self) -> DownloadProgress {
+        self.0.lock().values().fold(
+            DownloadProgress {
+                received_bytes: 0,
+                total_bytes: Some(0),
+            },
+            |sum, progress| DownloadProgress {
+                received_bytes: sum.received_bytes + progress.received_bytes,
+                total_bytes: sum
+                    .total_bytes
+                    .zip(progress.total_bytes)
+                    .map(|(a, b)| a + b),
+            },
+        )
+    }
+
+    /// Returns `true` if no download is in progress.
+    pub fn is_idle(&self) -> bool {
+        self.0.lock().is_empty()
+    }
+
+    fn start(&self, path: &Path, total_bytes: Option<u64>) -> DownloadGuard<'_> {
+        self.0.lock().insert(
+            path.to_owned(),
+            DownloadProgress {
+                received_bytes: 0,
+                total_bytes,
+            },
+        );
+        DownloadGuard {
+            progress: self,
+            path: path.to_owned(),
+        }
+    }
+
+    fn update(&self, path: &Path, received_bytes: u64) {
+        if let Some(progress) = self.0.lock().get_mut(path) {
+            progress.received_bytes = received_bytes;
+        }
+    }
+}
+
+/// Removes a download from its [`HttpProgress`] when it ends, whether it succeeded or not.
+struct DownloadGuard<'a> {
+    progress: &'a HttpProgress,
+    path: PathBuf,
+}
+
+impl Drop for DownloadGuard<'_> {
+    fn drop(&mut self) {
+        self.progress.0.lock().remove(&self.path);
+    }
+}
+
+/// Returns the names of the normal components of `path`, skipping `.`, `..` and roots so that
+/// paths can't escape the base URL or the cache directory.
+fn normal_components(path: &Path) -> impl Iterator<Item = &std::ffi::OsStr> {
+    path.components().filter_map(|component| match component {
+        Component::Normal(name) => Some(name),
+        _ => None,
+    })
+}
+
+/// Appends `segment` to `url`, percent-encoding everything but unreserved characters.
+fn percent_encode(segment: &str, url: &mut String) {
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::{AssetSource, ErasedAssetReader},
+        tests::{run_app_until, CoolText, CoolTextLoader},
+        AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
+    };
+    use bevy_app::App;
+    use bevy_core::TaskPoolPlugin;
+    use bevy_tasks::block_on;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// A stand-in HTTP server that serves `/assets/data.txt` with an `ETag` and
+    /// `/assets/text.cool.ron` without a `.meta` file, and fails with a server error for
+    /// `/assets/broken.txt`.
+    struct TestServer {
+        port: u16,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl TestServer {
+        fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let requests = Arc::new(AtomicUsize::new(0));
+            let counter = requests.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { break };
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    if Self::respond(stream).is_err() {
+                        break;
+                    }
+                }
+            });
+            Self { port, requests }
+        }
+
+        fn respond(mut stream: TcpStream) -> std::io::Result<()> {
+            let mut reader = BufReader::new(stream.try_clone()?);
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            let path = line
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            let mut if_none_match = None;
+            loop {
+                line.clear();
+                reader.read_line(&mut line)?;
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("if-none-match") {
+                        if_none_match = Some(value.trim().to_string());
+                    }
+                }
+            }
+
+            let response = match path.as_str() {
+                "/assets/data.txt" if if_none_match.as_deref() == Some("\"v1\"") => {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\n\r\n".to_string()
+                }
+                "/assets/data.txt" => {
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 5\r\n\r\nhello".to_string()
+                }
+                "/assets/chunked%20file.txt" => "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n".to_string(),
+                "/assets/text.cool.ron" => format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{COOL_TEXT}",
+                    COOL_TEXT.len()
+                ),
+                "/assets/broken.txt" => {
+                    "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\n\r\n".to_string()
+                }
+                _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string(),
+            };
+            stream.write_all(response.as_bytes())
+        }
+
+        fn url(&self) -> String {
+            format!("http://127.0.0.1:{}/assets", self.port)
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    const COOL_TEXT: &str = r#"(
+    text: "remote",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+
+    fn read(reader: &HttpAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut reader = ErasedAssetReader::read(reader, Path::new(path)).await?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(bytes)
+        })
+    }
+
+    fn read_meta(reader: &HttpAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(ErasedAssetReader::read_meta_bytes(reader, Path::new(path)))
+    }
+
+    /// A base URL nothing can listen on, as connecting to port 0 always fails.
+    const OFFLINE_URL: &str = "http://127.0.0.1:0/assets";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bevy_asset_http_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn urls_are_percent_encoded() {
+        let reader = HttpAssetReader::new("http://localhost/assets");
+        assert_eq!(
+            reader.url(Path::new("../levels/level 1.level")),
+            "http://localhost/assets/levels/level%201.level"
+        );
+    }
+
+    #[test]
+    fn responses() {
+        let server = TestServer::start();
+        let reader = HttpAssetReader::new(server.url());
+
+        assert_eq!(read(&reader, "data.txt").unwrap(), b"hello");
+        assert_eq!(read(&reader, "chunked file.txt").unwrap(), b"abcde");
+        assert_eq!(
+            read(&reader, "missing.txt").unwrap_err(),
+            AssetReaderError::NotFound("missing.txt".into())
+        );
+        assert_eq!(
+            read(&reader, "broken.txt").unwrap_err(),
+            AssetReaderError::HttpError(500)
+        );
+        assert!(reader.progress().is_idle());
+    }
+
+    #[test]
+    fn https_urls_fail_without_a_tls_client() {
+        let reader = HttpAssetReader::new("https://localhost/assets");
+        let AssetReaderError::Io(error) = read(&reader, "data.txt").unwrap_err() else {
+            panic!("expected an IO error");
+        };
+        assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn cached_assets_are_revalidated_and_used_offline() {
+        let cache = temp_dir("cache");
+        let server = TestServer::start();
+        let reader = HttpAssetReader::new(server.url()).with_cache(&cache);
+
+        assert_eq!(read(&reader, "data.txt").unwrap(), b"hello");
+        assert_eq!(std::fs::read(cache.join("data.txt")).unwrap(), b"hello");
+
+        // The server answers with `304 Not Modified` because the `ETag` matches.
+        std::fs::write(cache.join("data.txt"), b"cached").unwrap();
+        assert_eq!(read(&reader, "data.txt").unwrap(), b"cached");
+        assert_eq!(server.requests(), 2);
+        assert_eq!(
+            read_meta(&reader, "data.txt").unwrap_err(),
+            AssetReaderError::NotFound("data.txt.meta".into())
+        );
+
+        let reader = HttpAssetReader::new(OFFLINE_URL).with_cache(&cache);
+        assert_eq!(read(&reader, "data.txt").unwrap(), b"cached");
+        // The server didn't have the meta file, so it is still missing offline.
+        assert_eq!(
+            read_meta(&reader, "data.txt").unwrap_err(),
+            AssetReaderError::NotFound("data.txt.meta".into())
+        );
+        // Assets that were never fetched can't be told apart from an unreachable server.
+        assert!(matches!(
+            read(&reader, "other.txt").unwrap_err(),
+            AssetReaderError::Io(_)
+        ));
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn assets_without_meta_files_load_offline() {
+        fn load_text(reader: HttpAssetReader) -> String {
+            let mut app = App::new();
+            app.register_asset_source(
+                "remote",
+                AssetSource::build().with_reader(move || Box::new(reader.clone())),
+            )
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+
+            let handle: Handle<CoolText> = app
+                .world()
+                .resource::<AssetServer>()
+                .load("remote://text.cool.ron");
+            let mut text = None;
+            run_app_until(&mut app, |world| {
+                if let Some(LoadState::Failed(error)) =
+                    world.resource::<AssetServer>().get_load_state(&handle)
+                {
+                    panic!("{error}");
+                }
+                let asset = world.resource::<Assets<CoolText>>().get(&handle)?;
+                text = Some(asset.text.clone());
+                Some(())
+            });
+            text.unwrap()
+        }
+
+        let cache = temp_dir("meta");
+        let server = TestServer::start();
+        let online = HttpAssetReader::new(server.url()).with_cache(&cache);
+        assert_eq!(load_text(online), "remote");
+
+        let offline = HttpAssetReader::new(OFFLINE_URL).with_cache(&cache);
+        assert_eq!(load_text(offline), "remote");
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+}
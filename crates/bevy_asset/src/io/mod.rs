@@ -7,10 +7,13 @@ compile_error!(
 
 #[cfg(target_os = "android")]
 pub mod android;
+pub mod archive;
 pub mod embedded;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod file;
 pub mod gated;
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+pub mod http;
 pub mod memory;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
@@ -680,11 +683,11 @@ pub(crate) fn get_meta_path(path: &Path) -> PathBuf {
     meta_path
 }
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(target_arch = "wasm32", target_os = "android", feature = "http"))]
 /// A [`PathBuf`] [`Stream`] implementation that immediately returns nothing.
 struct EmptyPathStream;
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(target_arch = "wasm32", target_os = "android", feature = "http"))]
 impl Stream for EmptyPathStream {
     type Item = PathBuf;
 
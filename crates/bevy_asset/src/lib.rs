@@ -6,6 +6,7 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+pub mod diagnostics;
 pub mod io;
 pub mod meta;
 pub mod processor;
@@ -15,12 +16,13 @@ pub mod transformer;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        Asset, AssetApp, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer, Assets,
-        DirectAssetAccessExt, Handle, UntypedHandle,
+        Asset, AssetApp, AssetCollection, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer,
+        Assets, DirectAssetAccessExt, Handle, LoadingGroups, UntypedHandle,
     };
 }
 
 mod assets;
+mod cache;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -28,12 +30,14 @@ mod handle;
 mod id;
 mod loader;
 mod loader_builders;
+mod loading_progress;
 mod path;
 mod reflect;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use cache::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -44,6 +48,7 @@ pub use loader::*;
 pub use loader_builders::{
     DirectNestedLoader, NestedLoader, UntypedDirectNestedLoader, UntypedNestedLoader,
 };
+pub use loading_progress::*;
 pub use path::*;
 pub use reflect::*;
 pub use server::*;
@@ -53,7 +58,7 @@ pub use ron;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    processor::{AssetBundleSettings, AssetProcessor, Process},
 };
 use bevy_app::{App, Last, Plugin, PreUpdate};
 use bevy_ecs::{
@@ -95,6 +100,12 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// If set, processed assets are packed into bundles in [`AssetMode::Processed`].
+    ///
+    /// When the `asset_processor` feature is enabled, the [`AssetProcessor`] writes the bundles every time
+    /// it finishes processing, and assets are still loaded from the processed folder. Otherwise, assets are
+    /// loaded from the bundles, which is what should be shipped in release builds.
+    pub processed_bundles: Option<AssetBundleSettings>,
 }
 
 #[derive(Debug)]
@@ -142,6 +153,7 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            processed_bundles: None,
         }
     }
 }
@@ -189,6 +201,7 @@ impl Plugin for AssetPlugin {
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                         let processor = AssetProcessor::new(&mut builders);
+                        processor.set_bundle_settings(self.processed_bundles.clone());
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
                         // the main asset server shares loaders with the processor asset server
@@ -205,6 +218,12 @@ impl Plugin for AssetPlugin {
                     #[cfg(not(feature = "asset_processor"))]
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if let Some(bundles) = &self.processed_bundles {
+                            if let Err(err) = bundles.register_processed_readers(&mut builders) {
+                                error!("Failed to read asset bundles in {:?}, falling back to the processed asset folders: {err}", bundles.path);
+                            }
+                        }
                         let sources = builders.build_sources(false, watch);
                         app.insert_resource(AssetServer::new_with_meta_check(
                             sources,
@@ -220,9 +239,12 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
+            .init_resource::<LoadingGroups>()
             .add_event::<UntypedAssetLoadFailedEvent>()
+            .add_event::<LoadingGroupFinished>()
             .configure_sets(PreUpdate, TrackAssets.after(handle_internal_asset_events))
             .add_systems(PreUpdate, handle_internal_asset_events)
+            .add_systems(PreUpdate, track_loading_groups.in_set(TrackAssets))
             .register_type::<AssetPath>();
     }
 }
@@ -319,6 +341,9 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Keeps unused assets of type `A` loaded with the given [`AssetCache`], until they use more
+    /// memory than its budget. The asset type must be initialized with [`init_asset`](Self::init_asset) first.
+    fn set_asset_cache<A: Asset>(&mut self, cache: AssetCache<A>) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -422,6 +447,13 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn set_asset_cache<A: Asset>(&mut self, cache: AssetCache<A>) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<Assets<A>>()
+            .set_cache(Some(cache));
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -438,6 +470,7 @@ pub struct AssetEvents;
 mod tests {
     use crate::{
         self as bevy_asset,
+        diagnostics::AssetCacheDiagnosticsPlugin,
         folder::LoadedFolder,
         handle::Handle,
         io::{
@@ -446,12 +479,14 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, DependencyLoadState, LoadState,
+        Asset, AssetApp, AssetCache, AssetCollection, AssetEvent, AssetId, AssetLoadError,
+        AssetLoadFailedEvent, AssetMemoryUsage, AssetPath, AssetPlugin, AssetServer, Assets,
+        DependencyLoadState, LoadState, LoadingGroupFinished, LoadingGroups,
         RecursiveDependencyLoadState,
     };
     use bevy_app::{App, Update};
     use bevy_core::TaskPoolPlugin;
+    use bevy_diagnostic::DiagnosticsStore;
     use bevy_ecs::prelude::*;
     use bevy_ecs::{
         event::EventCursor,
@@ -1471,6 +1506,182 @@ mod tests {
         });
     }
 
+    #[derive(AssetCollection, Resource)]
+    struct LevelAssets {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(path = "missing.cool.ron")]
+        missing: Handle<CoolText>,
+        attempts: usize,
+    }
+
+    #[derive(Resource, Default)]
+    struct FinishedGroups(Vec<LoadingGroupFinished>);
+
+    #[test]
+    fn loading_groups() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: ["b.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: ["sub"],
+)"#;
+        let b_path = "b.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        for path in [a_path, b_path, "missing.cool.ron", "c.cool.ron"] {
+            gate_opener.open(path);
+        }
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<FinishedGroups>()
+            .register_asset_loader(CoolTextLoader)
+            .observe(
+                |trigger: Trigger<LoadingGroupFinished>, mut finished: ResMut<FinishedGroups>| {
+                    finished.0.push(trigger.event().clone());
+                },
+            );
+
+        let level = {
+            let world = app.world_mut();
+            let asset_server = world.resource::<AssetServer>().clone();
+            let mut groups = world.resource_mut::<LoadingGroups>();
+            groups.load_collection::<LevelAssets>("level", &asset_server)
+        };
+        assert_eq!(level.attempts, 0);
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<LoadingGroups>()
+                .is_finished("level")
+                .then_some(())
+        });
+
+        let groups = app.world().resource::<LoadingGroups>();
+        let progress = groups.progress("level").unwrap();
+        // `a`, its dependency `b` and the missing asset. Labeled assets are loaded with `a`.
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.loaded, 2);
+        assert_eq!(progress.failed, vec![level.missing.id().untyped()]);
+        assert_eq!(
+            progress.loaded_bytes,
+            (a_ron.len() + SIMPLE_TEXT.len()) as u64
+        );
+        assert_eq!(progress.fraction(), 1.0);
+        assert_eq!(groups.handles("level").len(), 2);
+        assert!(groups.handles("level").contains(&level.a.clone().untyped()));
+
+        let finished = &app.world().resource::<FinishedGroups>().0;
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].group, "level");
+        assert_eq!(&finished[0].progress, progress);
+
+        // Assets added later make the group load again.
+        let c = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<CoolText>("c.cool.ron");
+        app.world_mut()
+            .resource_mut::<LoadingGroups>()
+            .add("level", c);
+        assert!(!app.world().resource::<LoadingGroups>().is_finished("level"));
+        run_app_until(&mut app, |world| {
+            (world.resource::<FinishedGroups>().0.len() == 2).then_some(())
+        });
+        let progress = &app.world().resource::<FinishedGroups>().0[1].progress;
+        assert_eq!(progress.total, 4);
+        assert_eq!(progress.failed.len(), 2);
+        assert_eq!(
+            app.world().resource::<Events<LoadingGroupFinished>>().len(),
+            2
+        );
+    }
+
+    #[test]
+    fn unused_assets_are_cached_within_budget() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let b_path = "b.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), SIMPLE_TEXT);
+        dir.insert_asset_text(Path::new(b_path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .set_asset_cache(AssetCache::<CoolText>::new(100).with_size_estimate(|_| 10))
+            .add_plugins(AssetCacheDiagnosticsPlugin::<CoolText>::default());
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a = asset_server.load::<CoolText>(a_path);
+        let b = asset_server.load::<CoolText>(b_path);
+        let (a_id, b_id) = (a.id(), b.id());
+        run_app_until(&mut app, |world| {
+            let assets = world.resource::<Assets<CoolText>>();
+            (assets.contains(a_id) && assets.contains(b_id)).then_some(())
+        });
+
+        // `a` becomes unused before `b`, so it is the least recently used.
+        drop(a);
+        app.update();
+        drop(b);
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(assets.contains(a_id) && assets.contains(b_id));
+        let cache = assets.cache().unwrap();
+        assert!(cache.contains(a_id) && cache.contains(b_id));
+        assert_eq!(
+            cache.usage(),
+            AssetMemoryUsage {
+                total_bytes: 20,
+                cached_bytes: 20,
+                cached_assets: 2,
+            }
+        );
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let total_bytes = AssetCacheDiagnosticsPlugin::<CoolText>::total_bytes_path();
+        assert_eq!(
+            diagnostics.get_measurement(&total_bytes).unwrap().value,
+            20.0
+        );
+
+        // Loading a cached asset again doesn't reload it.
+        let a = asset_server.load::<CoolText>(a_path);
+        assert_eq!(a.id(), a_id);
+        assert_eq!(asset_server.load_state(&a), LoadState::Loaded);
+        app.update();
+        let cache = app.world().resource::<Assets<CoolText>>().cache().unwrap();
+        assert!(!cache.contains(a_id) && cache.contains(b_id));
+
+        // Now `b` is the least recently used asset.
+        drop(a);
+        app.update();
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .cache_mut()
+            .unwrap()
+            .set_budget(10);
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert!(assets.contains(a_id));
+        assert!(!assets.contains(b_id));
+        assert!(asset_server.get_load_state(b_id).is_none());
+        assert_eq!(assets.cache().unwrap().usage().total_bytes, 10);
+
+        // Disabling the cache unloads the cached assets.
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_cache(None);
+        app.update();
+        assert!(!app.world().resource::<Assets<CoolText>>().contains(a_id));
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -0,0 +1,239 @@
+use crate::{AssetServer, LoadState, UntypedAssetId, UntypedHandle};
+use bevy_ecs::prelude::*;
+use bevy_utils::{HashMap, HashSet};
+use std::borrow::Cow;
+
+/// A set of typed handles that are loaded together, usually stored as a [`Resource`].
+///
+/// This trait can be derived. Fields annotated with `#[asset(path = "...")]` are loaded with
+/// [`AssetServer::load`], fields annotated with `#[asset(folder = "...")]` are loaded with
+/// [`AssetServer::load_folder`] and all other fields are set to their [`Default`] value.
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle, LoadedFolder};
+/// # use bevy_ecs::system::Resource;
+/// # use bevy_reflect::TypePath;
+/// # #[derive(bevy_asset::Asset, TypePath)]
+/// # struct Level;
+/// #[derive(AssetCollection, Resource)]
+/// struct GameAssets {
+///     #[asset(path = "levels/1.level")]
+///     first_level: Handle<Level>,
+///     #[asset(folder = "music")]
+///     music: Handle<LoadedFolder>,
+///     current_track: usize,
+/// }
+/// ```
+///
+/// Use [`LoadingGroups::load_collection`] to start loading a collection and track its progress.
+pub trait AssetCollection: Send + Sync + 'static {
+    /// Starts loading all assets of the collection using `asset_server`.
+    fn load(asset_server: &AssetServer) -> Self;
+
+    /// Calls `visit` with the handle of every asset in the collection.
+    fn visit_handles(&self, visit: &mut impl FnMut(UntypedHandle));
+}
+
+/// Tracks the loading progress of named groups of assets, such as everything needed by a level.
+///
+/// A group tracks the assets it was given and all of their (recursive) dependencies, so a group
+/// is finished once every one of those assets either loaded or failed. Progress is updated in
+/// [`PreUpdate`](bevy_app::PreUpdate) and a [`LoadingGroupFinished`] event is both sent and
+/// triggered whenever a group finishes. Adding assets to a finished group makes it load again.
+///
+/// Groups keep strong handles to their assets, so they stay loaded until the group is removed.
+///
+/// ```
+/// # use bevy_asset::{AssetServer, LoadingGroups, LoadingGroupFinished};
+/// # use bevy_ecs::prelude::*;
+/// fn load_level(asset_server: Res<AssetServer>, mut groups: ResMut<LoadingGroups>) {
+///     let handle = asset_server.load_untyped("levels/1.level");
+///     groups.add("level", handle);
+/// }
+///
+/// fn loading_screen(groups: Res<LoadingGroups>) {
+///     if let Some(progress) = groups.progress("level") {
+///         println!("Loading: {:.0}%", progress.fraction() * 100.0);
+///     }
+/// }
+///
+/// fn level_loaded(mut events: EventReader<LoadingGroupFinished>) {
+///     for event in events.read() {
+///         println!("{} finished loading with {} failures", event.group, event.progress.failed.len());
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct LoadingGroups {
+    groups: HashMap<Cow<'static, str>, LoadingGroup>,
+}
+
+#[derive(Debug, Default)]
+struct LoadingGroup {
+    handles: Vec<UntypedHandle>,
+    progress: LoadingProgress,
+    finished: bool,
+}
+
+impl LoadingGroups {
+    /// Adds the asset of `handle` to `group`, creating the group if it doesn't exist.
+    pub fn add(&mut self, group: impl Into<Cow<'static, str>>, handle: impl Into<UntypedHandle>) {
+        let group = self.groups.entry(group.into()).or_default();
+        group.handles.push(handle.into());
+        group.finished = false;
+    }
+
+    /// Adds every asset of `collection` to `group`, creating the group if it doesn't exist.
+    pub fn add_collection(
+        &mut self,
+        group: impl Into<Cow<'static, str>>,
+        collection: &impl AssetCollection,
+    ) {
+        let group = self.groups.entry(group.into()).or_default();
+        collection.visit_handles(&mut |handle| group.handles.push(handle));
+        group.finished = false;
+    }
+
+    /// Starts loading the [`AssetCollection`] `C` and adds its assets to `group`.
+    pub fn load_collection<C: AssetCollection>(
+        &mut self,
+        group: impl Into<Cow<'static, str>>,
+        asset_server: &AssetServer,
+    ) -> C {
+        let collection = C::load(asset_server);
+        self.add_collection(group, &collection);
+        collection
+    }
+
+    /// Returns the progress of `group` as of the last update, if the group exists.
+    pub fn progress(&self, group: &str) -> Option<&LoadingProgress> {
+        self.groups.get(group).map(|group| &group.progress)
+    }
+
+    /// Returns `true` if `group` exists and all of its assets either loaded or failed.
+    pub fn is_finished(&self, group: &str) -> bool {
+        self.groups.get(group).is_some_and(|group| group.finished)
+    }
+
+    /// Returns `true` if `group` exists.
+    pub fn contains(&self, group: &str) -> bool {
+        self.groups.contains_key(group)
+    }
+
+    /// Returns the handles that were added to `group`, without their dependencies.
+    pub fn handles(&self, group: &str) -> &[UntypedHandle] {
+        self.groups
+            .get(group)
+            .map_or(&[], |group| group.handles.as_slice())
+    }
+
+    /// Removes `group`, dropping its handles. Returns `true` if the group existed.
+    pub fn remove(&mut self, group: &str) -> bool {
+        self.groups.remove(group).is_some()
+    }
+
+    /// Returns an iterator over the names of all groups.
+    pub fn groups(&self) -> impl Iterator<Item = &str> {
+        self.groups.keys().map(AsRef::as_ref)
+    }
+}
+
+/// The loading progress of a group of [`LoadingGroups`].
+///
+/// Dependencies are only known once the asset depending on them loaded, so `total` can grow while
+/// the group loads.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadingProgress {
+    /// The number of assets in the group, including the dependencies found so far.
+    pub total: usize,
+    /// The number of assets that loaded.
+    pub loaded: usize,
+    /// The assets that failed to load.
+    pub failed: Vec<UntypedAssetId>,
+    /// The number of bytes read from asset sources to load the loaded assets.
+    pub loaded_bytes: u64,
+}
+
+impl LoadingProgress {
+    /// Returns the fraction of assets that either loaded or failed, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.loaded + self.failed.len()) as f32 / self.total as f32
+    }
+
+    /// Returns the number of assets that are still loading.
+    pub fn loading(&self) -> usize {
+        self.total - self.loaded - self.failed.len()
+    }
+}
+
+/// Sent and triggered when a group of [`LoadingGroups`] finished loading.
+#[derive(Event, Debug, Clone)]
+pub struct LoadingGroupFinished {
+    /// The name of the group.
+    pub group: Cow<'static, str>,
+    /// The final progress of the group, which lists the assets that failed to load.
+    pub progress: LoadingProgress,
+}
+
+/// Updates the progress of all unfinished [`LoadingGroups`].
+pub(crate) fn track_loading_groups(
+    asset_server: Res<AssetServer>,
+    mut groups: ResMut<LoadingGroups>,
+    mut finished_events: EventWriter<LoadingGroupFinished>,
+    mut commands: Commands,
+) {
+    let mut changed = false;
+    let infos = asset_server.data.infos.read();
+    for (name, group) in groups.bypass_change_detection().groups.iter_mut() {
+        if group.finished {
+            continue;
+        }
+
+        let mut progress = LoadingProgress::default();
+        let mut visited = HashSet::new();
+        let mut stack: Vec<UntypedAssetId> = group.handles.iter().map(UntypedHandle::id).collect();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            progress.total += 1;
+            // Assets that aren't managed by the asset server were added directly to their `Assets`.
+            let Some(info) = infos.get(id) else {
+                progress.loaded += 1;
+                continue;
+            };
+            match info.load_state {
+                LoadState::Loaded => {
+                    progress.loaded += 1;
+                    progress.loaded_bytes += info.loaded_bytes.unwrap_or(0);
+                    stack.extend(info.dependencies.iter().copied());
+                }
+                LoadState::Failed(_) => progress.failed.push(id),
+                LoadState::NotLoaded | LoadState::Loading => {}
+            }
+        }
+        // Dependencies are visited in hash order, so failures are sorted to compare progress.
+        progress.failed.sort();
+
+        if progress != group.progress {
+            group.progress = progress;
+            changed = true;
+        }
+        if group.progress.loading() == 0 {
+            group.finished = true;
+            changed = true;
+            let event = LoadingGroupFinished {
+                group: name.clone(),
+                progress: group.progress.clone(),
+            };
+            finished_events.send(event.clone());
+            commands.trigger(event);
+        }
+    }
+    if changed {
+        groups.set_changed();
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{
+    io::{
+        archive::{Archive, ArchiveAssetReader, ArchiveError, PakWriter},
+        get_meta_path, AssetReaderError, AssetSourceBuilders, AssetSourceId, ErasedAssetReader,
+        MissingProcessedAssetReaderError,
+    },
+    meta::{AssetHash, ProcessedInfoMinimal},
+    processor::AssetProcessor,
+    AssetPath,
+};
+use bevy_utils::tracing::warn;
+use futures_lite::StreamExt;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Configures how the [`AssetProcessor`] packs processed assets into bundles, and where they are read from.
+///
+/// The processor still writes processed assets to the processed [`AssetSource`](crate::io::AssetSource)
+/// of every source, which it uses to decide which assets need to be processed again. Once processing
+/// has finished, the processed assets of every source are packed into pak bundles, which can be shipped
+/// instead of the processed folder and read with an [`ArchiveAssetReader`].
+///
+/// Bundles are deterministic: assets are sorted by path and assigned to bundles in order, so
+/// processing the same assets always produces the same bundles and manifest.
+///
+/// The bundles of the default source are written to `{path}/{name}_{index}.pak`, and the bundles of
+/// named sources to `{path}/{name}_{source}_{index}.pak`. The [`AssetBundleManifest`] describing them is
+/// written to `{path}/{name}.manifest.ron`.
+#[derive(Debug, Clone)]
+pub struct AssetBundleSettings {
+    /// The folder bundles are written to and read from.
+    pub path: PathBuf,
+    /// The name used for the bundle and manifest files.
+    pub name: String,
+    /// The maximum size of a bundle, in bytes.
+    ///
+    /// Assets are never split across bundles, so an asset larger than this gets a bundle of its own.
+    pub max_bundle_size: u64,
+}
+
+impl Default for AssetBundleSettings {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("bundled_assets"),
+            name: "assets".to_string(),
+            max_bundle_size: 512 * 1024 * 1024,
+        }
+    }
+}
+
+impl AssetBundleSettings {
+    /// Returns the path of the manifest file.
+    pub fn manifest_path(&self) -> PathBuf {
+        self.path.join(format!("{}.manifest.ron", self.name))
+    }
+
+    fn bundle_file_name(&self, source: &AssetSourceId, index: usize) -> String {
+        match source.as_str() {
+            None => format!("{}_{index}.pak", self.name),
+            Some(source) => format!("{}_{source}_{index}.pak", self.name),
+        }
+    }
+
+    /// Reads the [`AssetBundleManifest`] written by [`AssetProcessor::write_bundles`].
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(clippy::result_large_err)]
+    pub fn read_manifest(&self) -> Result<AssetBundleManifest, AssetBundleError> {
+        let bytes = std::fs::read(self.manifest_path())?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    /// Uses the bundles listed in the manifest as the processed [`AssetReader`](crate::io::AssetReader)
+    /// of their sources.
+    ///
+    /// Sources that are not registered in `builders` are skipped.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(clippy::result_large_err)]
+    pub fn register_processed_readers(
+        &self,
+        builders: &mut AssetSourceBuilders,
+    ) -> Result<(), AssetBundleError> {
+        let manifest = self.read_manifest()?;
+        for source in manifest.sources() {
+            let Some(builder) = builders.get_mut(source.clone()) else {
+                continue;
+            };
+            let reader = manifest.reader(&self.path, &source)?;
+            builder.processed_reader = Some(Box::new(move || Box::new(reader.clone())));
+        }
+        Ok(())
+    }
+}
+
+/// An index of the bundles written by [`AssetProcessor::write_bundles`] and of the assets they contain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetBundleManifest {
+    /// Every bundle, in the order they were written.
+    pub bundles: Vec<AssetBundleInfo>,
+    /// Every bundled asset, keyed by its [`AssetPath`].
+    pub assets: BTreeMap<String, BundledAssetInfo>,
+}
+
+/// A single bundle file in an [`AssetBundleManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetBundleInfo {
+    /// The name of the asset source the assets in this bundle belong to, or `None` for the default source.
+    pub source: Option<String>,
+    /// The file name of the bundle, relative to [`AssetBundleSettings::path`].
+    pub file: String,
+    /// The size of the bundle, in bytes.
+    pub size: u64,
+}
+
+/// The location and hashes of a single asset in an [`AssetBundleManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledAssetInfo {
+    /// The index of the bundle containing the asset in [`AssetBundleManifest::bundles`].
+    pub bundle: usize,
+    /// The offset of the processed asset bytes in the bundle.
+    pub offset: u64,
+    /// The size of the processed asset bytes.
+    pub size: u64,
+    /// The offset of the processed meta in the bundle.
+    pub meta_offset: u64,
+    /// The size of the processed meta.
+    pub meta_size: u64,
+    /// The [`ProcessedInfo::hash`](crate::meta::ProcessedInfo::hash) of the asset.
+    pub hash: AssetHash,
+    /// The [`ProcessedInfo::full_hash`](crate::meta::ProcessedInfo::full_hash) of the asset.
+    pub full_hash: AssetHash,
+}
+
+impl AssetBundleManifest {
+    /// Returns the ids of the sources that have bundles.
+    pub fn sources(&self) -> Vec<AssetSourceId<'static>> {
+        let mut sources: Vec<_> = self
+            .bundles
+            .iter()
+            .map(|bundle| AssetSourceId::new(bundle.source.clone()))
+            .collect();
+        sources.dedup();
+        sources
+    }
+
+    /// Opens the bundles of `source`, which are stored in the folder at `path`, and returns an
+    /// [`ArchiveAssetReader`] reading from them.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn reader(
+        &self,
+        path: &Path,
+        source: &AssetSourceId,
+    ) -> Result<ArchiveAssetReader, ArchiveError> {
+        let mut reader = ArchiveAssetReader::new();
+        for bundle in &self.bundles {
+            if bundle.source.as_deref() == source.as_str() {
+                reader.add_archive(Archive::open(path.join(&bundle.file))?, 0);
+            }
+        }
+        Ok(reader)
+    }
+}
+
+/// An error that occurs while writing or reading asset bundles.
+#[derive(Error, Debug)]
+pub enum AssetBundleError {
+    #[error("encountered an io error while writing or reading asset bundles: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    AssetReaderError(#[from] AssetReaderError),
+    #[error(transparent)]
+    MissingProcessedAssetReader(#[from] MissingProcessedAssetReaderError),
+    #[error("failed to read processed meta of asset {path}: {error}")]
+    InvalidProcessedMeta {
+        path: AssetPath<'static>,
+        error: ron::error::SpannedError,
+    },
+    #[error("failed to serialize the bundle manifest: {0}")]
+    SerializeManifest(#[from] ron::Error),
+    #[error("failed to deserialize the bundle manifest: {0}")]
+    DeserializeManifest(#[from] ron::error::SpannedError),
+    #[error(transparent)]
+    Archive(#[from] ArchiveError),
+}
+
+/// A processed asset that has been assigned to a bundle.
+struct BundledAsset {
+    path: AssetPath<'static>,
+    bundle: usize,
+    hash: AssetHash,
+    full_hash: AssetHash,
+}
+
+impl AssetProcessor {
+    /// Configures the processor to pack processed assets into bundles whenever it finishes processing.
+    /// Passing `None` disables bundling.
+    ///
+    /// This is usually configured with [`AssetPlugin::processed_bundles`](crate::AssetPlugin::processed_bundles).
+    pub fn set_bundle_settings(&self, settings: Option<AssetBundleSettings>) {
+        *self.data.bundle_settings.write() = settings;
+    }
+
+    /// Returns the current bundle settings. See [`AssetProcessor::set_bundle_settings`].
+    pub fn bundle_settings(&self) -> Option<AssetBundleSettings> {
+        self.data.bundle_settings.read().clone()
+    }
+
+    /// Packs the processed assets of every processed source into bundles and writes them, along with
+    /// their [`AssetBundleManifest`], to the folder configured in `settings`.
+    ///
+    /// This reads the processed assets from the processed [`AssetSource`](crate::io::AssetSource) of
+    /// each source, so it should only be called once processing has finished. Assets whose meta has no
+    /// processed info are skipped with a warning. Bundles left over from a previous run that produced
+    /// more bundles are removed.
+    pub async fn write_bundles(
+        &self,
+        settings: &AssetBundleSettings,
+    ) -> Result<AssetBundleManifest, AssetBundleError> {
+        let mut sources: Vec<_> = self.sources().iter_processed().collect();
+        sources.sort_by(|a, b| a.id().as_str().cmp(&b.id().as_str()));
+
+        async_fs::create_dir_all(&settings.path).await?;
+        let mut manifest = AssetBundleManifest::default();
+        for source in sources {
+            let reader = source.processed_reader()?;
+            let mut paths = Vec::new();
+            collect_asset_paths(reader, PathBuf::new(), &mut paths).await?;
+            paths.sort();
+
+            let first_bundle = manifest.bundles.len();
+            let mut bundles: Vec<PakWriter> = Vec::new();
+            let mut bundle_size = 0;
+            let mut assets = Vec::with_capacity(paths.len());
+            for path in paths {
+                let asset_path = AssetPath::from(path.clone()).with_source(source.id());
+                let mut asset_bytes = Vec::new();
+                reader
+                    .read(&path)
+                    .await?
+                    .read_to_end(&mut asset_bytes)
+                    .await
+                    .map_err(AssetReaderError::from)?;
+                let meta_bytes = reader.read_meta_bytes(&path).await?;
+                let Some(processed_info) = ron::de::from_bytes::<ProcessedInfoMinimal>(&meta_bytes)
+                    .map_err(|error| AssetBundleError::InvalidProcessedMeta {
+                        path: asset_path.clone(),
+                        error,
+                    })?
+                    .processed_info
+                else {
+                    warn!("Asset {asset_path} has not been processed and will not be bundled.");
+                    continue;
+                };
+
+                let size = (asset_bytes.len() + meta_bytes.len()) as u64;
+                if bundles.is_empty()
+                    || (bundle_size > 0 && bundle_size + size > settings.max_bundle_size)
+                {
+                    bundles.push(PakWriter::new());
+                    bundle_size = 0;
+                }
+                bundle_size += size;
+                let bundle = bundles.last_mut().unwrap();
+                bundle.add(&path, asset_bytes);
+                bundle.add(get_meta_path(&path), meta_bytes);
+                assets.push(BundledAsset {
+                    path: asset_path,
+                    bundle: first_bundle + bundles.len() - 1,
+                    hash: processed_info.hash,
+                    full_hash: processed_info.full_hash,
+                });
+            }
+
+            let mut layouts = Vec::with_capacity(bundles.len());
+            for (index, bundle) in bundles.iter().enumerate() {
+                let bytes = bundle.to_bytes();
+                let file = settings.bundle_file_name(&source.id(), index);
+                async_fs::write(settings.path.join(&file), &bytes).await?;
+                manifest.bundles.push(AssetBundleInfo {
+                    source: source.id().as_str().map(ToString::to_string),
+                    file,
+                    size: bytes.len() as u64,
+                });
+                layouts.push(
+                    bundle
+                        .layout()
+                        .map(|(path, offset, size)| (path.to_string(), (offset, size)))
+                        .collect::<BTreeMap<_, _>>(),
+                );
+            }
+            remove_stale_bundles(settings, &source.id(), bundles.len()).await?;
+
+            for asset in assets {
+                let layout = &layouts[asset.bundle - first_bundle];
+                let pak_path = pak_path(asset.path.path());
+                let (offset, size) = layout[&pak_path];
+                let (meta_offset, meta_size) = layout[&pak_path_meta(&pak_path)];
+                manifest.assets.insert(
+                    asset.path.to_string(),
+                    BundledAssetInfo {
+                        bundle: asset.bundle,
+                        offset,
+                        size,
+                        meta_offset,
+                        meta_size,
+                        hash: asset.hash,
+                        full_hash: asset.full_hash,
+                    },
+                );
+            }
+        }
+
+        let manifest_string = ron::ser::to_string_pretty(&manifest, PrettyConfig::default())?;
+        async_fs::write(settings.manifest_path(), manifest_string).await?;
+        Ok(manifest)
+    }
+}
+
+/// Recursively collects the paths of all assets in `reader`, starting at `path`.
+async fn collect_asset_paths(
+    reader: &dyn ErasedAssetReader,
+    path: PathBuf,
+    paths: &mut Vec<PathBuf>,
+) -> Result<(), AssetReaderError> {
+    if reader.is_directory(&path).await? {
+        let mut path_stream = reader.read_directory(&path).await?;
+        while let Some(child) = path_stream.next().await {
+            Box::pin(collect_asset_paths(reader, child, paths)).await?;
+        }
+    } else {
+        paths.push(path);
+    }
+    Ok(())
+}
+
+/// Removes the bundles of `source` with an index of `count` or more, left over from a previous run.
+async fn remove_stale_bundles(
+    settings: &AssetBundleSettings,
+    source: &AssetSourceId<'_>,
+    count: usize,
+) -> std::io::Result<()> {
+    for index in count.. {
+        let path = settings.path.join(settings.bundle_file_name(source, index));
+        match async_fs::remove_file(path).await {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => break,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path of `path` in a pak archive, as written by [`PakWriter`].
+fn pak_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn pak_path_meta(pak_path: &str) -> String {
+    format!("{pak_path}.meta")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::AssetSource,
+        meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessedInfo},
+    };
+    use bevy_tasks::block_on;
+
+    fn write_processed(dir: &Path, path: &str, contents: &str, hash: u8) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, contents).unwrap();
+        let mut meta = AssetMeta::<(), ()>::new(AssetAction::Ignore);
+        meta.processed_info = Some(ProcessedInfo {
+            hash: [hash; 32],
+            full_hash: [hash; 32],
+            process_dependencies: Vec::new(),
+        });
+        std::fs::write(get_meta_path(&path), AssetMetaDyn::serialize(&meta)).unwrap();
+    }
+
+    #[test]
+    fn bundles_are_deterministic_and_readable() {
+        let root = std::env::temp_dir().join(format!("bevy_asset_bundles_{}", std::process::id()));
+        let processed = root.join("processed");
+        write_processed(&processed, "a.txt", "a", 1);
+        write_processed(&processed, "levels/b.txt", "bb", 2);
+        write_processed(&processed, "levels/c.txt", "ccc", 3);
+
+        let mut builders = AssetSourceBuilders::default();
+        let processed_path = processed.to_str().unwrap().to_string();
+        builders.insert(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(AssetSource::get_default_reader(processed_path.clone()))
+                .with_processed_reader(AssetSource::get_default_reader(processed_path.clone()))
+                .with_processed_writer(AssetSource::get_default_writer(processed_path)),
+        );
+        let processor = AssetProcessor::new(&mut builders);
+
+        // Large enough for the first two assets and their meta files, but not for all three.
+        let file_size = |path: &str| std::fs::metadata(processed.join(path)).unwrap().len();
+        let settings = AssetBundleSettings {
+            path: root.join("bundles"),
+            name: "test".to_string(),
+            max_bundle_size: file_size("a.txt")
+                + file_size("a.txt.meta")
+                + file_size("levels/b.txt")
+                + file_size("levels/b.txt.meta"),
+        };
+        let manifest = block_on(processor.write_bundles(&settings)).unwrap();
+        assert_eq!(manifest.bundles.len(), 2);
+        assert_eq!(manifest.bundles[0].file, "test_0.pak");
+        assert_eq!(manifest.assets.len(), 3);
+        assert_eq!(manifest.assets["levels/c.txt"].bundle, 1);
+        let b = &manifest.assets["levels/b.txt"];
+        assert_eq!(b.size, 2);
+        assert_eq!(b.hash, [2; 32]);
+
+        let first_run = std::fs::read(settings.path.join("test_0.pak")).unwrap();
+        let second_manifest = block_on(processor.write_bundles(&settings)).unwrap();
+        assert_eq!(manifest, second_manifest);
+        assert_eq!(
+            first_run,
+            std::fs::read(settings.path.join("test_0.pak")).unwrap()
+        );
+        assert_eq!(settings.read_manifest().unwrap(), manifest);
+
+        let reader = manifest
+            .reader(&settings.path, &AssetSourceId::Default)
+            .unwrap();
+        for (path, contents) in [
+            ("a.txt", "a"),
+            ("levels/b.txt", "bb"),
+            ("levels/c.txt", "ccc"),
+        ] {
+            let mut bytes = Vec::new();
+            block_on(async {
+                let mut asset = reader.read(Path::new(path)).await.unwrap();
+                asset.read_to_end(&mut bytes).await.unwrap();
+            });
+            assert_eq!(bytes, contents.as_bytes());
+
+            // The offsets in the manifest point at the asset in its bundle.
+            let info = &manifest.assets[path];
+            let bundle =
+                std::fs::read(settings.path.join(&manifest.bundles[info.bundle].file)).unwrap();
+            let start = info.offset as usize;
+            assert_eq!(
+                &bundle[start..start + info.size as usize],
+                contents.as_bytes()
+            );
+        }
+        let meta = block_on(reader.read_meta_bytes(Path::new("levels/c.txt"))).unwrap();
+        let meta: ProcessedInfoMinimal = ron::de::from_bytes(&meta).unwrap();
+        assert_eq!(meta.processed_info.unwrap().hash, [3; 32]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn unprocessed_assets_are_skipped() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_bundles_unprocessed_{}",
+            std::process::id()
+        ));
+        let processed = root.join("processed");
+        write_processed(&processed, "a.txt", "a", 1);
+        write_processed(&processed, "b.txt", "b", 2);
+        let meta = AssetMeta::<(), ()>::new(AssetAction::Ignore);
+        std::fs::write(
+            get_meta_path(&processed.join("b.txt")),
+            AssetMetaDyn::serialize(&meta),
+        )
+        .unwrap();
+
+        let mut builders = AssetSourceBuilders::default();
+        let processed_path = processed.to_str().unwrap().to_string();
+        builders.insert(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(AssetSource::get_default_reader(processed_path.clone()))
+                .with_processed_reader(AssetSource::get_default_reader(processed_path.clone()))
+                .with_processed_writer(AssetSource::get_default_writer(processed_path)),
+        );
+        let processor = AssetProcessor::new(&mut builders);
+        let settings = AssetBundleSettings {
+            path: root.join("bundles"),
+            name: "test".to_string(),
+            max_bundle_size: u64::MAX,
+        };
+        let manifest = block_on(processor.write_bundles(&settings)).unwrap();
+        assert_eq!(manifest.bundles.len(), 1);
+        assert_eq!(manifest.assets.len(), 1);
+        assert_eq!(manifest.assets["a.txt"].hash, [1; 32]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
+mod bundle;
 mod log;
 mod process;
 
+pub use bundle::*;
 pub use log::*;
 pub use process::*;
 
@@ -69,6 +71,7 @@ pub struct AssetProcessorData {
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
+    bundle_settings: RwLock<Option<AssetBundleSettings>>,
     initialized_sender: async_broadcast::Sender<()>,
     initialized_receiver: async_broadcast::Receiver<()>,
     finished_sender: async_broadcast::Sender<()>,
@@ -435,6 +438,16 @@ impl AssetProcessor {
         self.try_reprocessing_queued().await;
         // clean up metadata in asset server
         self.server.data.infos.write().consume_handle_drop_events();
+        if let Some(settings) = self.bundle_settings() {
+            match self.write_bundles(&settings).await {
+                Ok(manifest) => debug!(
+                    "Wrote {} assets to {} bundles",
+                    manifest.assets.len(),
+                    manifest.bundles.len()
+                ),
+                Err(err) => error!("Failed to write asset bundles: {err}"),
+            }
+        }
         self.set_state(ProcessorState::Finished).await;
     }
 
@@ -980,6 +993,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            bundle_settings: Default::default(),
         }
     }
 
@@ -27,6 +27,11 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependants_waiting_on_load: HashSet<UntypedAssetId>,
     dependants_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset. This is set when the asset is loaded.
+    pub(crate) dependencies: HashSet<UntypedAssetId>,
+    /// The number of bytes read from the asset source to load this asset. This is set when the
+    /// asset is loaded, and is `None` for labeled assets and assets that weren't loaded from a source.
+    pub(crate) loaded_bytes: Option<u64>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -54,6 +59,8 @@ impl AssetInfo {
             loader_dependencies: HashMap::default(),
             dependants_waiting_on_load: HashSet::default(),
             dependants_waiting_on_recursive_dep_load: HashSet::default(),
+            dependencies: HashSet::default(),
+            loaded_bytes: None,
             handle_drops_to_skip: 0,
         }
     }
@@ -357,6 +364,23 @@ impl AssetInfos {
         }
     }
 
+    /// Creates a new strong handle for a loaded asset whose handles were all dropped, which keeps it
+    /// loaded. Returns `None` if the drop should be processed with [`Self::process_handle_drop`].
+    pub(crate) fn revive_dropped_handle(
+        &mut self,
+        id: UntypedAssetId,
+    ) -> Option<Arc<StrongHandle>> {
+        let info = self.infos.get_mut(&id)?;
+        // If a new handle was created since the drop, the drop is skipped anyway.
+        if info.handle_drops_to_skip > 0 || !matches!(info.load_state, LoadState::Loaded) {
+            return None;
+        }
+        let provider = self.handle_providers.get(&id.type_id())?;
+        let handle = provider.get_handle(id.internal(), true, info.path.clone(), None);
+        info.weak_handle = Arc::downgrade(&handle);
+        Some(handle)
+    }
+
     /// Returns `true` if the asset should be removed from the collection.
     pub(crate) fn process_handle_drop(&mut self, id: UntypedAssetId) -> bool {
         Self::process_handle_drop_internal(
@@ -384,6 +408,7 @@ impl AssetInfos {
 
         loaded_asset.value.insert(loaded_asset_id, world);
         let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loading_deps.clone();
         let mut failed_deps = HashSet::new();
         let mut loading_rec_deps = loading_deps.clone();
         let mut failed_rec_deps = HashSet::new();
@@ -471,6 +496,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -6,6 +6,7 @@ use crate::{
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
+        STACK_FUTURE_SIZE,
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
@@ -22,11 +23,16 @@ use bevy_tasks::IoTaskPool;
 use bevy_utils::tracing::{error, info};
 use bevy_utils::{CowArc, HashSet};
 use crossbeam_channel::{Receiver, Sender};
+use futures_io::{AsyncRead, AsyncSeek};
 use futures_lite::StreamExt;
+pub(crate) use info::AssetInfos;
 use info::*;
 use loaders::*;
 use parking_lot::RwLock;
+use stackfuture::StackFuture;
 use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use std::{any::Any, path::PathBuf};
 use std::{any::TypeId, path::Path, sync::Arc};
 use thiserror::Error;
@@ -497,7 +503,7 @@ impl AssetServer {
 
         let path = path.into_owned();
         let path_clone = path.clone();
-        let (mut meta, loader, mut reader) = self
+        let (mut meta, loader, reader) = self
             .get_meta_loader_and_reader(&path_clone, asset_type_id)
             .await
             .inspect_err(|e| {
@@ -584,8 +590,9 @@ impl AssetServer {
             (*meta_transform)(&mut *meta);
         }
 
+        let mut reader = CountingReader::new(reader);
         match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
+            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut reader, true, false)
             .await
         {
             Ok(loaded_asset) => {
@@ -611,6 +618,9 @@ impl AssetServer {
                     handle.unwrap()
                 };
 
+                if let Some(info) = self.data.infos.write().get_mut(base_handle.id()) {
+                    info.loaded_bytes = Some(reader.bytes);
+                }
                 self.send_loaded_asset(base_handle.id(), loaded_asset);
                 Ok(final_handle)
             }
@@ -1506,3 +1516,54 @@ impl std::fmt::Debug for AssetServer {
 /// This is appended to asset sources when loading a [`LoadedUntypedAsset`]. This provides a unique
 /// source for a given [`AssetPath`].
 const UNTYPED_SOURCE_SUFFIX: &str = "--untyped";
+
+/// A [`Reader`] that counts the bytes read from the wrapped reader, which is reported as the
+/// size of loaded assets.
+struct CountingReader<R> {
+    reader: R,
+    bytes: u64,
+}
+
+impl<R> CountingReader<R> {
+    fn new(reader: R) -> Self {
+        Self { reader, bytes: 0 }
+    }
+}
+
+impl<R: Reader> AsyncRead for CountingReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let result = Pin::new(&mut self.reader).poll_read(cx, buf);
+        if let Poll::Ready(Ok(read)) = result {
+            self.bytes += read as u64;
+        }
+        result
+    }
+}
+
+impl<R: Reader> AsyncSeek for CountingReader<R> {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: std::io::SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        Pin::new(&mut self.reader).poll_seek(cx, pos)
+    }
+}
+
+impl<R: Reader> Reader for CountingReader<R> {
+    fn read_to_end<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<u8>,
+    ) -> StackFuture<'a, std::io::Result<usize>, STACK_FUTURE_SIZE> {
+        // The wrapped future already fills the stack space, so this one is boxed.
+        StackFuture::from_or_box(async move {
+            let read = self.reader.read_to_end(buf).await?;
+            self.bytes += read as u64;
+            Ok(read)
+        })
+    }
+}
@@ -26,7 +26,10 @@ pub mod prelude {
 }
 
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    world::{apply_async_commands, AsyncWorld},
+};
 use std::marker::PhantomData;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -46,6 +49,8 @@ impl Plugin for TypeRegistrationPlugin {
 
 /// Setup of default task pools: [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool),
 /// [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool), [`IoTaskPool`](bevy_tasks::IoTaskPool).
+///
+/// Also applies the commands sent by [`AsyncWorld`] handles once per frame, during [`First`].
 #[derive(Default)]
 pub struct TaskPoolPlugin {
     /// Options for the [`TaskPool`](bevy_tasks::TaskPool) created at application start.
@@ -53,12 +58,16 @@ pub struct TaskPoolPlugin {
 }
 
 impl Plugin for TaskPoolPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Setup the default bevy task pools
         self.task_pool_options.create_default_pools();
 
+        // Apply the commands sent from async tasks once per frame
+        app.init_resource::<AsyncWorld>()
+            .add_systems(First, apply_async_commands);
+
         #[cfg(not(target_arch = "wasm32"))]
-        _app.add_systems(Last, tick_global_task_pools);
+        app.add_systems(Last, tick_global_task_pools);
     }
 }
 /// A dummy type that is [`!Send`](Send), to force systems to run on the main thread.
@@ -155,4 +164,36 @@ mod tests {
         let frame_count = app.world().resource::<FrameCount>();
         assert_eq!(1, frame_count.0);
     }
+
+    #[test]
+    fn applies_async_commands() {
+        #[derive(Component)]
+        struct Spawned;
+
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default());
+
+        let world = app.world().resource::<AsyncWorld>().clone();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            let entity = world.spawn(Spawned).await;
+            world.next_frame().await;
+            world.despawn(entity).await
+        });
+
+        for _ in 0..1000 {
+            if task.is_finished() {
+                break;
+            }
+            app.update();
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        assert!(bevy_tasks::block_on(task));
+        assert_eq!(
+            app.world_mut()
+                .query::<&Spawned>()
+                .iter(app.world())
+                .count(),
+            0
+        );
+    }
 }
@@ -0,0 +1,70 @@
+use bevy_app::prelude::*;
+use bevy_ecs::world::World;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds archetype, table and component memory diagnostics to an App.
+///
+/// The measurements are taken from [`World::memory_stats`], which walks every storage of the world,
+/// so this plugin is meant for debugging archetype fragmentation rather than for shipping builds.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct WorldMemoryDiagnosticsPlugin;
+
+impl Plugin for WorldMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::MAX_ARCHETYPES_PER_COMPONENT))
+            .register_diagnostic(Diagnostic::new(Self::USED_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::FRAGMENTATION).with_suffix("%"))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl WorldMemoryDiagnosticsPlugin {
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("world/archetype_count");
+    pub const EMPTY_ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("world/empty_archetype_count");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("world/table_count");
+    pub const EMPTY_TABLE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("world/empty_table_count");
+    pub const MAX_ARCHETYPES_PER_COMPONENT: DiagnosticPath =
+        DiagnosticPath::const_new("world/max_archetypes_per_component");
+    pub const USED_BYTES: DiagnosticPath = DiagnosticPath::const_new("world/used_bytes");
+    pub const ALLOCATED_BYTES: DiagnosticPath = DiagnosticPath::const_new("world/allocated_bytes");
+    pub const FRAGMENTATION: DiagnosticPath = DiagnosticPath::const_new("world/fragmentation");
+
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, world: &World) {
+        let stats = world.memory_stats();
+        let memory = stats.total_memory();
+
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || stats.archetypes.len() as f64);
+        diagnostics.add_measurement(&Self::EMPTY_ARCHETYPE_COUNT, || {
+            stats.empty_archetype_count() as f64
+        });
+        diagnostics.add_measurement(&Self::TABLE_COUNT, || stats.tables.len() as f64);
+        diagnostics.add_measurement(&Self::EMPTY_TABLE_COUNT, || {
+            stats.empty_table_count() as f64
+        });
+        diagnostics.add_measurement(&Self::MAX_ARCHETYPES_PER_COMPONENT, || {
+            stats
+                .components
+                .iter()
+                .map(|component| component.archetype_count)
+                .max()
+                .unwrap_or(0) as f64
+        });
+        diagnostics.add_measurement(&Self::USED_BYTES, || memory.used_bytes as f64);
+        diagnostics.add_measurement(&Self::ALLOCATED_BYTES, || memory.allocated_bytes as f64);
+        diagnostics.add_measurement(&Self::FRAGMENTATION, || {
+            memory.fragmentation() as f64 * 100.0
+        });
+    }
+}
@@ -1,7 +1,14 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, ExprPath, Ident, LitStr, Path, Result};
+use syn::{
+    parenthesized,
+    parse::Parse,
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    token::{Comma, Paren},
+    Data, DeriveInput, ExprPath, Fields, Ident, LitStr, Member, Path, Result, Type,
+};
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -23,6 +30,7 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #bevy_ecs_path::component::StorageType::SparseSet;
+            type Mutability = #bevy_ecs_path::component::Mutable;
         }
     })
 }
@@ -49,18 +57,48 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
-    let attrs = match parse_component_attr(&ast) {
+    let mut attrs = match parse_component_attr(&ast) {
         Ok(attrs) => attrs,
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &mut attrs, &bevy_ecs_path) {
+        Ok(relationship) => relationship,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &mut attrs, &bevy_ecs_path)
+    {
+        Ok(relationship_target) => relationship_target,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
+    let mutability = if attrs.immutable {
+        quote! { #bevy_ecs_path::component::Immutable }
+    } else {
+        quote! { #bevy_ecs_path::component::Mutable }
+    };
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
     let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
     let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
 
+    let register_required = attrs.requires.into_iter().flatten().map(|require| {
+        let ident = &require.path;
+        let constructor = match require.func {
+            Some(func) => quote! { #func },
+            None => quote! { <#ident as Default>::default },
+        };
+        quote! {
+            components.register_required_components_manual::<Self, #ident>(
+                storages,
+                required_components,
+                #constructor,
+            );
+        }
+    });
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -69,9 +107,25 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
+    let clone_handler = if relationship_target.is_some() {
+        // Cloning the sources of a relationship target would leave them pointing to the original entity.
+        quote! { #bevy_ecs_path::component::ComponentCloneHandler::Ignore }
+    } else {
+        quote! {
+            use #bevy_ecs_path::component::{ComponentCloneBase, ComponentCloneViaClone, ComponentCloneViaCloneAndMapEntities};
+            (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
+                .get_component_clone_handler()
+        }
+    };
+
     TokenStream::from(quote! {
+        #relationship
+
+        #relationship_target
+
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
+            type Mutability = #mutability;
 
             #[allow(unused_variables)]
             fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
@@ -80,12 +134,30 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 #on_replace
                 #on_remove
             }
+
+            #[allow(unused_variables)]
+            fn register_required_components(
+                requiree: #bevy_ecs_path::component::ComponentId,
+                components: &mut #bevy_ecs_path::component::Components,
+                storages: &mut #bevy_ecs_path::storage::Storages,
+                required_components: &mut #bevy_ecs_path::component::RequiredComponents,
+            ) {
+                #(#register_required)*
+            }
+
+            fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
+                #clone_handler
+            }
         }
     })
 }
 
 pub const COMPONENT: &str = "component";
+pub const REQUIRE: &str = "require";
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
 pub const STORAGE: &str = "storage";
+pub const IMMUTABLE: &str = "immutable";
 pub const ON_ADD: &str = "on_add";
 pub const ON_INSERT: &str = "on_insert";
 pub const ON_REPLACE: &str = "on_replace";
@@ -93,10 +165,19 @@ pub const ON_REMOVE: &str = "on_remove";
 
 struct Attrs {
     storage: StorageTy,
+    immutable: bool,
     on_add: Option<ExprPath>,
     on_insert: Option<ExprPath>,
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
+    requires: Option<Punctuated<Require, Comma>>,
+    relationship: Option<Path>,
+    relationship_target: Option<RelationshipTargetAttr>,
+}
+
+struct RelationshipTargetAttr {
+    relationship: Path,
+    despawn: Option<Ident>,
 }
 
 #[derive(Clone, Copy)]
@@ -112,12 +193,76 @@ const SPARSE_SET: &str = "SparseSet";
 fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     let mut attrs = Attrs {
         storage: StorageTy::Table,
+        immutable: false,
         on_add: None,
         on_insert: None,
         on_replace: None,
         on_remove: None,
+        requires: None,
+        relationship: None,
+        relationship_target: None,
     };
 
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident(REQUIRE) {
+            let punctuated =
+                attr.parse_args_with(Punctuated::<Require, Comma>::parse_terminated)?;
+            match attrs.requires.as_mut() {
+                Some(requires) => requires.extend(punctuated),
+                None => attrs.requires = Some(punctuated),
+            }
+        }
+    }
+
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(RELATIONSHIP)) {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                attrs.relationship = Some(nested.value()?.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+    }
+
+    for meta in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(RELATIONSHIP_TARGET))
+    {
+        let mut relationship = None;
+        let mut despawn = None;
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP) {
+                relationship = Some(nested.value()?.parse::<Path>()?);
+                Ok(())
+            } else if nested.path.is_ident(DESPAWN) {
+                let value = nested.value()?.parse::<LitStr>()?;
+                match value.value().as_str() {
+                    CASCADE | ORPHAN | DETACH => {
+                        despawn = Some(Ident::new(&value.value(), value.span()));
+                        Ok(())
+                    }
+                    s => Err(nested.error(format!(
+                        "Invalid despawn behavior `{s}`, expected '{CASCADE}', '{ORPHAN}' or '{DETACH}'.",
+                    ))),
+                }
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+        let Some(relationship) = relationship else {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "Missing `relationship = ...` in `relationship_target` attribute",
+            ));
+        };
+        attrs.relationship_target = Some(RelationshipTargetAttr {
+            relationship,
+            despawn,
+        });
+    }
+
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
         meta.parse_nested_meta(|nested| {
             if nested.path.is_ident(STORAGE) {
@@ -131,6 +276,9 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                     }
                 };
                 Ok(())
+            } else if nested.path.is_ident(IMMUTABLE) {
+                attrs.immutable = true;
+                Ok(())
             } else if nested.path.is_ident(ON_ADD) {
                 attrs.on_add = Some(nested.value()?.parse::<ExprPath>()?);
                 Ok(())
@@ -152,6 +300,161 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     Ok(attrs)
 }
 
+/// A single entry of the `#[require(...)]` attribute: `Type` or `Type(constructor)`.
+struct Require {
+    path: Path,
+    func: Option<Path>,
+}
+
+impl Parse for Require {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let path = input.parse::<Path>()?;
+        let func = if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse::<Path>()?)
+        } else {
+            None
+        };
+        Ok(Require { path, func })
+    }
+}
+
+// values for `despawn` attribute of `relationship_target`
+const DESPAWN: &str = "despawn";
+const CASCADE: &str = "Cascade";
+const ORPHAN: &str = "Orphan";
+const DETACH: &str = "Detach";
+
+/// Returns the single field of a relationship struct, as a member to access it and its type.
+fn relationship_field<'a>(ast: &'a DeriveInput, attribute: &str) -> Result<(Member, &'a Type)> {
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            format!("`{attribute}` can only be derived for structs"),
+        ));
+    };
+    let mut fields = data.fields.iter();
+    match (fields.next(), fields.next(), &data.fields) {
+        (Some(field), None, Fields::Named(_)) => {
+            Ok((Member::Named(field.ident.clone().unwrap()), &field.ty))
+        }
+        (Some(field), None, Fields::Unnamed(_)) => Ok((Member::Unnamed(0.into()), &field.ty)),
+        _ => Err(syn::Error::new_spanned(
+            &ast.ident,
+            format!("`{attribute}` components must have exactly one field"),
+        )),
+    }
+}
+
+fn take_relationship_hooks(
+    attrs: &mut Attrs,
+    ast: &DeriveInput,
+    on_insert: Option<ExprPath>,
+    on_replace: ExprPath,
+) -> Result<()> {
+    if attrs.on_replace.is_some() || (on_insert.is_some() && attrs.on_insert.is_some()) {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "Custom on_insert and on_replace hooks are not supported on relationships",
+        ));
+    }
+    if on_insert.is_some() {
+        attrs.on_insert = on_insert;
+    }
+    attrs.on_replace = Some(on_replace);
+    Ok(())
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &mut Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = attrs.relationship.clone() else {
+        return Ok(None);
+    };
+    let (field, _) = relationship_field(ast, RELATIONSHIP)?;
+    take_relationship_hooks(
+        attrs,
+        ast,
+        Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert)),
+        parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace),
+    )?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#field
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #field: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &mut Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = attrs.relationship_target.take() else {
+        return Ok(None);
+    };
+    let (field, collection) = relationship_field(ast, RELATIONSHIP_TARGET)?;
+    take_relationship_hooks(
+        attrs,
+        ast,
+        None,
+        parse_quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace),
+    )?;
+
+    let relationship = &relationship_target.relationship;
+    let despawn_behavior = relationship_target.despawn.map(|despawn| {
+        quote! {
+            const DESPAWN_BEHAVIOR: #bevy_ecs_path::relationship::RelationshipDespawnBehavior =
+                #bevy_ecs_path::relationship::RelationshipDespawnBehavior::#despawn;
+        }
+    });
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #despawn_behavior
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#field
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#field
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #field: collection
+                }
+            }
+        }
+    }))
+}
+
 fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     let storage_type = match ty {
         StorageTy::Table => Ident::new("Table", Span::call_site()),
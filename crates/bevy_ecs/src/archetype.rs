@@ -21,10 +21,12 @@
 
 use crate::{
     bundle::BundleId,
-    component::{ComponentId, Components, StorageType},
+    component::{ComponentId, Components, RequiredComponentConstructor, StorageType},
     entity::{Entity, EntityLocation},
     observer::Observers,
-    storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
+    storage::{
+        ImmutableSparseSet, MemoryUsage, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow,
+    },
 };
 use std::{
     hash::Hash,
@@ -120,8 +122,19 @@ pub(crate) struct AddBundle {
     /// For each component iterated in the same order as the source [`Bundle`](crate::bundle::Bundle),
     /// indicate if the component is newly added to the target archetype or if it already existed
     pub bundle_status: Vec<ComponentStatus>,
+    /// The constructors of the required components that are missing from the source archetype
+    pub required_components: Vec<RequiredComponentConstructor>,
     pub added: Vec<ComponentId>,
     pub mutated: Vec<ComponentId>,
+    /// The components inserted by the bundle: every component of the bundle itself,
+    /// followed by the required components that were missing
+    pub inserted: Vec<ComponentId>,
+}
+
+impl AddBundle {
+    pub(crate) fn iter_inserted(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.inserted.iter().copied()
+    }
 }
 
 /// This trait is used to report the status of [`Bundle`](crate::bundle::Bundle) components
@@ -200,21 +213,26 @@ impl Edges {
     ///
     /// [`EntityWorldMut::insert`]: crate::world::EntityWorldMut::insert
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn insert_add_bundle(
         &mut self,
         bundle_id: BundleId,
         archetype_id: ArchetypeId,
         bundle_status: Vec<ComponentStatus>,
+        required_components: Vec<RequiredComponentConstructor>,
         added: Vec<ComponentId>,
         mutated: Vec<ComponentId>,
+        inserted: Vec<ComponentId>,
     ) {
         self.add_bundle.insert(
             bundle_id,
             AddBundle {
                 archetype_id,
                 bundle_status,
+                required_components,
                 added,
                 mutated,
+                inserted,
             },
         );
     }
@@ -326,6 +344,7 @@ bitflags::bitflags! {
         const ON_INSERT_OBSERVER = (1 << 5);
         const ON_REPLACE_OBSERVER = (1 << 6);
         const ON_REMOVE_OBSERVER = (1 << 7);
+        const ON_DESPAWN_OBSERVER = (1 << 8);
     }
 }
 
@@ -420,6 +439,16 @@ impl Archetype {
         &self.entities
     }
 
+    /// Returns the heap memory used by the entity list of this archetype.
+    ///
+    /// The component values are stored in the archetype's [`Table`](crate::storage::Table) or in [`SparseSet`]s
+    /// and are not included.
+    ///
+    /// [`SparseSet`]: crate::storage::SparseSet
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.entities)
+    }
+
     /// Gets an iterator of all of the components stored in [`Table`]s.
     ///
     /// All of the IDs are unique.
@@ -648,6 +677,14 @@ impl Archetype {
     pub fn has_remove_observer(&self) -> bool {
         self.flags().contains(ArchetypeFlags::ON_REMOVE_OBSERVER)
     }
+
+    /// Returns true if any of the components in this archetype have at least one [`OnDespawn`] observer
+    ///
+    /// [`OnDespawn`]: crate::world::OnDespawn
+    #[inline]
+    pub fn has_despawn_observer(&self) -> bool {
+        self.flags().contains(ArchetypeFlags::ON_DESPAWN_OBSERVER)
+    }
 }
 
 /// The next [`ArchetypeId`] in an [`Archetypes`] collection.
@@ -11,7 +11,10 @@ use crate::{
         AddBundle, Archetype, ArchetypeId, Archetypes, BundleComponentStatus, ComponentStatus,
         SpawnBundleStatus,
     },
-    component::{Component, ComponentId, Components, StorageType, Tick},
+    component::{
+        Component, ComponentId, Components, RequiredComponentConstructor, RequiredComponents,
+        StorageType, Tick,
+    },
     entity::{Entities, Entity, EntityLocation},
     observer::Observers,
     prelude::World,
@@ -307,8 +310,11 @@ pub struct BundleInfo {
     id: BundleId,
     // SAFETY: Every ID in this list must be valid within the World that owns the BundleInfo,
     // must have its storage initialized (i.e. columns created in tables, sparse set created),
-    // and must be in the same order as the source bundle type writes its components in.
+    // and the range (0..explicit_components_len) must be in the same order as the source bundle
+    // type writes its components in.
     component_ids: Vec<ComponentId>,
+    required_components: Vec<RequiredComponentConstructor>,
+    explicit_components_len: usize,
 }
 
 impl BundleInfo {
@@ -322,7 +328,7 @@ impl BundleInfo {
     unsafe fn new(
         bundle_type_name: &'static str,
         components: &Components,
-        component_ids: Vec<ComponentId>,
+        mut component_ids: Vec<ComponentId>,
         id: BundleId,
     ) -> BundleInfo {
         let mut deduped = component_ids.clone();
@@ -351,11 +357,35 @@ impl BundleInfo {
             panic!("Bundle {bundle_type_name} has duplicate components: {names}");
         }
 
+        let explicit_components_len = component_ids.len();
+        let mut required_components = RequiredComponents::default();
+        for component_id in component_ids.iter().copied() {
+            // SAFETY: caller has verified that all ids are valid
+            let info = unsafe { components.get_info_unchecked(component_id) };
+            required_components.merge(info.required_components());
+        }
+        required_components.remove_explicit_components(&component_ids);
+        // Required components are ordered by id so that they are always inserted in the same order.
+        // Their storage was initialized when they were registered as required components.
+        let required_components = required_components
+            .sorted()
+            .into_iter()
+            .map(|(component_id, constructor)| {
+                component_ids.push(component_id);
+                constructor
+            })
+            .collect();
+
         // SAFETY: The caller ensures that component_ids:
         // - is valid for the associated world
         // - has had its storage initialized
         // - is in the same order as the source bundle type
-        BundleInfo { id, component_ids }
+        BundleInfo {
+            id,
+            component_ids,
+            required_components,
+            explicit_components_len,
+        }
     }
 
     /// Returns a value identifying the associated [`Bundle`] type.
@@ -365,17 +395,42 @@ impl BundleInfo {
     }
 
     /// Returns the [ID](ComponentId) of each component stored in this bundle.
+    ///
+    /// This does not include the required components of the bundle, see [`Self::contributed_components`].
     #[inline]
     pub fn components(&self) -> &[ComponentId] {
-        &self.component_ids
+        &self.component_ids[0..self.explicit_components_len]
     }
 
     /// Returns an iterator over the [ID](ComponentId) of each component stored in this bundle.
+    ///
+    /// This does not include the required components of the bundle, see [`Self::iter_contributed_components`].
     #[inline]
     pub fn iter_components(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.components().iter().cloned()
+    }
+
+    /// Returns the [ID](ComponentId) of each component contributed by this bundle: the components
+    /// stored in the bundle followed by its [required components](Component#required-components).
+    #[inline]
+    pub fn contributed_components(&self) -> &[ComponentId] {
+        &self.component_ids
+    }
+
+    /// Returns an iterator over the [ID](ComponentId) of each component contributed by this bundle.
+    /// See [`Self::contributed_components`].
+    #[inline]
+    pub fn iter_contributed_components(&self) -> impl Iterator<Item = ComponentId> + '_ {
         self.component_ids.iter().cloned()
     }
 
+    /// Returns the [ID](ComponentId) of each [required component](Component#required-components)
+    /// this bundle inserts when it is missing from the target entity.
+    #[inline]
+    pub fn required_components(&self) -> &[ComponentId] {
+        &self.component_ids[self.explicit_components_len..]
+    }
+
     /// This writes components from a given [`Bundle`] to the given entity.
     ///
     /// # Safety
@@ -391,7 +446,9 @@ impl BundleInfo {
     /// ownership of the entity's current archetype.
     ///
     /// `table` must be the "new" table for `entity`. `table_row` must have space allocated for the
-    /// `entity`, `bundle` must match this [`BundleInfo`]'s type
+    /// `entity`, `bundle` must match this [`BundleInfo`]'s type.
+    /// `required_components` must only contain constructors for components that are missing from
+    /// the entity's original archetype, and whose storage is present in `table` / `sparse_sets`.
     #[inline]
     #[allow(clippy::too_many_arguments)]
     unsafe fn write_components<T: DynamicBundle, S: BundleComponentStatus>(
@@ -399,6 +456,7 @@ impl BundleInfo {
         table: &mut Table,
         sparse_sets: &mut SparseSets,
         bundle_component_status: &S,
+        required_components: &[RequiredComponentConstructor],
         entity: Entity,
         table_row: TableRow,
         change_tick: Tick,
@@ -455,6 +513,73 @@ impl BundleInfo {
             }
             bundle_component += 1;
         });
+
+        for required_component in required_components {
+            // SAFETY: The caller ensures the required component is missing from the entity
+            // and that its storage exists in `table` / `sparse_sets`.
+            unsafe {
+                required_component.initialize(
+                    table,
+                    sparse_sets,
+                    change_tick,
+                    table_row,
+                    entity,
+                    #[cfg(feature = "track_change_detection")]
+                    caller,
+                );
+            }
+        }
+    }
+
+    /// Internal method to initialize a required component from an [`OwningPtr`]. This should ultimately be called
+    /// in the context of [`BundleInfo::write_components`], via [`RequiredComponentConstructor::initialize`].
+    ///
+    /// # Safety
+    ///
+    /// `component_ptr` must point to a required component value that matches the given `component_id`. The `storage_type` must match
+    /// the type associated with `component_id`. The entity must not already have a value for `component_id`.
+    /// `table` must be the table of `entity` and `table_row` its allocated row.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) unsafe fn initialize_required_component(
+        table: &mut Table,
+        sparse_sets: &mut SparseSets,
+        change_tick: Tick,
+        table_row: TableRow,
+        entity: Entity,
+        component_id: ComponentId,
+        storage_type: StorageType,
+        component_ptr: OwningPtr,
+        #[cfg(feature = "track_change_detection")] caller: &'static Location<'static>,
+    ) {
+        match storage_type {
+            StorageType::Table => {
+                let column =
+                    // SAFETY: If component_id is in required_components, BundleInfo::new requires that
+                    // the target table contains the component.
+                    unsafe { table.get_column_mut(component_id).debug_checked_unwrap() };
+                column.initialize(
+                    table_row,
+                    component_ptr,
+                    change_tick,
+                    #[cfg(feature = "track_change_detection")]
+                    caller,
+                );
+            }
+            StorageType::SparseSet => {
+                let sparse_set =
+                    // SAFETY: If component_id is in required_components, BundleInfo::new requires that
+                    // a sparse set exists for the component.
+                    unsafe { sparse_sets.get_mut(component_id).debug_checked_unwrap() };
+                sparse_set.insert(
+                    entity,
+                    component_ptr,
+                    change_tick,
+                    #[cfg(feature = "track_change_detection")]
+                    caller,
+                );
+            }
+        }
     }
 
     /// Adds a bundle to the given archetype and returns the resulting archetype. This could be the
@@ -475,12 +600,13 @@ impl BundleInfo {
         }
         let mut new_table_components = Vec::new();
         let mut new_sparse_set_components = Vec::new();
-        let mut bundle_status = Vec::with_capacity(self.component_ids.len());
+        let mut bundle_status = Vec::with_capacity(self.explicit_components_len);
+        let mut added_required_components = Vec::new();
         let mut added = Vec::new();
         let mut mutated = Vec::new();
 
         let current_archetype = &mut archetypes[archetype_id];
-        for component_id in self.component_ids.iter().cloned() {
+        for component_id in self.iter_components() {
             if current_archetype.contains(component_id) {
                 bundle_status.push(ComponentStatus::Mutated);
                 mutated.push(component_id);
@@ -496,10 +622,37 @@ impl BundleInfo {
             }
         }
 
+        let mut inserted = self.components().to_vec();
+        for (index, component_id) in self.required_components().iter().copied().enumerate() {
+            if !current_archetype.contains(component_id) {
+                added_required_components.push(self.required_components[index].clone());
+                added.push(component_id);
+                inserted.push(component_id);
+                // SAFETY: component_id exists
+                let component_info = unsafe { components.get_info_unchecked(component_id) };
+                match component_info.storage_type() {
+                    StorageType::Table => {
+                        new_table_components.push(component_id);
+                    }
+                    StorageType::SparseSet => {
+                        new_sparse_set_components.push(component_id);
+                    }
+                }
+            }
+        }
+
         if new_table_components.is_empty() && new_sparse_set_components.is_empty() {
             let edges = current_archetype.edges_mut();
             // the archetype does not change when we add this bundle
-            edges.insert_add_bundle(self.id, archetype_id, bundle_status, added, mutated);
+            edges.insert_add_bundle(
+                self.id,
+                archetype_id,
+                bundle_status,
+                added_required_components,
+                added,
+                mutated,
+                inserted,
+            );
             archetype_id
         } else {
             let table_id;
@@ -548,8 +701,10 @@ impl BundleInfo {
                 self.id,
                 new_archetype_id,
                 bundle_status,
+                added_required_components,
                 added,
                 mutated,
+                inserted,
             );
             new_archetype_id
         }
@@ -724,6 +879,7 @@ impl<'w> BundleInserter<'w> {
                     table,
                     sparse_sets,
                     add_bundle,
+                    &add_bundle.required_components,
                     entity,
                     location.table_row,
                     self.change_tick,
@@ -764,6 +920,7 @@ impl<'w> BundleInserter<'w> {
                     table,
                     sparse_sets,
                     add_bundle,
+                    &add_bundle.required_components,
                     entity,
                     result.table_row,
                     self.change_tick,
@@ -845,6 +1002,7 @@ impl<'w> BundleInserter<'w> {
                     new_table,
                     sparse_sets,
                     add_bundle,
+                    &add_bundle.required_components,
                     entity,
                     move_result.new_row,
                     self.change_tick,
@@ -868,9 +1026,9 @@ impl<'w> BundleInserter<'w> {
             if new_archetype.has_add_observer() {
                 deferred_world.trigger_observers(ON_ADD, entity, &add_bundle.added);
             }
-            deferred_world.trigger_on_insert(new_archetype, entity, bundle_info.iter_components());
+            deferred_world.trigger_on_insert(new_archetype, entity, add_bundle.iter_inserted());
             if new_archetype.has_insert_observer() {
-                deferred_world.trigger_observers(ON_INSERT, entity, bundle_info.components());
+                deferred_world.trigger_observers(ON_INSERT, entity, &add_bundle.inserted);
             }
         }
 
@@ -966,6 +1124,7 @@ impl<'w> BundleSpawner<'w> {
                 table,
                 sparse_sets,
                 &SpawnBundleStatus,
+                bundle_info.required_components.as_slice(),
                 entity,
                 table_row,
                 self.change_tick,
@@ -984,13 +1143,29 @@ impl<'w> BundleSpawner<'w> {
         // SAFETY: All components in the bundle are guaranteed to exist in the World
         // as they must be initialized before creating the BundleInfo.
         unsafe {
-            deferred_world.trigger_on_add(archetype, entity, bundle_info.iter_components());
+            deferred_world.trigger_on_add(
+                archetype,
+                entity,
+                bundle_info.iter_contributed_components(),
+            );
             if archetype.has_add_observer() {
-                deferred_world.trigger_observers(ON_ADD, entity, bundle_info.components());
+                deferred_world.trigger_observers(
+                    ON_ADD,
+                    entity,
+                    bundle_info.contributed_components(),
+                );
             }
-            deferred_world.trigger_on_insert(archetype, entity, bundle_info.iter_components());
+            deferred_world.trigger_on_insert(
+                archetype,
+                entity,
+                bundle_info.iter_contributed_components(),
+            );
             if archetype.has_insert_observer() {
-                deferred_world.trigger_observers(ON_INSERT, entity, bundle_info.components());
+                deferred_world.trigger_observers(
+                    ON_INSERT,
+                    entity,
+                    bundle_info.contributed_components(),
+                );
             }
         };
 
@@ -1055,6 +1230,12 @@ impl Bundles {
         self.bundle_infos.get(bundle_id.index())
     }
 
+    /// Returns an iterator over the [`BundleInfo`] of every bundle registered with the world.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &BundleInfo> {
+        self.bundle_infos.iter()
+    }
+
     /// Gets the value identifying a specific type of bundle.
     /// Returns `None` if the bundle does not exist in the world,
     /// or if `type_id` does not correspond to a type of bundle.
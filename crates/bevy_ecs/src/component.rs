@@ -3,9 +3,10 @@
 use crate::{
     self as bevy_ecs,
     archetype::ArchetypeFlags,
+    bundle::BundleInfo,
     change_detection::MAX_CHANGE_AGE,
-    entity::Entity,
-    storage::{SparseSetIndex, Storages},
+    entity::{ComponentCloneCtx, Entity, MapEntities},
+    storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
     system::{Local, Resource, SystemParam},
     world::{DeferredWorld, FromWorld, World},
 };
@@ -13,15 +14,20 @@ pub use bevy_ecs_macros::Component;
 use bevy_ptr::{OwningPtr, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use bevy_utils::TypeIdMap;
+use bevy_utils::{HashMap, HashSet, TypeIdMap};
 use std::cell::UnsafeCell;
+#[cfg(feature = "track_change_detection")]
+use std::panic::Location;
 use std::{
     alloc::Layout,
     any::{Any, TypeId},
     borrow::Cow,
     marker::PhantomData,
     mem::needs_drop,
+    ops::{Deref, DerefMut},
+    sync::Arc,
 };
+use thiserror::Error;
 
 /// A data type that can be used to store data for an [entity].
 ///
@@ -93,6 +99,161 @@ use std::{
 /// [`Table`]: crate::storage::Table
 /// [`SparseSet`]: crate::storage::SparseSet
 ///
+/// # Immutable Components
+///
+/// Components are [`Mutable`] by default. A component can instead be marked as [`Immutable`] with the
+/// `#[component(immutable)]` attribute: it can then not be accessed mutably, neither through `&mut T` in queries
+/// nor through methods like [`EntityWorldMut::get_mut`](crate::world::EntityWorldMut::get_mut).
+/// The only way to change the value of an immutable component is to insert it again, which triggers its
+/// `on_replace` and `on_insert` [hooks](ComponentHooks) and observers. This makes hooks a reliable way to keep
+/// indexes or other derived data in sync with the component.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[component(immutable)]
+/// struct Name(String);
+///
+/// let mut world = World::new();
+/// let entity = world.spawn(Name("Alice".into())).id();
+/// // `world.get_mut::<Name>(entity)` would not compile: the name has to be replaced instead.
+/// world.entity_mut(entity).insert(Name("Bob".into()));
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[component(immutable)]
+/// struct Name(String);
+///
+/// fn rename(mut names: Query<&mut Name>) {}
+/// # bevy_ecs::system::assert_is_system(rename);
+/// ```
+///
+/// # Required Components
+///
+/// Components can specify Required Components. If some [`Component`] `A` requires [`Component`] `B`,  then when `A` is inserted,
+/// `B` will _also_ be initialized and inserted (if it was not manually specified).
+///
+/// The [`Default`] constructor will be used to initialize the component, by default:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[require(B)]
+/// struct A;
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// struct B(usize);
+///
+/// # let mut world = World::default();
+/// // This will implicitly also insert B with the Default constructor
+/// let id = world.spawn(A).id();
+/// assert_eq!(&B(0), world.entity(id).get::<B>().unwrap());
+///
+/// // This will _not_ implicitly insert B, because it was already provided
+/// world.spawn((A, B(11)));
+/// ```
+///
+/// Components can have more than one Required Component:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[require(B, C)]
+/// struct A;
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// struct B(usize);
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// struct C(u32);
+///
+/// # let mut world = World::default();
+/// // This will implicitly also insert B and C with their Default constructors
+/// let id = world.spawn(A).id();
+/// assert_eq!(&B(0), world.entity(id).get::<B>().unwrap());
+/// assert_eq!(&C(0), world.entity(id).get::<C>().unwrap());
+/// ```
+///
+/// You can also define a custom constructor function:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[require(B(init_b))]
+/// struct A;
+///
+/// #[derive(Component, PartialEq, Eq, Debug)]
+/// struct B(usize);
+///
+/// fn init_b() -> B {
+///     B(10)
+/// }
+///
+/// # let mut world = World::default();
+/// // This will implicitly also insert B with the init_b() constructor
+/// let id = world.spawn(A).id();
+/// assert_eq!(&B(10), world.entity(id).get::<B>().unwrap());
+/// ```
+///
+/// Required Components are _recursive_. This means, if a Required Component has required components,
+/// those components will _also_ be inserted if they are missing:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[require(B)]
+/// struct A;
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// #[require(C)]
+/// struct B(usize);
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// struct C(u32);
+///
+/// # let mut world = World::default();
+/// // This will implicitly also insert B and C with their Default constructors
+/// let id = world.spawn(A).id();
+/// assert_eq!(&B(0), world.entity(id).get::<B>().unwrap());
+/// assert_eq!(&C(0), world.entity(id).get::<C>().unwrap());
+/// ```
+///
+/// Cycles in the "component require tree" are not allowed: initializing a component that
+/// (recursively) requires itself panics.
+///
+/// ## Inheritance depth
+///
+/// If the same component is required more than once in the "require tree", the constructor of the
+/// requirement that is "closest" to the inserted components (the one with the lowest inheritance depth) is used.
+/// Requirements at the same depth are resolved in the order the components were listed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[require(B, C(c_ten))]
+/// struct A;
+///
+/// #[derive(Component, Default)]
+/// #[require(C)]
+/// struct B;
+///
+/// #[derive(Component, Default, PartialEq, Eq, Debug)]
+/// struct C(u32);
+///
+/// fn c_ten() -> C {
+///     C(10)
+/// }
+///
+/// # let mut world = World::default();
+/// // A requires C directly, so its constructor wins over the one inherited through B.
+/// let id = world.spawn(A).id();
+/// assert_eq!(&C(10), world.entity(id).get::<C>().unwrap());
+/// ```
+///
+/// Required components can also be registered at runtime with [`World::register_required_components`].
+///
 /// # Adding component's hooks
 ///
 /// See [`ComponentHooks`] for a detailed explanation of component's hooks.
@@ -196,8 +357,68 @@ pub trait Component: Send + Sync + 'static {
     /// A constant indicating the storage type used for this component.
     const STORAGE_TYPE: StorageType;
 
+    /// Whether this component can be accessed mutably: [`Mutable`] or [`Immutable`].
+    ///
+    /// See [the `Component` docs](Component#immutable-components) for more info.
+    type Mutability: ComponentMutability;
+
     /// Called when registering this component, allowing mutable access to its [`ComponentHooks`].
     fn register_component_hooks(_hooks: &mut ComponentHooks) {}
+
+    /// Registers required components.
+    ///
+    /// This is called once, when the component is first initialized in a given [`World`].
+    /// `requiree` is the [`ComponentId`] of `Self`.
+    fn register_required_components(
+        _requiree: ComponentId,
+        _components: &mut Components,
+        _storages: &mut Storages,
+        _required_components: &mut RequiredComponents,
+    ) {
+    }
+
+    /// Called when registering this component, returning the [`ComponentCloneHandler`] used to clone
+    /// this component when cloning entities (see [`EntityCloner`](crate::entity::EntityCloner)).
+    ///
+    /// `#[derive(Component)]` uses [`component_clone_via_clone`] for components that implement [`Clone`]
+    /// (remapping entity references if they also implement [`MapEntities`]),
+    /// and the world's [default handler](ComponentCloneHandlers::get_default_handler) otherwise.
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::Default
+    }
+}
+
+mod private {
+    pub trait Seal {}
+}
+
+/// The mutability of a [`Component`], used as its [`Component::Mutability`].
+///
+/// This trait is sealed: it is only implemented by [`Mutable`] and [`Immutable`].
+pub trait ComponentMutability: private::Seal + 'static {
+    /// Whether components with this mutability can be accessed mutably.
+    const MUTABLE: bool;
+}
+
+/// Marks a [`Component`] as mutable: it can be accessed through `&mut T` and [`Mut<T>`](crate::change_detection::Mut).
+/// This is the default for `#[derive(Component)]`.
+pub struct Mutable;
+
+impl private::Seal for Mutable {}
+
+impl ComponentMutability for Mutable {
+    const MUTABLE: bool = true;
+}
+
+/// Marks a [`Component`] as immutable: it can only be changed by inserting a new value.
+///
+/// See [the `Component` docs](Component#immutable-components) for more info.
+pub struct Immutable;
+
+impl private::Seal for Immutable {}
+
+impl ComponentMutability for Immutable {
+    const MUTABLE: bool = false;
 }
 
 /// The storage used for a specific component type.
@@ -408,6 +629,8 @@ pub struct ComponentInfo {
     id: ComponentId,
     descriptor: ComponentDescriptor,
     hooks: ComponentHooks,
+    required_components: RequiredComponents,
+    required_by: HashSet<ComponentId>,
 }
 
 impl ComponentInfo {
@@ -461,12 +684,23 @@ impl ComponentInfo {
         self.descriptor.is_send_and_sync
     }
 
+    /// Returns `true` if the component can be accessed mutably.
+    /// Immutable components can only be changed by inserting a new value.
+    ///
+    /// See [the `Component` docs](Component#immutable-components) for more info.
+    #[inline]
+    pub fn mutable(&self) -> bool {
+        self.descriptor.mutable
+    }
+
     /// Create a new [`ComponentInfo`].
     pub(crate) fn new(id: ComponentId, descriptor: ComponentDescriptor) -> Self {
         ComponentInfo {
             id,
             descriptor,
             hooks: ComponentHooks::default(),
+            required_components: RequiredComponents::default(),
+            required_by: HashSet::new(),
         }
     }
 
@@ -491,6 +725,17 @@ impl ComponentInfo {
     pub fn hooks(&self) -> &ComponentHooks {
         &self.hooks
     }
+
+    /// Retrieves the [`RequiredComponents`] collection, which contains all required components (and their constructors)
+    /// needed by this component. This includes _recursive_ required components.
+    pub fn required_components(&self) -> &RequiredComponents {
+        &self.required_components
+    }
+
+    /// Returns the [`ComponentId`]s of the components that (directly or recursively) require this component.
+    pub fn required_by(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.required_by.iter().copied()
+    }
 }
 
 /// A value which uniquely identifies the type of a [`Component`] or [`Resource`] within a
@@ -567,6 +812,9 @@ pub struct ComponentDescriptor {
     // this descriptor describes.
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
+    // SAFETY: This must remain private. It must match the statically known mutability of the
+    // associated rust component type if one exists.
+    mutable: bool,
 }
 
 // We need to ignore the `drop` field in our `Debug` impl
@@ -578,6 +826,7 @@ impl std::fmt::Debug for ComponentDescriptor {
             .field("is_send_and_sync", &self.is_send_and_sync)
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
+            .field("mutable", &self.mutable)
             .finish()
     }
 }
@@ -602,11 +851,14 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            mutable: T::Mutability::MUTABLE,
         }
     }
 
     /// Create a new `ComponentDescriptor`.
     ///
+    /// `mutable` is `false` for [immutable](Component#immutable-components) components.
+    ///
     /// # Safety
     /// - the `drop` fn must be usable on a pointer with a value of the layout `layout`
     /// - the component type must be safe to access from any thread (Send + Sync in rust terms)
@@ -615,6 +867,7 @@ impl ComponentDescriptor {
         storage_type: StorageType,
         layout: Layout,
         drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
+        mutable: bool,
     ) -> Self {
         Self {
             name: name.into(),
@@ -623,6 +876,7 @@ impl ComponentDescriptor {
             type_id: None,
             layout,
             drop,
+            mutable,
         }
     }
 
@@ -639,6 +893,7 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            mutable: true,
         }
     }
 
@@ -650,6 +905,7 @@ impl ComponentDescriptor {
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            mutable: true,
         }
     }
 
@@ -673,12 +929,46 @@ impl ComponentDescriptor {
     }
 }
 
+/// Pushes a component onto [`Components::registering_requirements`] while its required components
+/// are registered, popping it again when dropped, even if the registration panics.
+struct RegisteringRequirements<'a>(&'a mut Components);
+
+impl<'a> RegisteringRequirements<'a> {
+    fn push(components: &'a mut Components, id: ComponentId) -> Self {
+        components.registering_requirements.push(id);
+        Self(components)
+    }
+}
+
+impl Deref for RegisteringRequirements<'_> {
+    type Target = Components;
+
+    fn deref(&self) -> &Components {
+        self.0
+    }
+}
+
+impl DerefMut for RegisteringRequirements<'_> {
+    fn deref_mut(&mut self) -> &mut Components {
+        self.0
+    }
+}
+
+impl Drop for RegisteringRequirements<'_> {
+    fn drop(&mut self) {
+        self.0.registering_requirements.pop();
+    }
+}
+
 /// Stores metadata associated with each kind of [`Component`] in a given [`World`].
 #[derive(Debug, Default)]
 pub struct Components {
     components: Vec<ComponentInfo>,
     indices: TypeIdMap<ComponentId>,
     resource_indices: TypeIdMap<ComponentId>,
+    component_clone_handlers: ComponentCloneHandlers,
+    /// The components whose required components are currently being registered, used to detect cycles.
+    registering_requirements: Vec<ComponentId>,
 }
 
 impl Components {
@@ -693,21 +983,34 @@ impl Components {
     #[inline]
     pub fn init_component<T: Component>(&mut self, storages: &mut Storages) -> ComponentId {
         let type_id = TypeId::of::<T>();
+        if let Some(&id) = self.indices.get(&type_id) {
+            return id;
+        }
 
-        let Components {
-            indices,
-            components,
-            ..
-        } = self;
-        *indices.entry(type_id).or_insert_with(|| {
-            let index = Components::init_component_inner(
-                components,
-                storages,
-                ComponentDescriptor::new::<T>(),
-            );
-            T::register_component_hooks(&mut components[index.index()].hooks);
-            index
-        })
+        let id = Components::init_component_inner(
+            &mut self.components,
+            storages,
+            ComponentDescriptor::new::<T>(),
+        );
+        self.indices.insert(type_id, id);
+        T::register_component_hooks(&mut self.components[id.index()].hooks);
+        self.component_clone_handlers
+            .set_component_handler(id, T::get_component_clone_handler());
+
+        // The component is registered before its requirements so that cycles in the
+        // "require tree" are detected instead of recursing forever.
+        let mut required_components = RequiredComponents::default();
+        T::register_required_components(
+            id,
+            &mut RegisteringRequirements::push(self, id),
+            storages,
+            &mut required_components,
+        );
+        for required in required_components.iter_ids() {
+            self.components[required.index()].required_by.insert(id);
+        }
+        self.components[id.index()].required_components = required_components;
+        id
     }
 
     /// Initializes a component described by `descriptor`.
@@ -788,6 +1091,130 @@ impl Components {
         self.components.get_mut(id.0).map(|info| &mut info.hooks)
     }
 
+    /// Retrieves the [`ComponentCloneHandlers`], which decide how each component is cloned
+    /// when cloning entities.
+    #[inline]
+    pub fn get_component_clone_handlers(&self) -> &ComponentCloneHandlers {
+        &self.component_clone_handlers
+    }
+
+    /// Retrieves a mutable reference to the [`ComponentCloneHandlers`], which decide how each component
+    /// is cloned when cloning entities.
+    #[inline]
+    pub fn get_component_clone_handlers_mut(&mut self) -> &mut ComponentCloneHandlers {
+        &mut self.component_clone_handlers
+    }
+
+    /// Registers `R` as a required component of `T`, using `constructor` to create `R` when it is missing.
+    ///
+    /// This also registers the required components of `R` as (recursive) required components of `T`.
+    /// This is intended to be called from [`Component::register_required_components`] implementations,
+    /// such as the one generated by `#[derive(Component)]` for the `#[require(...)]` attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is `T` or (recursively) requires `T`.
+    pub fn register_required_components_manual<T: Component, R: Component>(
+        &mut self,
+        storages: &mut Storages,
+        required_components: &mut RequiredComponents,
+        constructor: fn() -> R,
+    ) {
+        let requiree = self.init_component::<T>(storages);
+        let required = self.init_component::<R>(storages);
+        // A component whose requirements are still being registered was reached again through
+        // its own requirements.
+        let cycle_start = self
+            .registering_requirements
+            .iter()
+            .position(|&id| id == required);
+        if cycle_start.is_some() || required == requiree {
+            let cycle = match cycle_start {
+                Some(start) => &self.registering_requirements[start..],
+                None => &[requiree],
+            };
+            let cycle = cycle
+                .iter()
+                .chain([&required])
+                .map(|&id| self.components[id.index()].name())
+                .collect::<Vec<_>>();
+            panic!(
+                "Cyclic required components are not allowed: {}",
+                cycle.join(" requires ")
+            );
+        }
+        required_components.register_by_id(required, constructor, 0);
+
+        // SAFETY: `required` was just initialized.
+        let inherited = unsafe { self.get_info_unchecked(required) }
+            .required_components
+            .clone();
+        required_components.merge_inherited(&inherited);
+    }
+
+    /// Registers `R` as a required component of `T` after `T` has been initialized,
+    /// and propagates it to every component that already requires `T`.
+    ///
+    /// # Safety
+    ///
+    /// No [`BundleInfo`] may have been created for `requiree` or any of the components requiring it,
+    /// as the cached required components of those bundles would be stale.
+    pub(crate) unsafe fn register_required_components<R: Component>(
+        &mut self,
+        storages: &mut Storages,
+        requiree: ComponentId,
+        constructor: fn() -> R,
+    ) -> Result<(), RequiredComponentsError> {
+        let required = self.init_component::<R>(storages);
+
+        // SAFETY: The caller ensures `requiree` is valid.
+        let requiree_info = unsafe { self.get_info_unchecked(requiree) };
+        // SAFETY: `required` was just initialized.
+        let required_info = unsafe { self.get_info_unchecked(required) };
+        if required == requiree || required_info.required_components.contains(requiree) {
+            return Err(RequiredComponentsError::CyclicRequirement(
+                requiree, required,
+            ));
+        }
+        if requiree_info
+            .required_components
+            .0
+            .get(&required)
+            .is_some_and(|r| r.inheritance_depth == 0)
+        {
+            return Err(RequiredComponentsError::DuplicateRegistration(
+                requiree, required,
+            ));
+        }
+
+        let mut new_requirements = RequiredComponents::default();
+        new_requirements.register_by_id(required, constructor, 0);
+        new_requirements.merge_inherited(&required_info.required_components);
+
+        let mut targets = vec![requiree];
+        targets.extend(requiree_info.required_by.iter().copied());
+        for target in targets {
+            let depth = if target == requiree {
+                0
+            } else {
+                // SAFETY: `target` requires `requiree`, so it is a valid component.
+                let target_info = unsafe { self.get_info_unchecked(target) };
+                target_info.required_components.0[&requiree].inheritance_depth + 1
+            };
+            for (&id, component) in new_requirements.0.iter() {
+                self.components[target.index()]
+                    .required_components
+                    .register_dynamic(
+                        id,
+                        component.constructor.clone(),
+                        component.inheritance_depth + depth,
+                    );
+                self.components[id.index()].required_by.insert(target);
+            }
+        }
+        Ok(())
+    }
+
     /// Type-erased equivalent of [`Components::component_id()`].
     #[inline]
     pub fn get_id(&self, type_id: TypeId) -> Option<ComponentId> {
@@ -1133,3 +1560,454 @@ impl<T: Component> FromWorld for InitComponentId<T> {
         }
     }
 }
+
+/// An error returned when the registration of a required component fails.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum RequiredComponentsError {
+    /// The component is already a directly required component for the requiree.
+    #[error("Component {0:?} already directly requires component {1:?}")]
+    DuplicateRegistration(ComponentId, ComponentId),
+    /// An archetype or bundle containing the requiree already exists, so its required components can no longer change.
+    #[error("An archetype with the component {0:?} that requires other components already exists")]
+    ArchetypeExists(ComponentId),
+    /// The required component is the requiree or (recursively) requires it, which would form a cycle.
+    #[error("Component {0:?} cannot require component {1:?}, because {1:?} is or requires {0:?}")]
+    CyclicRequirement(ComponentId, ComponentId),
+}
+
+#[cfg(feature = "track_change_detection")]
+type RequiredComponentConstructorFn = dyn Fn(&mut Table, &mut SparseSets, Tick, TableRow, Entity, &'static Location<'static>)
+    + Send
+    + Sync;
+
+#[cfg(not(feature = "track_change_detection"))]
+type RequiredComponentConstructorFn =
+    dyn Fn(&mut Table, &mut SparseSets, Tick, TableRow, Entity) + Send + Sync;
+
+/// A Required Component constructor. See [`Component`] for details.
+#[derive(Clone)]
+pub struct RequiredComponentConstructor(
+    // Note: this is an `Arc` so that cloning it into bundle infos and archetype edges stays cheap.
+    pub(crate) Arc<RequiredComponentConstructorFn>,
+);
+
+impl RequiredComponentConstructor {
+    /// Initializes the required component for `entity`.
+    ///
+    /// # Safety
+    ///
+    /// - `table` must be the table `entity` is stored in, and `table_row` must be its allocated row.
+    /// - The required component must not be initialized for `entity` yet, and `table` / `sparse_sets`
+    ///   must contain storage for it.
+    pub(crate) unsafe fn initialize(
+        &self,
+        table: &mut Table,
+        sparse_sets: &mut SparseSets,
+        change_tick: Tick,
+        table_row: TableRow,
+        entity: Entity,
+        #[cfg(feature = "track_change_detection")] caller: &'static Location<'static>,
+    ) {
+        (self.0)(
+            table,
+            sparse_sets,
+            change_tick,
+            table_row,
+            entity,
+            #[cfg(feature = "track_change_detection")]
+            caller,
+        );
+    }
+}
+
+impl std::fmt::Debug for RequiredComponentConstructor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RequiredComponentConstructor").finish()
+    }
+}
+
+/// A required component, along with its constructor and how "far" it is from the requiring component.
+#[derive(Debug, Clone)]
+pub struct RequiredComponent {
+    /// The constructor used to initialize the component when it is missing.
+    pub constructor: RequiredComponentConstructor,
+    /// The depth of the requirement in the "require tree". Direct requirements have a depth of 0.
+    ///
+    /// When a component is required more than once, the constructor with the lowest depth is used.
+    pub inheritance_depth: u16,
+}
+
+/// The collection of metadata for components that are required for a given component.
+///
+/// For more information, see the "Required Components" section of [`Component`].
+#[derive(Default, Clone, Debug)]
+pub struct RequiredComponents(pub(crate) HashMap<ComponentId, RequiredComponent>);
+
+impl RequiredComponents {
+    /// Registers a required component. If the component is already registered with an inheritance depth
+    /// lower than or equal to `inheritance_depth`, the existing registration is kept.
+    ///
+    /// # Safety
+    ///
+    /// `component_id` must be a valid component for the [`World`] these [`RequiredComponents`] belong to,
+    /// and `constructor` must initialize a value of that component.
+    pub unsafe fn register_dynamic(
+        &mut self,
+        component_id: ComponentId,
+        constructor: RequiredComponentConstructor,
+        inheritance_depth: u16,
+    ) {
+        match self.0.get_mut(&component_id) {
+            Some(existing) if existing.inheritance_depth <= inheritance_depth => {}
+            Some(existing) => {
+                existing.constructor = constructor;
+                existing.inheritance_depth = inheritance_depth;
+            }
+            None => {
+                self.0.insert(
+                    component_id,
+                    RequiredComponent {
+                        constructor,
+                        inheritance_depth,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Registers a required component. If the component is already registered with an inheritance depth
+    /// lower than or equal to `inheritance_depth`, the existing registration is kept.
+    pub fn register<C: Component>(
+        &mut self,
+        components: &mut Components,
+        storages: &mut Storages,
+        constructor: fn() -> C,
+        inheritance_depth: u16,
+    ) {
+        let component_id = components.init_component::<C>(storages);
+        self.register_by_id(component_id, constructor, inheritance_depth);
+    }
+
+    /// Registers the [`Component`] with the given ID as required if it exists.
+    ///
+    /// `component_id` must be the [`ComponentId`] of `C` in the [`World`] these [`RequiredComponents`] belong to.
+    pub fn register_by_id<C: Component>(
+        &mut self,
+        component_id: ComponentId,
+        constructor: fn() -> C,
+        inheritance_depth: u16,
+    ) {
+        let erased: RequiredComponentConstructor = RequiredComponentConstructor(Arc::new(
+            move |table,
+                  sparse_sets,
+                  change_tick,
+                  table_row,
+                  entity,
+                  #[cfg(feature = "track_change_detection")] caller| {
+                OwningPtr::make(constructor(), |ptr| {
+                    // SAFETY: This will only be called in the context of `BundleInfo::write_components`, which will
+                    // pass in a valid table_row and entity requiring a C constructor.
+                    // C::STORAGE_TYPE is the storage type associated with `component_id` / `C`.
+                    // `ptr` points to valid `C` data, which matches the type associated with `component_id`
+                    unsafe {
+                        BundleInfo::initialize_required_component(
+                            table,
+                            sparse_sets,
+                            change_tick,
+                            table_row,
+                            entity,
+                            component_id,
+                            C::STORAGE_TYPE,
+                            ptr,
+                            #[cfg(feature = "track_change_detection")]
+                            caller,
+                        );
+                    }
+                });
+            },
+        ));
+        // SAFETY: `erased` initializes a value of `C`, which `component_id` identifies.
+        unsafe { self.register_dynamic(component_id, erased, inheritance_depth) };
+    }
+
+    /// Iterates the ids of all required components. This includes recursive required components.
+    pub fn iter_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Returns the number of required components.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no required components.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the component with the given id is required.
+    pub fn contains(&self, component_id: ComponentId) -> bool {
+        self.0.contains_key(&component_id)
+    }
+
+    /// Removes components that are explicitly provided in a given [`Bundle`]. These components should
+    /// be logically treated as normal components, not "required components".
+    ///
+    /// [`Bundle`]: crate::bundle::Bundle
+    pub(crate) fn remove_explicit_components(&mut self, components: &[ComponentId]) {
+        for component in components {
+            self.0.remove(component);
+        }
+    }
+
+    /// Merges `required_components` into this collection. This only inserts a required component
+    /// if it _did not already exist_ with a lower or equal inheritance depth.
+    pub(crate) fn merge(&mut self, required_components: &RequiredComponents) {
+        for (&id, component) in required_components.0.iter() {
+            // SAFETY: `required_components` belong to the same world as `self`.
+            unsafe {
+                self.register_dynamic(
+                    id,
+                    component.constructor.clone(),
+                    component.inheritance_depth,
+                );
+            }
+        }
+    }
+
+    /// Merges the required components of a direct requirement, one level deeper in the "require tree".
+    fn merge_inherited(&mut self, inherited: &RequiredComponents) {
+        for (&id, component) in inherited.0.iter() {
+            // SAFETY: `inherited` belongs to the same world as `self`.
+            unsafe {
+                self.register_dynamic(
+                    id,
+                    component.constructor.clone(),
+                    component.inheritance_depth + 1,
+                );
+            }
+        }
+    }
+
+    /// Returns the required components sorted by [`ComponentId`], so that bundles insert them in a deterministic order.
+    pub(crate) fn sorted(&self) -> Vec<(ComponentId, RequiredComponentConstructor)> {
+        let mut sorted = self
+            .0
+            .iter()
+            .map(|(&id, component)| (id, component.constructor.clone()))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|(id, _)| *id);
+        sorted
+    }
+}
+
+/// Function type that can be used to clone an entity's component.
+///
+/// The function is called with the world and a [`ComponentCloneCtx`] describing the component
+/// that is being cloned, as well as its source and target entities.
+pub type ComponentCloneFn = fn(&mut World, &mut ComponentCloneCtx);
+
+/// Decides how a component is cloned when cloning entities with an [`EntityCloner`](crate::entity::EntityCloner).
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ComponentCloneHandler {
+    /// Use the world's [default handler](ComponentCloneHandlers::get_default_handler).
+    #[default]
+    Default,
+    /// Do not clone this component.
+    Ignore,
+    /// Clone this component with the given function.
+    Custom(ComponentCloneFn),
+}
+
+/// A registry of the [`ComponentCloneFn`]s used to clone each component of a [`World`].
+///
+/// Components without a specific handler use the default handler, which clones components through
+/// reflection when the `bevy_reflect` feature is enabled (see [`component_clone_via_reflect`]),
+/// and ignores them otherwise.
+#[derive(Debug)]
+pub struct ComponentCloneHandlers {
+    handlers: Vec<Option<ComponentCloneFn>>,
+    default_handler: ComponentCloneFn,
+}
+
+impl ComponentCloneHandlers {
+    /// Sets the default handler for this registry. All components with [`Default`](ComponentCloneHandler::Default) handler,
+    /// as well as any component that does not have an explicitly registered clone function, will use this handler.
+    ///
+    /// See [`ComponentCloneHandlers`] for more details.
+    pub fn set_default_handler(&mut self, handler: ComponentCloneFn) {
+        self.default_handler = handler;
+    }
+
+    /// Returns the currently registered default handler.
+    pub fn get_default_handler(&self) -> ComponentCloneFn {
+        self.default_handler
+    }
+
+    /// Sets a handler for a specific component.
+    ///
+    /// This can be used to opt a component out of entity cloning with [`ComponentCloneHandler::Ignore`].
+    pub fn set_component_handler(&mut self, id: ComponentId, handler: ComponentCloneHandler) {
+        if id.0 >= self.handlers.len() {
+            self.handlers.resize(id.0 + 1, None);
+        }
+        self.handlers[id.0] = match handler {
+            ComponentCloneHandler::Default => None,
+            ComponentCloneHandler::Ignore => Some(component_clone_ignore),
+            ComponentCloneHandler::Custom(handler) => Some(handler),
+        };
+    }
+
+    /// Checks if the specified component is registered. If not, the component will use the default global handler.
+    ///
+    /// This will return an incorrect result if `id` did not come from the same world as `self`.
+    pub fn is_handler_registered(&self, id: ComponentId) -> bool {
+        self.handlers.get(id.0).is_some_and(Option::is_some)
+    }
+
+    /// Gets a handler to clone a component. This can be one of the following:
+    /// - Custom clone function for this specific component.
+    /// - Default global handler.
+    /// - A [`component_clone_ignore`] (no cloning).
+    ///
+    /// This will return an incorrect result if `id` did not come from the same world as `self`.
+    pub fn get_handler(&self, id: ComponentId) -> ComponentCloneFn {
+        match self.handlers.get(id.0) {
+            Some(Some(handler)) => *handler,
+            Some(None) | None => self.default_handler,
+        }
+    }
+}
+
+impl Default for ComponentCloneHandlers {
+    fn default() -> Self {
+        Self {
+            handlers: Default::default(),
+            #[cfg(feature = "bevy_reflect")]
+            default_handler: component_clone_via_reflect,
+            #[cfg(not(feature = "bevy_reflect"))]
+            default_handler: component_clone_ignore,
+        }
+    }
+}
+
+/// Component [clone handler function](ComponentCloneFn) implemented using the [`Clone`] trait.
+/// Can be [set](ComponentCloneHandlers::set_component_handler) as clone handler for the specific component it is implemented for.
+/// It will panic if set as handler for any other component.
+///
+/// See [`ComponentCloneHandlers`] for more details.
+pub fn component_clone_via_clone<C: Clone + Component>(
+    world: &mut World,
+    ctx: &mut ComponentCloneCtx,
+) {
+    let component = world
+        .entity(ctx.source())
+        .get::<C>()
+        .expect("Component must exist on the source entity")
+        .clone();
+    world.entity_mut(ctx.target()).insert(component);
+}
+
+/// Component [clone handler function](ComponentCloneFn) implemented using the [`Clone`] and [`MapEntities`] traits.
+///
+/// Entity references of the clone are remapped once the whole clone operation is done, so that
+/// references to other cloned entities (for example when cloning recursively) point to their clones.
+/// References to entities that were not cloned are left unchanged.
+pub fn component_clone_via_clone_and_map_entities<C: Clone + MapEntities + Component>(
+    world: &mut World,
+    ctx: &mut ComponentCloneCtx,
+) {
+    component_clone_via_clone::<C>(world, ctx);
+    ctx.map_entities_later::<C>();
+}
+
+/// Component [clone handler function](ComponentCloneFn) implemented using reflect.
+/// Can be [set](ComponentCloneHandlers::set_component_handler) as clone handler for any registered component,
+/// but only reflected components will be cloned.
+///
+/// The component must be registered in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry) with
+/// [`ReflectComponent`](crate::reflect::ReflectComponent) type data, otherwise it is not cloned.
+///
+/// See [`ComponentCloneHandlers`] for more details.
+#[cfg(feature = "bevy_reflect")]
+pub fn component_clone_via_reflect(world: &mut World, ctx: &mut ComponentCloneCtx) {
+    let Some(registry) = world.get_resource::<crate::reflect::AppTypeRegistry>() else {
+        return;
+    };
+    let registry = registry.clone();
+    let registry = registry.read();
+
+    let Some(type_id) = world
+        .components()
+        .get_info(ctx.component_id())
+        .and_then(ComponentInfo::type_id)
+    else {
+        return;
+    };
+    let Some(reflect_component) =
+        registry.get_type_data::<crate::reflect::ReflectComponent>(type_id)
+    else {
+        return;
+    };
+    let Some(component) = reflect_component
+        .reflect(world.entity(ctx.source()))
+        .map(Reflect::clone_value)
+    else {
+        return;
+    };
+    reflect_component.insert(&mut world.entity_mut(ctx.target()), &*component, &registry);
+}
+
+/// Noop implementation of component clone handler function.
+///
+/// See [`ComponentCloneHandlers`] for more details.
+pub fn component_clone_ignore(_world: &mut World, _ctx: &mut ComponentCloneCtx) {}
+
+/// Wrapper for components clone specialization using autoderef.
+#[doc(hidden)]
+pub struct ComponentCloneSpecializationWrapper<T>(PhantomData<T>);
+
+impl<T> Default for ComponentCloneSpecializationWrapper<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Base trait for components clone specialization using autoderef.
+#[doc(hidden)]
+pub trait ComponentCloneBase {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler;
+}
+
+impl<C: Component> ComponentCloneBase for ComponentCloneSpecializationWrapper<C> {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler {
+        ComponentCloneHandler::Default
+    }
+}
+
+/// Specialized trait for components clone specialization using autoderef.
+#[doc(hidden)]
+pub trait ComponentCloneViaClone {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler;
+}
+
+impl<C: Clone + Component> ComponentCloneViaClone for &ComponentCloneSpecializationWrapper<C> {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler {
+        ComponentCloneHandler::Custom(component_clone_via_clone::<C>)
+    }
+}
+
+/// Specialized trait for components clone specialization using autoderef.
+#[doc(hidden)]
+pub trait ComponentCloneViaCloneAndMapEntities {
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler;
+}
+
+impl<C: Clone + MapEntities + Component> ComponentCloneViaCloneAndMapEntities
+    for &&ComponentCloneSpecializationWrapper<C>
+{
+    fn get_component_clone_handler(&self) -> ComponentCloneHandler {
+        ComponentCloneHandler::Custom(component_clone_via_clone_and_map_entities::<C>)
+    }
+}
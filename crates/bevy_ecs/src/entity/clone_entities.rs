@@ -0,0 +1,587 @@
+use std::collections::VecDeque;
+
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{
+    bundle::Bundle,
+    component::{component_clone_ignore, Component, ComponentCloneHandler, ComponentId},
+    entity::{map_component_entities, Entity, EntityHashMap, EntityMapper, MapEntities},
+    world::World,
+};
+
+/// Maps cloned entities to their clones, leaving every other entity untouched.
+pub struct CloneEntityMapper<'m>(&'m EntityHashMap<Entity>);
+
+impl EntityMapper for CloneEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+
+    fn mappings(&self) -> impl Iterator<Item = (Entity, Entity)> {
+        self.0.iter().map(|(&source, &target)| (source, target))
+    }
+}
+
+type MapComponentEntitiesFn = for<'m> fn(&mut World, Entity, &mut CloneEntityMapper<'m>);
+
+fn map_cloned_component_entities<C: Component + MapEntities>(
+    world: &mut World,
+    entity: Entity,
+    mapper: &mut CloneEntityMapper,
+) {
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        map_component_entities::<C>(&mut entity, mapper);
+    }
+}
+
+/// Context passed to a [`ComponentCloneFn`](crate::component::ComponentCloneFn) while cloning an entity.
+pub struct ComponentCloneCtx<'a> {
+    source: Entity,
+    target: Entity,
+    component_id: ComponentId,
+    entity_map: &'a mut EntityHashMap<Entity>,
+    queue: &'a mut VecDeque<(Entity, Entity)>,
+    deferred_entity_maps: &'a mut Vec<(Entity, MapComponentEntitiesFn)>,
+}
+
+impl ComponentCloneCtx<'_> {
+    /// Returns the entity the component is cloned from.
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+
+    /// Returns the entity the component is cloned to.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Returns the [`ComponentId`] of the component being cloned.
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+
+    /// Returns the clone of `entity` if it was (or is going to be) cloned as part of the current clone operation,
+    /// or `entity` itself otherwise.
+    pub fn map_entity(&self, entity: Entity) -> Entity {
+        self.entity_map.get(&entity).copied().unwrap_or(entity)
+    }
+
+    /// Returns `true` if `entity` was (or is going to be) cloned as part of the current clone operation.
+    pub fn is_cloned(&self, entity: Entity) -> bool {
+        self.entity_map.contains_key(&entity)
+    }
+
+    /// Spawns an empty entity and queues `source` to be cloned into it as part of the current clone operation,
+    /// using the same filters and handlers. Returns the spawned entity.
+    ///
+    /// If `source` is already being cloned, its existing clone is returned instead.
+    ///
+    /// This is used to clone related entities, for example the children of an entity when cloning recursively.
+    pub fn queue_clone(&mut self, world: &mut World, source: Entity) -> Entity {
+        if let Some(&target) = self.entity_map.get(&source) {
+            return target;
+        }
+        let target = world.spawn_empty().id();
+        self.entity_map.insert(source, target);
+        self.queue.push_back((source, target));
+        target
+    }
+
+    /// Remaps the [`Entity`] references of the component `C` on the target entity once the whole clone
+    /// operation is done, using [`MapEntities`].
+    ///
+    /// References to entities that were cloned point to their clones, every other reference is left unchanged.
+    pub fn map_entities_later<C: Component + MapEntities>(&mut self) {
+        self.deferred_entity_maps
+            .push((self.target, map_cloned_component_entities::<C>));
+    }
+}
+
+/// A configuration determining how to clone entities. This can be built using [`EntityCloneBuilder`],
+/// or obtained with [`EntityCloneBuilder::into_cloner`] to be reused.
+///
+/// Components are cloned using the [`ComponentCloneFn`](crate::component::ComponentCloneFn)
+/// registered for them in [`ComponentCloneHandlers`](crate::component::ComponentCloneHandlers),
+/// unless it was overridden for this cloner.
+#[derive(Debug, Clone, Default)]
+pub struct EntityCloner {
+    filter_allows_components: bool,
+    filter: HashSet<ComponentId>,
+    clone_handlers_overrides: HashMap<ComponentId, ComponentCloneHandler>,
+}
+
+impl EntityCloner {
+    /// Spawns a new entity and clones the components of `source` into it. Returns the new entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` does not exist.
+    pub fn clone_entity(&self, world: &mut World, source: Entity) -> Entity {
+        let target = world.spawn_empty().id();
+        self.clone_entity_to(world, source, target);
+        target
+    }
+
+    /// Clones the components of `source` into the existing `target` entity.
+    ///
+    /// Components of `target` that are also cloned from `source` are overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` or `target` do not exist.
+    pub fn clone_entity_to(&self, world: &mut World, source: Entity, target: Entity) {
+        assert!(
+            world.get_entity(target).is_some(),
+            "Target entity {target:?} must exist"
+        );
+
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(source, target);
+        let mut queue = VecDeque::from([(source, target)]);
+        let mut deferred_entity_maps = Vec::new();
+
+        while let Some((source, target)) = queue.pop_front() {
+            let components = world
+                .get_entity(source)
+                .unwrap_or_else(|| panic!("Source entity {source:?} must exist"))
+                .archetype()
+                .components()
+                .filter(|id| self.is_cloning_allowed(id))
+                .collect::<Vec<_>>();
+
+            for component_id in components {
+                let handler = match self.clone_handlers_overrides.get(&component_id) {
+                    None => world
+                        .components()
+                        .get_component_clone_handlers()
+                        .get_handler(component_id),
+                    Some(ComponentCloneHandler::Default) => world
+                        .components()
+                        .get_component_clone_handlers()
+                        .get_default_handler(),
+                    Some(ComponentCloneHandler::Ignore) => component_clone_ignore,
+                    Some(ComponentCloneHandler::Custom(handler)) => *handler,
+                };
+                let mut ctx = ComponentCloneCtx {
+                    source,
+                    target,
+                    component_id,
+                    entity_map: &mut entity_map,
+                    queue: &mut queue,
+                    deferred_entity_maps: &mut deferred_entity_maps,
+                };
+                (handler)(world, &mut ctx);
+            }
+        }
+
+        let mut mapper = CloneEntityMapper(&entity_map);
+        for (entity, map_entities) in deferred_entity_maps {
+            (map_entities)(world, entity, &mut mapper);
+        }
+    }
+
+    fn is_cloning_allowed(&self, component: &ComponentId) -> bool {
+        (self.filter_allows_components && self.filter.contains(component))
+            || (!self.filter_allows_components && !self.filter.contains(component))
+    }
+}
+
+/// Builder struct to clone an entity. Allows configuring which components to clone, as well as how to clone them.
+/// After configuration is complete an entity can be cloned using [`Self::clone_entity`].
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::EntityCloneBuilder;
+///
+/// #[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+/// struct A {
+///     field: usize,
+/// }
+///
+/// let mut world = World::default();
+///
+/// let component = A { field: 5 };
+///
+/// let entity = world.spawn(component.clone()).id();
+/// let entity_clone = EntityCloneBuilder::new(&mut world).clone_entity(entity);
+///
+/// assert!(world.get::<A>(entity_clone).is_some_and(|c| *c == component));
+/// ```
+///
+/// # Default cloning strategy
+///
+/// By default, all types that derive [`Component`] and implement [`Clone`] are cloned using [`Clone`]
+/// (see [`component_clone_via_clone`](crate::component::component_clone_via_clone)), and [`Entity`]
+/// references of those that also implement [`MapEntities`] are remapped to point to the clones of the
+/// entities cloned in the same operation.
+///
+/// Other components are cloned with the world's default handler, which uses reflection when the `bevy_reflect`
+/// feature is enabled (see [`ComponentCloneHandlers`](crate::component::ComponentCloneHandlers)).
+///
+/// It is also possible to disable cloning for a specific component with
+/// [`ComponentCloneHandler::Ignore`], either for a single clone with
+/// [`Self::override_component_clone_handler`] or for every clone of the world with
+/// [`ComponentCloneHandlers::set_component_handler`](crate::component::ComponentCloneHandlers::set_component_handler).
+#[derive(Debug)]
+pub struct EntityCloneBuilder<'w> {
+    world: &'w mut World,
+    cloner: EntityCloner,
+}
+
+impl<'w> EntityCloneBuilder<'w> {
+    /// Creates a new [`EntityCloneBuilder`] for world.
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            cloner: EntityCloner::default(),
+        }
+    }
+
+    /// Spawns a new entity and clones the components of `source` into it. Returns the new entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` does not exist.
+    pub fn clone_entity(self, source: Entity) -> Entity {
+        self.cloner.clone_entity(self.world, source)
+    }
+
+    /// Clones the components of `source` into the existing `target` entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` or `target` do not exist.
+    pub fn clone_entity_to(self, source: Entity, target: Entity) {
+        self.cloner.clone_entity_to(self.world, source, target);
+    }
+
+    /// Finishes the configuration and returns the [`EntityCloner`], which can be used to clone several entities.
+    pub fn into_cloner(self) -> EntityCloner {
+        self.cloner
+    }
+
+    /// Returns the [`World`] this builder clones entities in.
+    pub fn world(&mut self) -> &mut World {
+        self.world
+    }
+
+    /// Adds all components of the bundle to the list of components to clone.
+    ///
+    /// Note that all components are allowed by default, to clone only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow<T: Bundle>(&mut self) -> &mut Self {
+        let ids = self.bundle_ids::<T>();
+        self.allow_by_ids(ids)
+    }
+
+    /// Extends the list of components to clone.
+    ///
+    /// Note that all components are allowed by default, to clone only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.cloner.filter_allows_components {
+            self.cloner.filter.extend(ids);
+        } else {
+            ids.into_iter().for_each(|id| {
+                self.cloner.filter.remove(&id);
+            });
+        }
+        self
+    }
+
+    /// Resets the filter to allow all components to be cloned.
+    pub fn allow_all(&mut self) -> &mut Self {
+        self.cloner.filter_allows_components = false;
+        self.cloner.filter.clear();
+        self
+    }
+
+    /// Disallows all components of the bundle from being cloned.
+    pub fn deny<T: Bundle>(&mut self) -> &mut Self {
+        let ids = self.bundle_ids::<T>();
+        self.deny_by_ids(ids)
+    }
+
+    /// Extends the list of components that shouldn't be cloned.
+    pub fn deny_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.cloner.filter_allows_components {
+            ids.into_iter().for_each(|id| {
+                self.cloner.filter.remove(&id);
+            });
+        } else {
+            self.cloner.filter.extend(ids);
+        }
+        self
+    }
+
+    /// Sets the filter to deny all components.
+    pub fn deny_all(&mut self) -> &mut Self {
+        self.cloner.filter_allows_components = true;
+        self.cloner.filter.clear();
+        self
+    }
+
+    /// Overrides the [`ComponentCloneHandler`] for a component in this builder.
+    /// This handler will be used to clone the component instead of the global one defined by
+    /// [`ComponentCloneHandlers`](crate::component::ComponentCloneHandlers).
+    ///
+    pub fn override_component_clone_handler<T: Component>(
+        &mut self,
+        handler: ComponentCloneHandler,
+    ) -> &mut Self {
+        let id = self.world.init_component::<T>();
+        self.cloner.clone_handlers_overrides.insert(id, handler);
+        self
+    }
+
+    /// Removes a previously set override of [`ComponentCloneHandler`] for a component in this builder.
+    pub fn remove_component_clone_handler_override<T: Component>(&mut self) -> &mut Self {
+        if let Some(id) = self.world.components().component_id::<T>() {
+            self.cloner.clone_handlers_overrides.remove(&id);
+        }
+        self
+    }
+
+    fn bundle_ids<T: Bundle>(&mut self) -> Vec<ComponentId> {
+        let mut ids = Vec::new();
+        T::component_ids(
+            &mut self.world.components,
+            &mut self.world.storages,
+            &mut |id| ids.push(id),
+        );
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::{Component, ComponentCloneHandler, ComponentId},
+        entity::{Entity, EntityCloneBuilder, MapEntities},
+        prelude::EntityMapper,
+        system::Resource,
+        world::{DeferredWorld, World},
+    };
+
+    #[derive(Component, Clone, PartialEq, Eq, Debug)]
+    struct A {
+        field: usize,
+    }
+
+    #[derive(Component, Clone, PartialEq, Eq, Debug)]
+    struct B;
+
+    #[derive(Component, PartialEq, Eq, Debug)]
+    struct NotClone;
+
+    #[test]
+    fn clone_entity_using_clone() {
+        let mut world = World::default();
+        let component = A { field: 5 };
+        let e = world.spawn((component.clone(), B)).id();
+
+        let e_clone = world.clone_entity(e);
+
+        assert_ne!(e, e_clone);
+        assert_eq!(world.get::<A>(e_clone), Some(&component));
+        assert_eq!(world.get::<B>(e_clone), Some(&B));
+        assert_eq!(world.get::<A>(e), Some(&component), "source is untouched");
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn clone_entity_using_reflect() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect, PartialEq, Eq, Debug, Default)]
+        #[reflect(Component)]
+        struct R {
+            field: usize,
+        }
+
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        world.resource::<AppTypeRegistry>().write().register::<R>();
+
+        let e = world.spawn(R { field: 5 }).id();
+        let e_clone = world.clone_entity(e);
+
+        assert_eq!(world.get::<R>(e_clone), Some(&R { field: 5 }));
+    }
+
+    #[test]
+    fn clone_entity_skips_unclonable_components() {
+        let mut world = World::default();
+        let e = world.spawn((A { field: 1 }, NotClone)).id();
+
+        let e_clone = world.clone_entity(e);
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<NotClone>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_with_allow_filter() {
+        let mut world = World::default();
+        let e = world.spawn((A { field: 1 }, B)).id();
+
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.deny_all().allow::<A>();
+        });
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<B>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_with_deny_filter() {
+        let mut world = World::default();
+        let e = world.spawn((A { field: 1 }, B)).id();
+
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.deny::<A>();
+        });
+
+        assert!(world.get::<A>(e_clone).is_none());
+        assert!(world.get::<B>(e_clone).is_some());
+
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.deny::<(A, B)>().allow::<B>();
+        });
+
+        assert!(world.get::<A>(e_clone).is_none());
+        assert!(world.get::<B>(e_clone).is_some());
+    }
+
+    #[test]
+    fn clone_entity_with_override_handler() {
+        let mut world = World::default();
+        let e = world.spawn((A { field: 1 }, B)).id();
+
+        let e_clone = world.clone_entity_with(e, |builder| {
+            builder.override_component_clone_handler::<B>(ComponentCloneHandler::Ignore);
+        });
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<B>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_with_global_opt_out() {
+        let mut world = World::default();
+        let b = world.init_component::<B>();
+        world
+            .get_component_clone_handlers_mut()
+            .set_component_handler(b, ComponentCloneHandler::Ignore);
+        let e = world.spawn((A { field: 1 }, B)).id();
+
+        let e_clone = world.clone_entity(e);
+
+        assert!(world.get::<A>(e_clone).is_some());
+        assert!(world.get::<B>(e_clone).is_none());
+    }
+
+    #[test]
+    fn clone_entity_into_existing_target() {
+        let mut world = World::default();
+        let e = world.spawn((A { field: 1 }, B)).id();
+        let target = world.spawn(A { field: 2 }).id();
+
+        EntityCloneBuilder::new(&mut world).clone_entity_to(e, target);
+
+        assert_eq!(world.get::<A>(target), Some(&A { field: 1 }));
+        assert_eq!(world.get::<B>(target), Some(&B));
+    }
+
+    #[test]
+    fn clone_entity_maps_entities() {
+        #[derive(Component, Clone)]
+        struct Link(Entity);
+
+        impl MapEntities for Link {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                self.0 = entity_mapper.map_entity(self.0);
+            }
+        }
+
+        #[derive(Component, Clone)]
+        struct Linked(Entity);
+
+        fn clone_linked(world: &mut World, ctx: &mut bevy_ecs::entity::ComponentCloneCtx) {
+            let linked = world.get::<Linked>(ctx.source()).unwrap().0;
+            let linked_clone = ctx.queue_clone(world, linked);
+            world.entity_mut(ctx.target()).insert(Linked(linked_clone));
+        }
+
+        let mut world = World::default();
+        let other = world.spawn_empty().id();
+        let b = world.spawn(Link(other)).id();
+        let a = world.spawn((Link(b), Linked(b))).id();
+
+        // Without cloning `b`, the link is left untouched.
+        let a_clone = world.clone_entity(a);
+        assert_eq!(world.get::<Link>(a_clone).unwrap().0, b);
+
+        // When `b` is cloned in the same operation, links to it point to its clone.
+        let a_clone = world.clone_entity_with(a, |builder| {
+            builder.override_component_clone_handler::<Linked>(ComponentCloneHandler::Custom(
+                clone_linked,
+            ));
+        });
+        let b_clone = world.get::<Linked>(a_clone).unwrap().0;
+        assert_ne!(b_clone, b);
+        assert_eq!(world.get::<Link>(a_clone).unwrap().0, b_clone);
+        assert_eq!(
+            world.get::<Link>(b_clone).unwrap().0,
+            other,
+            "links to entities outside of the clone are left untouched"
+        );
+    }
+
+    #[test]
+    fn clone_entity_maps_immutable_components_through_hooks() {
+        #[derive(Resource, Default)]
+        struct Inserted(Vec<Entity>);
+
+        #[derive(Component, Clone)]
+        #[component(immutable, on_insert = record_insert)]
+        struct Link(Entity);
+
+        fn record_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+            let link = world.get::<Link>(entity).unwrap().0;
+            world.resource_mut::<Inserted>().0.push(link);
+        }
+
+        impl MapEntities for Link {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                self.0 = entity_mapper.map_entity(self.0);
+            }
+        }
+
+        #[derive(Component, Clone)]
+        struct Linked(Entity);
+
+        fn clone_linked(world: &mut World, ctx: &mut bevy_ecs::entity::ComponentCloneCtx) {
+            let linked = world.get::<Linked>(ctx.source()).unwrap().0;
+            let linked_clone = ctx.queue_clone(world, linked);
+            world.entity_mut(ctx.target()).insert(Linked(linked_clone));
+        }
+
+        let mut world = World::default();
+        world.init_resource::<Inserted>();
+        let b = world.spawn_empty().id();
+        let a = world.spawn((Link(b), Linked(b))).id();
+
+        let a_clone = world.clone_entity_with(a, |builder| {
+            builder.override_component_clone_handler::<Linked>(ComponentCloneHandler::Custom(
+                clone_linked,
+            ));
+        });
+        let b_clone = world.get::<Linked>(a_clone).unwrap().0;
+        assert_eq!(world.get::<Link>(a_clone).unwrap().0, b_clone);
+        // The hook saw the mapped value, as the component was inserted again after mapping it.
+        assert_eq!(world.resource::<Inserted>().0.last(), Some(&b_clone));
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    borrow::Borrow,
+    collections::{btree_set, BTreeSet},
+    fmt,
+    ops::Deref,
+    slice,
+};
+
+use bevy_utils::hashbrown::hash_set;
+use thiserror::Error;
+
+use super::{Entity, EntityHash, EntityHashSet};
+
+/// A collection of [`Entity`]s that is guaranteed to yield each entity at most once when iterated.
+///
+/// This is what allows APIs like [`Query::iter_many_unique_mut`] and
+/// [`Query::par_iter_many_unique_mut`] to hand out mutable query items for an arbitrary,
+/// runtime-sized list of entities without checking for aliasing on every item.
+///
+/// This is implemented for the standard set types ([`EntityHashSet`], [`BTreeSet<Entity>`])
+/// and for [`UniqueEntitySlice`], which can be used to check an arbitrary slice of entities once up front.
+///
+/// # Safety
+///
+/// Iterating over the [`IntoIterator::into_iter`] of this type must never yield the same [`Entity`] twice.
+///
+/// [`Query::iter_many_unique_mut`]: crate::system::Query::iter_many_unique_mut
+/// [`Query::par_iter_many_unique_mut`]: crate::system::Query::par_iter_many_unique_mut
+pub unsafe trait EntitySet: IntoIterator<Item: Borrow<Entity>> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for EntityHashSet {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for &EntityHashSet {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for hash_set::Iter<'_, Entity> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for hash_set::IntoIter<Entity> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for BTreeSet<Entity> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for &BTreeSet<Entity> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for btree_set::Iter<'_, Entity> {}
+
+// SAFETY: A set contains each entity at most once.
+unsafe impl EntitySet for btree_set::IntoIter<Entity> {}
+
+// SAFETY: `UniqueEntitySlice` can only be constructed from slices without duplicates.
+unsafe impl EntitySet for &UniqueEntitySlice {}
+
+/// A slice of [`Entity`]s that is known to contain no duplicates.
+///
+/// Checking for uniqueness takes `O(n)` time, so a `UniqueEntitySlice` is best created once
+/// and then reused, for example to iterate over the same group of entities in several queries.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity::UniqueEntitySlice;
+/// let mut world = World::new();
+/// let a = world.spawn_empty().id();
+/// let b = world.spawn_empty().id();
+///
+/// assert!(UniqueEntitySlice::from_slice(&[a, b]).is_ok());
+/// assert!(UniqueEntitySlice::from_slice(&[a, b, a]).is_err());
+/// ```
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UniqueEntitySlice([Entity]);
+
+impl UniqueEntitySlice {
+    /// Checks that `entities` contains no duplicates and wraps it in a [`UniqueEntitySlice`].
+    ///
+    /// Returns a [`DuplicateEntityError`] naming the first repeated entity otherwise.
+    pub fn from_slice(entities: &[Entity]) -> Result<&Self, DuplicateEntityError> {
+        // Small slices are cheaper to check pairwise than to hash.
+        if entities.len() <= 8 {
+            for (i, entity) in entities.iter().enumerate() {
+                if entities[..i].contains(entity) {
+                    return Err(DuplicateEntityError(*entity));
+                }
+            }
+        } else {
+            let mut seen = EntityHashSet::with_capacity_and_hasher(entities.len(), EntityHash);
+            for entity in entities {
+                if !seen.insert(*entity) {
+                    return Err(DuplicateEntityError(*entity));
+                }
+            }
+        }
+        // SAFETY: We just checked that there are no duplicates.
+        Ok(unsafe { Self::from_slice_unchecked(entities) })
+    }
+
+    /// Wraps `entities` in a [`UniqueEntitySlice`] without checking for duplicates.
+    ///
+    /// # Safety
+    ///
+    /// `entities` must not contain any [`Entity`] more than once.
+    pub unsafe fn from_slice_unchecked(entities: &[Entity]) -> &Self {
+        // SAFETY: `UniqueEntitySlice` is a `repr(transparent)` wrapper around `[Entity]`.
+        unsafe { &*(std::ptr::from_ref(entities) as *const Self) }
+    }
+
+    /// Returns the underlying slice of entities.
+    pub fn as_slice(&self) -> &[Entity] {
+        &self.0
+    }
+
+    /// Returns an iterator over the entities in this slice.
+    pub fn iter(&self) -> slice::Iter<'_, Entity> {
+        self.0.iter()
+    }
+}
+
+impl Deref for UniqueEntitySlice {
+    type Target = [Entity];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[Entity]> for UniqueEntitySlice {
+    fn as_ref(&self) -> &[Entity] {
+        &self.0
+    }
+}
+
+impl<'a> TryFrom<&'a [Entity]> for &'a UniqueEntitySlice {
+    type Error = DuplicateEntityError;
+
+    fn try_from(entities: &'a [Entity]) -> Result<Self, Self::Error> {
+        UniqueEntitySlice::from_slice(entities)
+    }
+}
+
+impl<'a> IntoIterator for &'a UniqueEntitySlice {
+    type Item = &'a Entity;
+    type IntoIter = slice::Iter<'a, Entity>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Debug for UniqueEntitySlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+/// An error returned by [`UniqueEntitySlice::from_slice`] when the slice contains an entity more than once.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Entity {0:?} appears more than once in the slice")]
+pub struct DuplicateEntityError(pub Entity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_entity_slice_rejects_duplicates() {
+        let entities: Vec<Entity> = (0..20).map(Entity::from_raw).collect();
+
+        // Both the pairwise and the hashing path.
+        for len in [2, 8, 9, 20] {
+            let slice = &entities[..len];
+            assert_eq!(
+                UniqueEntitySlice::from_slice(slice).unwrap().as_slice(),
+                slice
+            );
+
+            let mut duplicated = slice.to_vec();
+            duplicated.push(slice[len / 2]);
+            assert_eq!(
+                UniqueEntitySlice::from_slice(&duplicated),
+                Err(DuplicateEntityError(slice[len / 2]))
+            );
+        }
+    }
+}
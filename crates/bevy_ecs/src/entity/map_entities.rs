@@ -1,7 +1,8 @@
 use crate::{
+    component::{Component, ComponentMutability},
     entity::Entity,
     identifier::masks::{IdentifierMask, HIGH_MASK},
-    world::World,
+    world::{EntityWorldMut, World},
 };
 
 use super::EntityHashMap;
@@ -45,6 +46,25 @@ pub trait MapEntities {
     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M);
 }
 
+/// Maps the [`Entity`] references of the component `C` of `entity` with `entity_mapper`, if it has one.
+///
+/// Mutable components are mapped in place. Immutable components are taken out of the entity, mapped and
+/// inserted again, so that their hooks and observers run like for any other change of their value.
+pub(crate) fn map_component_entities<C: Component + MapEntities>(
+    entity: &mut EntityWorldMut,
+    entity_mapper: &mut impl EntityMapper,
+) {
+    if C::Mutability::MUTABLE {
+        // SAFETY: `C` was just checked to be mutable.
+        if let Some(mut component) = unsafe { entity.get_mut_assume_mutable::<C>() } {
+            component.map_entities(entity_mapper);
+        }
+    } else if let Some(mut component) = entity.take::<C>() {
+        component.map_entities(entity_mapper);
+        entity.insert(component);
+    }
+}
+
 /// An implementor of this trait knows how to map an [`Entity`] into another [`Entity`].
 ///
 /// Usually this is done by using an [`EntityHashMap<Entity>`] to map source entities
@@ -42,7 +42,11 @@ use bevy_reflect::Reflect;
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 pub use map_entities::*;
 
+mod clone_entities;
+mod entity_set;
 mod hash;
+pub use clone_entities::*;
+pub use entity_set::*;
 pub use hash::*;
 
 use bevy_utils::tracing::warn;
@@ -0,0 +1,236 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! An entity is disabled by inserting the [`Disabled`] marker component on it. Disabling an entity
+//! neither despawns it nor removes any of its components, so it can be re-enabled at any time by
+//! removing [`Disabled`]. This is useful for object pools, or for entities that should be hidden
+//! from the game logic, like the entities of an editor.
+//!
+//! Every [`Query`](crate::system::Query) and [`QueryState`] implicitly gets a `Without<Disabled>` filter,
+//! unless it already accesses or filters on [`Disabled`], e.g. with `With<Disabled>`, `Has<Disabled>` or
+//! `Option<&Disabled>`.
+//!
+//! ```
+//! use bevy_ecs::{entity_disabling::Disabled, prelude::*};
+//!
+//! #[derive(Component)]
+//! struct Enemy;
+//!
+//! let mut world = World::new();
+//! world.spawn(Enemy);
+//! let pooled = world.spawn((Enemy, Disabled)).id();
+//!
+//! // Disabled entities are skipped by default...
+//! assert_eq!(world.query::<&Enemy>().iter(&world).count(), 1);
+//! // ...unless the query opts in.
+//! assert_eq!(world.query::<(&Enemy, Has<Disabled>)>().iter(&world).count(), 2);
+//!
+//! world.entity_mut(pooled).remove::<Disabled>();
+//! assert_eq!(world.query::<&Enemy>().iter(&world).count(), 2);
+//! ```
+//!
+//! Other components can be registered as disabling components in the world's [`DefaultQueryFilters`]
+//! (see [`World::default_query_filters_mut`]): entities with any of them are then excluded from queries
+//! in the same way.
+//!
+//! Note that the filters are applied when a query is created: queries built before a disabling component
+//! was registered will not filter it out.
+//!
+//! Disabling components should be stored in tables. Registering a sparse set disabling component makes
+//! every query iterate over archetypes instead of whole tables, which is slower.
+//!
+//! [`QueryState`]: crate::query::QueryState
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId, Components, StorageType},
+    query::FilteredAccess,
+};
+#[cfg(feature = "bevy_reflect")]
+use {crate::reflect::ReflectComponent, bevy_reflect::Reflect};
+
+/// A marker component for disabled entities. See [the module docs](crate::entity_disabling) for more info.
+#[derive(Component, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct Disabled;
+
+/// The default filters applied to every [`Query`](crate::system::Query) and
+/// [`QueryState`](crate::query::QueryState) of a [`World`](crate::world::World).
+///
+/// Entities with any of the registered disabling components are excluded from queries
+/// that do not explicitly mention that component. Worlds register [`Disabled`] when they are created.
+///
+/// Use [`World::default_query_filters_mut`](crate::world::World::default_query_filters_mut) to configure them.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultQueryFilters {
+    disabling: Vec<ComponentId>,
+}
+
+impl DefaultQueryFilters {
+    /// Registers a component as disabling: entities with this component will be excluded from queries
+    /// that do not explicitly mention it.
+    ///
+    /// Only queries created after this call are affected.
+    pub fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling.contains(&component_id) {
+            self.disabling.push(component_id);
+        }
+    }
+
+    /// Unregisters a disabling component, so that entities with it show up in queries again.
+    ///
+    /// Only queries created after this call are affected.
+    pub fn unregister_disabling_component(&mut self, component_id: ComponentId) {
+        self.disabling.retain(|&id| id != component_id);
+    }
+
+    /// Returns the ids of the disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Returns `true` if all disabling components are stored in tables.
+    ///
+    /// Entities with and without a sparse set disabling component share a table, so queries have to
+    /// iterate over archetypes instead of tables to skip the disabled ones.
+    pub(crate) fn is_dense(&self, components: &Components) -> bool {
+        self.disabling.iter().all(|&component_id| {
+            components
+                .get_info(component_id)
+                .is_some_and(|info| info.storage_type() == StorageType::Table)
+        })
+    }
+
+    /// Adds a `Without` filter for every disabling component that is not already mentioned in `component_access`.
+    pub(crate) fn apply(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for &component_id in &self.disabling {
+            if !component_access.contains(component_id) {
+                component_access.and_without(component_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disabled;
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::Entity,
+        query::{Has, With},
+        system::{Query, RunSystemOnce},
+        world::World,
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct Hidden;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct SparseHidden;
+
+    #[test]
+    fn disabled_entities_are_filtered_by_default() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let entities: Vec<Entity> = world.query::<Entity>().iter(&world).collect();
+        assert!(entities.contains(&enabled));
+        assert!(!entities.contains(&disabled));
+
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert!(world.query::<&A>().get(&world, disabled).is_err());
+
+        let count = world.run_system_once(|query: Query<&A>| query.iter().count());
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn queries_can_opt_in_to_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        assert_eq!(world.query::<(&A, Has<Disabled>)>().iter(&world).count(), 2);
+        assert_eq!(
+            world
+                .query::<(&A, Option<&Disabled>)>()
+                .iter(&world)
+                .count(),
+            2
+        );
+        assert_eq!(
+            world
+                .query_filtered::<&A, With<Disabled>>()
+                .iter(&world)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn reenabling_entities() {
+        let mut world = World::new();
+        let entity = world.spawn((A, Disabled)).id();
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 0);
+
+        world.entity_mut(entity).remove::<Disabled>();
+        assert_eq!(query.iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn custom_disabling_components() {
+        let mut world = World::new();
+        let hidden = world.init_component::<Hidden>();
+        world
+            .default_query_filters_mut()
+            .register_disabling_component(hidden);
+        world.spawn(A);
+        world.spawn((A, Hidden));
+        world.spawn((A, Disabled));
+
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<(&A, Has<Hidden>)>().iter(&world).count(), 2);
+
+        let disabled = world.init_component::<Disabled>();
+        world
+            .default_query_filters_mut()
+            .unregister_disabling_component(disabled);
+        world
+            .default_query_filters_mut()
+            .unregister_disabling_component(hidden);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 3);
+    }
+
+    #[test]
+    fn sparse_set_disabling_components() {
+        let mut world = World::new();
+        let hidden = world.init_component::<SparseHidden>();
+        world
+            .default_query_filters_mut()
+            .register_disabling_component(hidden);
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, SparseHidden)).id();
+        assert_eq!(
+            world.entity(enabled).location().table_id,
+            world.entity(disabled).location().table_id
+        );
+
+        let mut query = world.query::<&A>();
+        assert!(!query.is_dense());
+        assert_eq!(query.iter(&world).count(), 1);
+        let count = world.run_system_once(|query: Query<&A>| query.iter().count());
+        assert_eq!(count, 1);
+        let entities = world.run_system_once(|query: Query<Entity, With<A>>| {
+            let mut entities = Vec::new();
+            query.iter().for_each(|entity| entities.push(entity));
+            entities
+        });
+        assert_eq!(entities, vec![enabled]);
+    }
+}
@@ -0,0 +1,87 @@
+use std::{borrow::Cow, fmt};
+
+use bevy_utils::tracing::{debug, error, info, trace, warn};
+
+use crate::{component::Tick, error::Error};
+
+/// Where an [`Error`] passed to an [`ErrorHandler`] came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// The error was returned by a system.
+    System {
+        /// The name of the system.
+        name: Cow<'static, str>,
+        /// The last tick the system was run.
+        last_run: Tick,
+    },
+    /// The error was returned by an observer.
+    Observer {
+        /// The name of the observer's system.
+        name: Cow<'static, str>,
+        /// The last tick the observer was run.
+        last_run: Tick,
+    },
+    /// The error was returned by a command.
+    Command {
+        /// The name of the command.
+        name: Cow<'static, str>,
+    },
+}
+
+impl ErrorContext {
+    /// The name of the system, observer or command that failed.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::System { name, .. } | Self::Observer { name, .. } | Self::Command { name } => {
+                name
+            }
+        }
+    }
+
+    /// A short description of the kind of code that failed.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::System { .. } => "system",
+            Self::Observer { .. } => "observer",
+            Self::Command { .. } => "command",
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind(), self.name())
+    }
+}
+
+/// A function that handles the errors returned by systems, observers and commands.
+///
+/// See [the module docs](crate::error) for how to configure it.
+pub type ErrorHandler = fn(Error, ErrorContext);
+
+/// Error handler that panics with the error. This is the default.
+#[track_caller]
+#[inline]
+pub fn panic(error: Error, context: ErrorContext) {
+    panic!("Encountered an error in {context}: {error}");
+}
+
+/// Error handler that ignores the error.
+#[inline]
+pub fn ignore(_: Error, _: ErrorContext) {}
+
+macro_rules! log_error_handler {
+    ($name: ident, $log: ident, $level: literal) => {
+        #[doc = concat!("Error handler that logs the error at the `", $level, "` level.")]
+        #[inline]
+        pub fn $name(error: Error, context: ErrorContext) {
+            $log!("Encountered an error in {context}: {error}");
+        }
+    };
+}
+
+log_error_handler!(error, error, "error");
+log_error_handler!(warn, warn, "warn");
+log_error_handler!(info, info, "info");
+log_error_handler!(debug, debug, "debug");
+log_error_handler!(trace, trace, "trace");
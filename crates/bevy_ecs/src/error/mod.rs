@@ -0,0 +1,69 @@
+//! Error handling for systems, observers and commands.
+//!
+//! Systems and observers may return a [`Result`] instead of `()`. Any error they return is routed to an
+//! [`ErrorHandler`], which decides what happens to it: by default errors [`panic`], but they can also be
+//! logged with one of the provided handlers ([`error`], [`warn`], ...), [`ignore`]d, or passed to any other
+//! function with the [`ErrorHandler`] signature.
+//!
+//! The handler is configured for the whole [`World`] with [`World::set_error_handler`], and can be
+//! overridden for a single [`Schedule`] with [`Schedule::set_error_handler`].
+//!
+//! ```
+//! use bevy_ecs::{error::{self, Result}, prelude::*, schedule::Schedule};
+//!
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! fn find_player(query: Query<Entity, With<Player>>) -> Result {
+//!     // No need for `let Ok(..) else { return }`: the error is handed to the error handler.
+//!     let player = query.get_single()?;
+//!     println!("Found {player:?}");
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! world.set_error_handler(error::warn);
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(find_player);
+//! // There is no player yet: this logs a warning instead of panicking.
+//! schedule.run(&mut world);
+//! ```
+//!
+//! [`World`]: crate::world::World
+//! [`World::set_error_handler`]: crate::world::World::set_error_handler
+//! [`Schedule`]: crate::schedule::Schedule
+//! [`Schedule::set_error_handler`]: crate::schedule::Schedule::set_error_handler
+
+mod handler;
+
+pub use handler::*;
+
+/// The error type returned by fallible systems, observers and commands.
+///
+/// Any type implementing [`std::error::Error`] can be converted into it with the `?` operator.
+pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A result whose error defaults to [`Error`]. Systems and observers returning `Result` route their errors
+/// to the current [`ErrorHandler`].
+pub type Result<T = (), E = Error> = std::result::Result<T, E>;
+
+/// Outputs of systems and commands that can be checked for an [`Error`]: `()` and [`Result`].
+pub trait IntoResult {
+    /// Converts this output into a [`Result`].
+    fn into_result(self) -> Result;
+}
+
+impl IntoResult for () {
+    #[inline]
+    fn into_result(self) -> Result {
+        Ok(())
+    }
+}
+
+impl IntoResult for Result {
+    #[inline]
+    fn into_result(self) -> Result {
+        self
+    }
+}
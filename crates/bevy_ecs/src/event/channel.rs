@@ -0,0 +1,491 @@
+use crate as bevy_ecs;
+use bevy_ecs::{
+    event::{Event, EventId},
+    system::Resource,
+};
+use bevy_utils::{detailed_trace, tracing::debug};
+use std::{
+    collections::{vec_deque, VecDeque},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Controls how long an [`EventChannel`] keeps events around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventRetention {
+    /// Events are kept for the given number of [`EventChannel::update`] calls.
+    ///
+    /// `Frames(2)` matches the double buffering of [`Events`](super::Events).
+    Frames(usize),
+    /// Events are kept until every active reader has read them, or until one of them consumed them.
+    ///
+    /// Dormant readers (see [`EventChannel`]) don't keep events around.
+    /// If no readers are active, events are removed on the next update.
+    UntilRead,
+}
+
+impl Default for EventRetention {
+    fn default() -> Self {
+        Self::Frames(2)
+    }
+}
+
+/// Identifies a reader registered with an [`EventChannel`] through [`EventChannel::register_reader`].
+///
+/// Reader ids are only valid for the channel they were registered with: other channels,
+/// including channels of the same event type that replaced it, treat them as unknown readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChannelReaderId {
+    channel: usize,
+    index: usize,
+}
+
+/// Gives every [`EventChannel`] a unique id, so that readers of replaced channels are not mistaken for new ones.
+static NEXT_CHANNEL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Counters describing what happened to the events of an [`EventChannel`].
+///
+/// Every event that is removed from the channel is counted exactly once, as either
+/// [`consumed`](Self::consumed), [`read`](Self::read) or [`dropped`](Self::dropped).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventChannelDiagnostics {
+    /// The number of events sent to the channel.
+    pub sent: usize,
+    /// The number of removed events that were consumed by a reader.
+    pub consumed: usize,
+    /// The number of removed events that were read by every active reader.
+    pub read: usize,
+    /// The number of removed events that at least one active reader never saw.
+    pub dropped: usize,
+    /// The number of events dropped during the most recent [`EventChannel::update`].
+    pub dropped_last_update: usize,
+}
+
+#[derive(Debug)]
+struct ChannelEvent<E: Event> {
+    event: E,
+    sent_frame: usize,
+    consumed: bool,
+}
+
+#[derive(Debug)]
+struct ChannelReader {
+    priority: i32,
+    /// The id of the next event this reader has not seen yet.
+    next_event: usize,
+    /// The last frame this reader was registered, read or [marked active](EventChannel::mark_active) in.
+    active_frame: usize,
+}
+
+/// The number of [`EventChannel::update`] calls after which a reader that stopped reading is dormant.
+const DORMANT_AFTER_UPDATES: usize = 2;
+
+/// An opt-in alternative to [`Events`](super::Events) with consumable events,
+/// reader priorities and configurable retention.
+///
+/// Readers are registered with a priority, usually by adding an [`EventChannelReader`] to a system.
+/// A reader only sees an event once every reader with a higher priority has read past it,
+/// regardless of the order the systems run in. Readers with the same priority don't wait for each other.
+/// Any reader can [`consume`](ConsumableEvent::consume) an event, which hides it from all readers that
+/// haven't seen it yet, including the readers with the same priority.
+///
+/// A reader that didn't read from the channel during the last two frames, for example because its
+/// system doesn't run anymore, is dormant: until it reads again, it doesn't hold back lower priority
+/// readers, and events are not kept around or counted as dropped on its behalf.
+///
+/// How long events are kept is controlled by the [`EventRetention`] of the channel.
+/// Events that are removed before every active reader saw them are counted as dropped
+/// in the [`EventChannelDiagnostics`].
+///
+/// Channels are usually added with `App::add_event_channel`, which registers them
+/// in the [`EventRegistry`](super::EventRegistry) so they are updated once per frame.
+///
+/// Methods taking a [`ChannelReaderId`] that was not registered with this channel treat it as
+/// a reader without any visible events.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::event::{Event, EventChannel, EventRetention};
+///
+/// #[derive(Event)]
+/// struct Click {
+///     button: usize,
+/// }
+///
+/// let mut channel = EventChannel::<Click>::new(EventRetention::Frames(2));
+/// let ui = channel.register_reader(10);
+/// let world_picking = channel.register_reader(0);
+///
+/// channel.send(Click { button: 0 });
+/// channel.send(Click { button: 1 });
+///
+/// // The low priority reader has to wait for the UI to handle the clicks first.
+/// assert_eq!(channel.read(world_picking).count(), 0);
+///
+/// // The UI only handles clicks of the first button.
+/// for click in channel.read(ui) {
+///     if click.button == 0 {
+///         click.consume();
+///     }
+/// }
+///
+/// let buttons: Vec<usize> = channel.read(world_picking).map(|click| click.button).collect();
+/// assert_eq!(buttons, vec![1]);
+/// ```
+///
+/// [`EventChannelReader`]: super::EventChannelReader
+#[derive(Debug, Resource)]
+pub struct EventChannel<E: Event> {
+    id: usize,
+    events: VecDeque<ChannelEvent<E>>,
+    /// The id of the first event in `events`.
+    start_event_count: usize,
+    event_count: usize,
+    readers: Vec<ChannelReader>,
+    retention: EventRetention,
+    frame: usize,
+    diagnostics: EventChannelDiagnostics,
+}
+
+impl<E: Event> Default for EventChannel<E> {
+    fn default() -> Self {
+        Self::new(EventRetention::default())
+    }
+}
+
+impl<E: Event> EventChannel<E> {
+    /// Creates an empty channel with the given [`EventRetention`].
+    pub fn new(retention: EventRetention) -> Self {
+        Self {
+            id: NEXT_CHANNEL_ID.fetch_add(1, Ordering::Relaxed),
+            events: VecDeque::new(),
+            start_event_count: 0,
+            event_count: 0,
+            readers: Vec::new(),
+            retention,
+            frame: 0,
+            diagnostics: EventChannelDiagnostics::default(),
+        }
+    }
+
+    /// Returns the [`EventRetention`] of this channel.
+    pub fn retention(&self) -> EventRetention {
+        self.retention
+    }
+
+    /// Changes the [`EventRetention`] of this channel. Takes effect on the next [`update`](Self::update).
+    pub fn set_retention(&mut self, retention: EventRetention) {
+        self.retention = retention;
+    }
+
+    /// Returns the [`EventChannelDiagnostics`] of this channel.
+    pub fn diagnostics(&self) -> EventChannelDiagnostics {
+        self.diagnostics
+    }
+
+    /// Registers a new reader with the given `priority`.
+    ///
+    /// Readers with a higher priority see events before readers with a lower priority.
+    /// Readers with the same priority don't wait for each other, but events consumed by one of them
+    /// are hidden from the others if they haven't seen them yet.
+    /// The new reader will see all events still stored in the channel.
+    pub fn register_reader(&mut self, priority: i32) -> ChannelReaderId {
+        self.readers.push(ChannelReader {
+            priority,
+            next_event: self.start_event_count,
+            active_frame: self.frame,
+        });
+        ChannelReaderId {
+            channel: self.id,
+            index: self.readers.len() - 1,
+        }
+    }
+
+    /// Returns `true` if `reader` was registered with this channel.
+    pub fn contains_reader(&self, reader: ChannelReaderId) -> bool {
+        self.get_reader(reader).is_some()
+    }
+
+    /// Marks `reader` as active during the current frame, as if it had [read](Self::read) from the channel.
+    ///
+    /// [`EventChannelReader`](super::EventChannelReader) does this every time its system runs.
+    /// Does nothing if `reader` was not registered with this channel.
+    pub fn mark_active(&mut self, reader: ChannelReaderId) {
+        let frame = self.frame;
+        if let Some(reader) = self.get_reader_mut(reader) {
+            reader.active_frame = frame;
+        }
+    }
+
+    /// Sends an `event` to the channel.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    pub fn send(&mut self, event: E) -> EventId<E> {
+        let event_id = EventId {
+            id: self.event_count,
+            _marker: PhantomData,
+        };
+        detailed_trace!("EventChannel::send() -> id: {}", event_id);
+
+        self.events.push_back(ChannelEvent {
+            event,
+            sent_frame: self.frame,
+            consumed: false,
+        });
+        self.event_count += 1;
+        self.diagnostics.sent += 1;
+
+        event_id
+    }
+
+    /// Sends the default value of the event. Useful when the event is an empty struct.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    pub fn send_default(&mut self) -> EventId<E>
+    where
+        E: Default,
+    {
+        self.send(Default::default())
+    }
+
+    /// Iterates over the events `reader` has not seen yet and that are visible to it,
+    /// i.e. that have been read by all readers with a higher priority and were not consumed.
+    ///
+    /// The iterator is empty if `reader` was not registered with this channel.
+    pub fn read(&mut self, reader: ChannelReaderId) -> ConsumableEventIter<'_, E> {
+        let Some(priority) = self.get_reader(reader).map(|reader| reader.priority) else {
+            return ConsumableEventIter {
+                events: self.events.range_mut(0..0),
+                next_event: None,
+                event_count: self.event_count,
+                limit: self.event_count,
+            };
+        };
+        self.mark_active(reader);
+        let limit = self.visible_limit(priority);
+        let next_event = &mut self.readers[reader.index].next_event;
+        let start = (*next_event).max(self.start_event_count).min(limit);
+        let events = self
+            .events
+            .range_mut(start - self.start_event_count..limit - self.start_event_count);
+        ConsumableEventIter {
+            events,
+            next_event: Some(next_event),
+            event_count: start,
+            limit,
+        }
+    }
+
+    /// Returns the number of events `reader` can currently [`read`](Self::read).
+    ///
+    /// Returns `0` if `reader` was not registered with this channel.
+    pub fn visible_len(&self, reader: ChannelReaderId) -> usize {
+        let Some(reader) = self.get_reader(reader) else {
+            return 0;
+        };
+        let limit = self.visible_limit(reader.priority);
+        let start = reader.next_event.max(self.start_event_count).min(limit);
+        self.events
+            .range(start - self.start_event_count..limit - self.start_event_count)
+            .filter(|event| !event.consumed)
+            .count()
+    }
+
+    /// Returns the number of events `reader` missed because they were removed before it read them.
+    ///
+    /// Returns `0` if `reader` was not registered with this channel.
+    pub fn missed_events(&self, reader: ChannelReaderId) -> usize {
+        self.get_reader(reader).map_or(0, |reader| {
+            self.start_event_count.saturating_sub(reader.next_event)
+        })
+    }
+
+    /// Returns the number of events currently stored in the channel, including consumed ones.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there are no events currently stored in the channel.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes all events, counting the ones that weren't seen by every active reader as dropped.
+    pub fn clear(&mut self) {
+        let dropped_before = self.diagnostics.dropped;
+        while !self.events.is_empty() {
+            self.remove_oldest();
+        }
+        self.diagnostics.dropped_last_update = self.diagnostics.dropped - dropped_before;
+    }
+
+    /// Advances the channel by one frame and removes the events that are past their [`EventRetention`].
+    /// In general, this should be called once per frame/update.
+    pub fn update(&mut self) {
+        self.frame += 1;
+        let dropped_before = self.diagnostics.dropped;
+        while let Some(oldest) = self.events.front() {
+            let expired = match self.retention {
+                EventRetention::Frames(frames) => self.frame - oldest.sent_frame >= frames,
+                EventRetention::UntilRead => {
+                    oldest.consumed || self.is_read_by_active(self.start_event_count)
+                }
+            };
+            if !expired {
+                break;
+            }
+            self.remove_oldest();
+        }
+        let dropped = self.diagnostics.dropped - dropped_before;
+        self.diagnostics.dropped_last_update = dropped;
+        if dropped > 0 {
+            debug!(
+                "EventChannel<{}> dropped {} events that were not seen by every active reader",
+                std::any::type_name::<E>(),
+                dropped
+            );
+        }
+    }
+
+    fn get_reader(&self, reader: ChannelReaderId) -> Option<&ChannelReader> {
+        (reader.channel == self.id)
+            .then(|| self.readers.get(reader.index))
+            .flatten()
+    }
+
+    fn get_reader_mut(&mut self, reader: ChannelReaderId) -> Option<&mut ChannelReader> {
+        (reader.channel == self.id)
+            .then(|| self.readers.get_mut(reader.index))
+            .flatten()
+    }
+
+    /// The id of the first event a reader with the given `priority` is not allowed to see yet.
+    ///
+    /// This is never lower than the id of the first stored event, as higher priority readers
+    /// may have missed events that were removed since.
+    fn visible_limit(&self, priority: i32) -> usize {
+        self.readers
+            .iter()
+            .filter(|other| other.priority > priority && !self.is_dormant(other))
+            .map(|other| other.next_event)
+            .fold(self.event_count, usize::min)
+            .max(self.start_event_count)
+    }
+
+    fn is_dormant(&self, reader: &ChannelReader) -> bool {
+        self.frame - reader.active_frame > DORMANT_AFTER_UPDATES
+    }
+
+    fn is_read_by_active(&self, event_count: usize) -> bool {
+        self.readers
+            .iter()
+            .all(|reader| reader.next_event > event_count || self.is_dormant(reader))
+    }
+
+    fn remove_oldest(&mut self) {
+        let Some(event) = self.events.pop_front() else {
+            return;
+        };
+        if event.consumed {
+            self.diagnostics.consumed += 1;
+        } else if self.is_read_by_active(self.start_event_count) {
+            self.diagnostics.read += 1;
+        } else {
+            self.diagnostics.dropped += 1;
+        }
+        self.start_event_count += 1;
+    }
+}
+
+impl<E: Event> Extend<E> for EventChannel<E> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for event in iter {
+            self.send(event);
+        }
+    }
+}
+
+/// An event read from an [`EventChannel`], which can be [consumed](Self::consume)
+/// to hide it from the readers that haven't seen it yet.
+#[derive(Debug)]
+pub struct ConsumableEvent<'a, E: Event> {
+    event: &'a mut ChannelEvent<E>,
+    id: EventId<E>,
+}
+
+impl<'a, E: Event> ConsumableEvent<'a, E> {
+    /// Returns the [`EventId`] of this event.
+    pub fn id(&self) -> EventId<E> {
+        self.id
+    }
+
+    /// Returns the event.
+    pub fn event(&self) -> &E {
+        &self.event.event
+    }
+
+    /// Marks the event as handled, so that readers that haven't seen it yet will skip it.
+    pub fn consume(self) {
+        self.event.consumed = true;
+    }
+}
+
+impl<'a, E: Event> Deref for ConsumableEvent<'a, E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event.event
+    }
+}
+
+/// An iterator over the events a reader can see in an [`EventChannel`].
+///
+/// This struct is created by [`EventChannel::read`].
+#[derive(Debug)]
+pub struct ConsumableEventIter<'a, E: Event> {
+    events: vec_deque::IterMut<'a, ChannelEvent<E>>,
+    /// The id of the next event the reader has not seen yet, or `None` for unknown readers.
+    next_event: Option<&'a mut usize>,
+    /// The id of the next event in `events`.
+    event_count: usize,
+    limit: usize,
+}
+
+impl<'a, E: Event> Iterator for ConsumableEventIter<'a, E> {
+    type Item = ConsumableEvent<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(event) = self.events.next() else {
+                // Skip over any trailing consumed events.
+                if let Some(next_event) = &mut self.next_event {
+                    **next_event = (**next_event).max(self.limit);
+                }
+                return None;
+            };
+            let id = EventId {
+                id: self.event_count,
+                _marker: PhantomData,
+            };
+            self.event_count += 1;
+            if let Some(next_event) = &mut self.next_event {
+                **next_event = self.event_count;
+            }
+            if !event.consumed {
+                detailed_trace!("ConsumableEventIter::next() -> {}", id);
+                return Some(ConsumableEvent { event, id });
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.events.len()))
+    }
+}
+
+impl<'a, E: Event> FusedIterator for ConsumableEventIter<'a, E> {}
@@ -0,0 +1,154 @@
+use crate as bevy_ecs;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::{ComponentId, Tick},
+    event::{ChannelReaderId, ConsumableEventIter, Event, EventChannel, EventId},
+    system::{ResMut, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+
+/// Reads events of type `E` from an [`EventChannel`], in order of reader priority.
+///
+/// Each `EventChannelReader` registers its own reader with the channel when the system is initialized,
+/// using `PRIORITY` as its priority. A reader only sees an event once every reader with a higher
+/// priority has read past it, and any reader can consume an event to hide it from the readers that
+/// haven't seen it yet, including the readers with the same priority.
+///
+/// The [`EventChannel`] is usually added with `App::add_event_channel`. If it doesn't exist yet
+/// when the system is initialized, the reader is registered the first time the system runs instead.
+/// If the channel is replaced, the reader is registered again with the new channel.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::event::EventChannelReader;
+/// #[derive(Event)]
+/// struct Click;
+///
+/// fn ui_system(mut clicks: EventChannelReader<Click, 10>) {
+///     for click in clicks.read() {
+///         // The UI handled the click, so the world shouldn't see it.
+///         click.consume();
+///     }
+/// }
+///
+/// fn picking_system(mut clicks: EventChannelReader<Click>) {
+///     for click in clicks.read() {
+///         // Only sees the clicks the UI didn't consume.
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(ui_system);
+/// # bevy_ecs::system::assert_is_system(picking_system);
+/// ```
+///
+/// # Concurrency
+///
+/// `EventChannelReader` has [`ResMut<EventChannel<E>>`](EventChannel) inside, so systems reading or
+/// writing the same channel won't be executed concurrently.
+#[derive(Debug)]
+pub struct EventChannelReader<'w, E: Event, const PRIORITY: i32 = 0> {
+    reader: ChannelReaderId,
+    channel: ResMut<'w, EventChannel<E>>,
+}
+
+impl<'w, E: Event, const PRIORITY: i32> EventChannelReader<'w, E, PRIORITY> {
+    /// Iterates over the events this reader has not seen yet and that haven't been consumed
+    /// by a reader with a higher priority.
+    pub fn read(&mut self) -> ConsumableEventIter<'_, E> {
+        self.channel.read(self.reader)
+    }
+
+    /// Returns the number of events that can currently be read.
+    pub fn len(&self) -> usize {
+        self.channel.visible_len(self.reader)
+    }
+
+    /// Returns `true` if there are no events available to read.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of events this reader missed because they were removed before it read them.
+    pub fn missed_events(&self) -> usize {
+        self.channel.missed_events(self.reader)
+    }
+
+    /// Returns the [`ChannelReaderId`] this reader was registered with.
+    pub fn id(&self) -> ChannelReaderId {
+        self.reader
+    }
+}
+
+// SAFETY: this impl defers to `ResMut`, which initializes and validates the correct world access.
+unsafe impl<'w, E: Event, const PRIORITY: i32> SystemParam for EventChannelReader<'w, E, PRIORITY> {
+    type State = (Option<ChannelReaderId>, ComponentId);
+    type Item<'world, 'state> = EventChannelReader<'world, E, PRIORITY>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let component_id = ResMut::<EventChannel<E>>::init_state(world, system_meta);
+        let reader = world
+            .get_resource_mut::<EventChannel<E>>()
+            .map(|mut channel| channel.register_reader(PRIORITY));
+        (reader, component_id)
+    }
+
+    #[inline]
+    unsafe fn get_param<'world, 'state>(
+        (reader, component_id): &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        // SAFETY: The access was registered in `init_state`.
+        let mut channel = unsafe {
+            ResMut::<EventChannel<E>>::get_param(component_id, system_meta, world, change_tick)
+        };
+        // The reader is registered again if the channel was replaced since it was registered.
+        let reader = match *reader {
+            Some(id) if channel.contains_reader(id) => id,
+            _ => *reader.insert(channel.register_reader(PRIORITY)),
+        };
+        // The reader runs, so it shouldn't be considered dormant even if it doesn't read.
+        channel.bypass_change_detection().mark_active(reader);
+        EventChannelReader { reader, channel }
+    }
+}
+
+/// Sends events of type `E` to an [`EventChannel`].
+///
+/// # Concurrency
+///
+/// `EventChannelWriter` has [`ResMut<EventChannel<E>>`](EventChannel) inside, so systems reading or
+/// writing the same channel won't be executed concurrently.
+#[derive(SystemParam)]
+pub struct EventChannelWriter<'w, E: Event> {
+    channel: ResMut<'w, EventChannel<E>>,
+}
+
+impl<'w, E: Event> EventChannelWriter<'w, E> {
+    /// Sends an `event` to the channel.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    ///
+    /// See [`EventChannel`] for details.
+    pub fn send(&mut self, event: E) -> EventId<E> {
+        self.channel.send(event)
+    }
+
+    /// Sends a list of `events` all at once.
+    ///
+    /// See [`EventChannel`] for details.
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) {
+        self.channel.extend(events);
+    }
+
+    /// Sends the default value of the event. Useful when the event is an empty struct.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    ///
+    /// See [`EventChannel`] for details.
+    pub fn send_default(&mut self) -> EventId<E>
+    where
+        E: Default,
+    {
+        self.channel.send_default()
+    }
+}
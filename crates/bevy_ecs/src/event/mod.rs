@@ -1,5 +1,7 @@
 //! Event handling types.
 mod base;
+mod channel;
+mod channel_params;
 mod collections;
 mod event_cursor;
 mod iterators;
@@ -13,6 +15,11 @@ mod writer;
 pub(crate) use base::EventInstance;
 pub use base::{Event, EventId};
 pub use bevy_ecs_macros::Event;
+pub use channel::{
+    ChannelReaderId, ConsumableEvent, ConsumableEventIter, EventChannel, EventChannelDiagnostics,
+    EventRetention,
+};
+pub use channel_params::{EventChannelReader, EventChannelWriter};
 pub use collections::{Events, SendBatchIds};
 pub use event_cursor::EventCursor;
 #[cfg(feature = "multi_threaded")]
@@ -32,7 +39,7 @@ pub use writer::EventWriter;
 #[cfg(test)]
 mod tests {
     use crate as bevy_ecs;
-    use bevy_ecs::{event::*, system::assert_is_read_only_system};
+    use bevy_ecs::{component::Tick, event::*, system::assert_is_read_only_system};
     use bevy_ecs_macros::Event;
 
     #[derive(Event, Copy, Clone, PartialEq, Eq, Debug)]
@@ -620,4 +627,297 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    fn read_channel(channel: &mut EventChannel<TestEvent>, reader: ChannelReaderId) -> Vec<usize> {
+        channel.read(reader).map(|event| event.i).collect()
+    }
+
+    #[test]
+    fn test_event_channel_priorities() {
+        let mut channel = EventChannel::<TestEvent>::new(EventRetention::Frames(2));
+        let low = channel.register_reader(-1);
+        let high = channel.register_reader(5);
+        let high_2 = channel.register_reader(5);
+
+        channel.extend((0..4).map(|i| TestEvent { i }));
+
+        // lower priority readers wait for every higher priority reader
+        assert_eq!(channel.visible_len(low), 0);
+        assert!(read_channel(&mut channel, low).is_empty());
+
+        for event in channel.read(high) {
+            if event.i % 2 == 0 {
+                event.consume();
+            }
+        }
+        assert_eq!(channel.visible_len(low), 0);
+
+        // readers of the same priority don't wait for each other, but see consumed events as gone
+        assert_eq!(read_channel(&mut channel, high_2), vec![1, 3]);
+        assert_eq!(read_channel(&mut channel, low), vec![1, 3]);
+
+        channel.send(TestEvent { i: 4 });
+        channel.read(high).next().unwrap().consume();
+        assert_eq!(read_channel(&mut channel, high_2), vec![]);
+        assert_eq!(read_channel(&mut channel, low), vec![]);
+    }
+
+    #[test]
+    fn test_event_channel_frame_retention() {
+        let mut channel = EventChannel::<TestEvent>::new(EventRetention::Frames(2));
+        let reader = channel.register_reader(0);
+        let slow_reader = channel.register_reader(0);
+
+        channel.send(TestEvent { i: 0 });
+        channel.update();
+        channel.send(TestEvent { i: 1 });
+        assert_eq!(read_channel(&mut channel, reader), vec![0, 1]);
+        channel.update();
+
+        // event 0 expired before the slow reader read it
+        assert_eq!(channel.len(), 1);
+        assert_eq!(channel.missed_events(slow_reader), 1);
+        assert_eq!(read_channel(&mut channel, slow_reader), vec![1]);
+        let diagnostics = channel.diagnostics();
+        assert_eq!(diagnostics.sent, 2);
+        assert_eq!(diagnostics.dropped, 1);
+        assert_eq!(diagnostics.dropped_last_update, 1);
+
+        channel.update();
+        assert!(channel.is_empty());
+        let diagnostics = channel.diagnostics();
+        assert_eq!(diagnostics.read, 1);
+        assert_eq!(diagnostics.dropped, 1);
+        assert_eq!(diagnostics.dropped_last_update, 0);
+    }
+
+    #[test]
+    fn test_event_channel_until_read_retention() {
+        let mut channel = EventChannel::<TestEvent>::new(EventRetention::UntilRead);
+        let reader = channel.register_reader(1);
+        let slow_reader = channel.register_reader(0);
+
+        channel.extend((0..3).map(|i| TestEvent { i }));
+        channel.update();
+        assert_eq!(channel.len(), 3);
+
+        channel.read(reader).next().unwrap().consume();
+        assert!(read_channel(&mut channel, slow_reader).is_empty());
+        channel.update();
+        // consumed events are removed even if other readers didn't see them
+        assert_eq!(channel.len(), 2);
+
+        assert_eq!(read_channel(&mut channel, reader), vec![1, 2]);
+        channel.update();
+        assert_eq!(channel.len(), 2);
+
+        assert_eq!(read_channel(&mut channel, slow_reader), vec![1, 2]);
+        channel.update();
+        assert!(channel.is_empty());
+        assert_eq!(
+            channel.diagnostics(),
+            EventChannelDiagnostics {
+                sent: 3,
+                consumed: 1,
+                read: 2,
+                dropped: 0,
+                dropped_last_update: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_event_channel_dormant_readers() {
+        let mut channel = EventChannel::<TestEvent>::new(EventRetention::UntilRead);
+        let dormant = channel.register_reader(1);
+        let reader = channel.register_reader(0);
+
+        let mut seen = Vec::new();
+        for i in 0..4 {
+            channel.send(TestEvent { i });
+            seen.extend(read_channel(&mut channel, reader));
+            channel.update();
+        }
+        // once the higher priority reader didn't read for more than two updates,
+        // it doesn't hold back the other reader and doesn't keep the events around
+        assert_eq!(seen, vec![0, 1, 2, 3]);
+        assert!(channel.is_empty());
+        assert_eq!(channel.diagnostics().read, 4);
+
+        // it is active again once it reads
+        channel.send(TestEvent { i: 4 });
+        assert_eq!(channel.missed_events(dormant), 4);
+        assert_eq!(read_channel(&mut channel, dormant), vec![4]);
+        channel.send(TestEvent { i: 5 });
+        assert_eq!(read_channel(&mut channel, reader), vec![4]);
+    }
+
+    #[test]
+    fn test_event_channel_dormant_reader_system() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Seen(Vec<usize>);
+
+        let mut world = World::new();
+        world.init_resource::<Seen>();
+        EventRegistry::register_channel::<TestEvent>(&mut world, EventRetention::Frames(2));
+        // registering the channel again keeps the existing one, which is still updated once per frame
+        EventRegistry::register_channel::<TestEvent>(&mut world, EventRetention::UntilRead);
+        assert_eq!(
+            world.resource::<EventChannel<TestEvent>>().retention(),
+            EventRetention::Frames(2)
+        );
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            (|mut events: EventChannelReader<TestEvent, 1>| {
+                events.read().for_each(ConsumableEvent::consume);
+            })
+            .run_if(|| false),
+            |mut events: EventChannelReader<TestEvent>, mut seen: ResMut<Seen>| {
+                seen.0.extend(events.read().map(|event| event.i));
+            },
+        ));
+        for i in 0..5 {
+            world
+                .resource_mut::<EventChannel<TestEvent>>()
+                .send(TestEvent { i });
+            schedule.run(&mut world);
+            world.resource_scope(|world, mut registry: Mut<EventRegistry>| {
+                registry.run_updates(world, Tick::new(0));
+            });
+        }
+
+        // the system of the higher priority reader never runs,
+        // so it only holds back the events sent during the first frames
+        assert_eq!(world.resource::<Seen>().0, vec![2, 3, 4]);
+        let diagnostics = world.resource::<EventChannel<TestEvent>>().diagnostics();
+        assert_eq!(diagnostics.dropped, 2);
+    }
+
+    #[test]
+    fn test_event_channel_unknown_readers() {
+        let mut channel = EventChannel::<TestEvent>::new(EventRetention::Frames(2));
+        let mut other = EventChannel::<TestEvent>::new(EventRetention::Frames(2));
+        let reader = channel.register_reader(0);
+        let unknown = other.register_reader(1);
+        assert!(channel.contains_reader(reader));
+        assert!(!channel.contains_reader(unknown));
+
+        channel.send(TestEvent { i: 0 });
+        channel.mark_active(unknown);
+        assert_eq!(channel.visible_len(unknown), 0);
+        assert_eq!(channel.missed_events(unknown), 0);
+        assert!(read_channel(&mut channel, unknown).is_empty());
+        // the unknown reader doesn't hold back the registered one, despite its priority
+        assert_eq!(read_channel(&mut channel, reader), vec![0]);
+    }
+
+    #[test]
+    fn test_event_channel_registered_after_insert() {
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.insert_resource(EventChannel::<TestEvent>::new(EventRetention::Frames(2)));
+        EventRegistry::register_channel::<TestEvent>(&mut world, EventRetention::UntilRead);
+        EventRegistry::register_channel::<TestEvent>(&mut world, EventRetention::UntilRead);
+
+        world
+            .resource_mut::<EventChannel<TestEvent>>()
+            .send(TestEvent { i: 0 });
+        let mut update = |world: &mut World| {
+            world.resource_scope(|world, mut registry: Mut<EventRegistry>| {
+                registry.run_updates(world, Tick::new(0));
+            });
+        };
+        // the inserted channel is updated once per frame, with its own retention
+        update(&mut world);
+        assert_eq!(world.resource::<EventChannel<TestEvent>>().len(), 1);
+        update(&mut world);
+        assert!(world.resource::<EventChannel<TestEvent>>().is_empty());
+    }
+
+    #[test]
+    fn test_event_channel_reader_of_replaced_channel() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Seen(Vec<usize>);
+
+        let mut world = World::new();
+        world.init_resource::<Seen>();
+        world.insert_resource(EventChannel::<TestEvent>::default());
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            |mut events: EventChannelReader<TestEvent>, mut seen: ResMut<Seen>| {
+                seen.0.extend(events.read().map(|event| event.i));
+            },
+        );
+        world
+            .resource_mut::<EventChannel<TestEvent>>()
+            .send(TestEvent { i: 0 });
+        schedule.run(&mut world);
+
+        // the reader is registered again with the new channel
+        let mut channel = EventChannel::<TestEvent>::default();
+        channel.send(TestEvent { i: 1 });
+        world.insert_resource(channel);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Seen>().0, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_event_channel_params() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Seen(Vec<(i32, usize)>);
+
+        fn consume_even(mut events: EventChannelReader<TestEvent, 1>, mut seen: ResMut<Seen>) {
+            for event in events.read() {
+                seen.0.push((1, event.i));
+                if event.i % 2 == 0 {
+                    event.consume();
+                }
+            }
+        }
+
+        fn read_rest(mut events: EventChannelReader<TestEvent>, mut seen: ResMut<Seen>) {
+            for event in events.read() {
+                seen.0.push((0, event.i));
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Seen>();
+        EventRegistry::register_channel::<TestEvent>(&mut world, EventRetention::UntilRead);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut writer: EventChannelWriter<TestEvent>, mut sent: Local<bool>| {
+                    if !std::mem::replace(&mut *sent, true) {
+                        writer.send_batch((0..3).map(|i| TestEvent { i }));
+                    }
+                },
+                read_rest,
+                consume_even,
+            )
+                .chain(),
+        );
+        // the low priority reader runs first, so it only sees events on the next run
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Seen>().0, vec![(1, 0), (1, 1), (1, 2)]);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<Seen>().0,
+            vec![(1, 0), (1, 1), (1, 2), (0, 1)]
+        );
+
+        world.resource_scope(|world, mut registry: Mut<EventRegistry>| {
+            registry.run_updates(world, Tick::new(0));
+        });
+        assert!(world.resource::<EventChannel<TestEvent>>().is_empty());
+    }
 }
@@ -2,7 +2,7 @@ use crate as bevy_ecs;
 use bevy_ecs::{
     change_detection::{DetectChangesMut, MutUntyped},
     component::{ComponentId, Tick},
-    event::{Event, Events},
+    event::{Event, EventChannel, EventRetention, Events},
     system::Resource,
     world::World,
 };
@@ -12,6 +12,8 @@ struct RegisteredEvent {
     component_id: ComponentId,
     // Required to flush the secondary buffer and drop events even if left unchanged.
     previously_updated: bool,
+    // Event channels count frames, so they need to be updated even if left unchanged.
+    always_update: bool,
     // SAFETY: The component ID and the function must be used to fetch the Events<T> resource
     // of the same type initialized in `register_event`, or improper type casts will occur.
     update: unsafe fn(MutUntyped),
@@ -53,6 +55,7 @@ impl EventRegistry {
         registry.event_updates.push(RegisteredEvent {
             component_id,
             previously_updated: false,
+            always_update: false,
             update: |ptr| {
                 // SAFETY: The resource was initialized with the type Events<T>.
                 unsafe { ptr.with_type::<Events<T>>() }
@@ -62,13 +65,49 @@ impl EventRegistry {
         });
     }
 
+    /// Registers an [`EventChannel`] to be updated in a given [`World`], inserting it with the given
+    /// [`EventRetention`] if it doesn't exist yet. A channel that was already inserted is kept as is,
+    /// and registering the same channel again does nothing.
+    ///
+    /// If no instance of the [`EventRegistry`] exists in the world, this will add one - otherwise it will use
+    /// the existing instance.
+    pub fn register_channel<T: Event>(world: &mut World, retention: EventRetention) {
+        if !world.contains_resource::<EventChannel<T>>() {
+            world.insert_resource(EventChannel::<T>::new(retention));
+        }
+        let component_id = world.components().resource_id::<EventChannel<T>>().unwrap();
+        let mut registry = world.get_resource_or_insert_with(Self::default);
+        if registry
+            .event_updates
+            .iter()
+            .any(|e| e.component_id == component_id)
+        {
+            return;
+        }
+        registry.event_updates.push(RegisteredEvent {
+            component_id,
+            previously_updated: false,
+            always_update: true,
+            update: |ptr| {
+                // SAFETY: The resource was initialized with the type EventChannel<T>.
+                unsafe { ptr.with_type::<EventChannel<T>>() }
+                    .bypass_change_detection()
+                    .update();
+            },
+        });
+    }
+
     /// Updates all of the registered events in the World.
     pub fn run_updates(&mut self, world: &mut World, last_change_tick: Tick) {
         for registered_event in &mut self.event_updates {
             // Bypass the type ID -> Component ID lookup with the cached component ID.
             if let Some(events) = world.get_resource_mut_by_id(registered_event.component_id) {
                 let has_changed = events.has_changed_since(last_change_tick);
-                if registered_event.previously_updated || has_changed {
+                if registered_event.always_update {
+                    // SAFETY: The update function pointer is called with the resource
+                    // fetched from the same component ID.
+                    unsafe { (registered_event.update)(events) };
+                } else if registered_event.previously_updated || has_changed {
                     // SAFETY: The update function pointer is called with the resource
                     // fetched from the same component ID.
                     unsafe { (registered_event.update)(events) };
@@ -90,4 +129,16 @@ impl EventRegistry {
             .retain(|e| e.component_id != component_id);
         world.remove_resource::<Events<T>>();
     }
+
+    /// Removes an [`EventChannel`] from the world and it's associated [`EventRegistry`].
+    pub fn deregister_channel<T: Event>(world: &mut World) {
+        let Some(component_id) = world.components().resource_id::<EventChannel<T>>() else {
+            return;
+        };
+        let mut registry = world.get_resource_or_insert_with(Self::default);
+        registry
+            .event_updates
+            .retain(|e| e.component_id != component_id);
+        world.remove_resource::<EventChannel<T>>();
+    }
 }
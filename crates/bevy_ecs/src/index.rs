@@ -0,0 +1,427 @@
+//! Lookups of entities by the value of one of their components.
+//!
+//! Finding the entities whose component has a given value normally requires iterating over a
+//! `Query<(Entity, &C)>` and comparing every value. For components that are frequently looked up by value,
+//! like identifiers, names or grid coordinates, a [`ComponentIndex`] can be registered with
+//! [`World::register_index`]: it maps each value of the component to the set of entities holding it,
+//! and is kept up to date by the component's [hooks](crate::component::ComponentHooks).
+//! Systems can then read it through the [`Index`] system parameter.
+//!
+//! The hooks see every insertion, replacement and removal of the component, so the index of an
+//! [immutable](crate::component::Immutable) component is always up to date. Values of mutable components
+//! can also change through `&mut C` without running any hook: those changes are picked up by the
+//! [`update_index`] system, which should run before the systems reading the index.
+//!
+//! ```
+//! use bevy_ecs::{index::Index, prelude::*, system::RunSystemOnce};
+//!
+//! #[derive(Component, Clone, PartialEq, Eq, Hash)]
+//! #[component(immutable)]
+//! struct Team(u32);
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! let mut world = World::new();
+//! world.register_index::<Team>();
+//!
+//! world.spawn((Team(0), Health(10)));
+//! world.spawn((Team(1), Health(20)));
+//! world.spawn((Team(1), Health(30)));
+//!
+//! fn heal_team(team: Index<Team>, mut health: Query<&mut Health>) {
+//!     let mut members = health.iter_many_mut(team.get(&Team(1)));
+//!     while let Some(mut health) = members.fetch_next() {
+//!         health.0 += 5;
+//!     }
+//! }
+//!
+//! world.run_system_once(heal_team);
+//!
+//! let mut health = world.query::<(&Team, &Health)>();
+//! for (team, health) in health.iter(&world) {
+//!     assert!(team.0 == 0 || health.0 % 10 == 5);
+//! }
+//! ```
+
+use std::{hash::Hash, marker::PhantomData, ops::Deref};
+
+use bevy_utils::HashMap;
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentHook, ComponentId},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    query::Changed,
+    system::{Query, Res, ResMut, Resource, SystemParam},
+    world::{DeferredWorld, World},
+};
+
+/// A [`Component`] that can be indexed with a [`ComponentIndex`].
+///
+/// This is implemented for every component that can be used as a key of a [`HashMap`].
+pub trait IndexableComponent: Component + Eq + Hash + Clone {}
+
+impl<C> IndexableComponent for C where C: Component + Eq + Hash + Clone {}
+
+/// A [`Resource`] mapping each value of the component `C` to the entities holding it.
+///
+/// It is created by [`World::register_index`] and kept up to date automatically, except for values
+/// changed through `&mut C` which are picked up by [`update_index`].
+/// See [the module docs](crate::index) for more info.
+#[derive(Resource)]
+pub struct ComponentIndex<C: IndexableComponent> {
+    entities: HashMap<C, EntityHashSet>,
+    /// The value each entity is indexed by, which differs from its current value if it was
+    /// changed through `&mut C` since the last [`update_index`].
+    values: EntityHashMap<C>,
+}
+
+impl<C: IndexableComponent> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: HashMap::default(),
+            values: EntityHashMap::default(),
+        }
+    }
+}
+
+impl<C: IndexableComponent> ComponentIndex<C> {
+    /// Returns an iterator over the entities whose `C` component is equal to `value`, in arbitrary order.
+    pub fn get(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.get(value).into_iter().flatten().copied()
+    }
+
+    /// Returns the only entity whose `C` component is equal to `value`, or `None` if there are
+    /// no such entities or more than one.
+    pub fn get_single(&self, value: &C) -> Option<Entity> {
+        match self.entities.get(value) {
+            Some(entities) if entities.len() == 1 => entities.iter().next().copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if any entity has a `C` component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities.contains_key(value)
+    }
+
+    /// Returns the number of entities whose `C` component is equal to `value`.
+    pub fn count(&self, value: &C) -> usize {
+        self.entities.get(value).map_or(0, EntityHashSet::len)
+    }
+
+    /// Returns an iterator over the distinct values of `C` held by at least one entity, in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &C> + '_ {
+        self.entities.keys()
+    }
+
+    /// Returns the number of distinct values of `C` held by at least one entity.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity has a `C` component.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.entities
+            .entry(value.clone())
+            .or_default()
+            .insert(entity);
+        self.values.insert(entity, value);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let Some(value) = self.values.remove(&entity) else {
+            return;
+        };
+        if let Some(entities) = self.entities.get_mut(&value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(&value);
+            }
+        }
+    }
+}
+
+/// Updates the [`ComponentIndex`] of `C` with the values that were changed through `&mut C`.
+///
+/// Insertions, replacements and removals of `C` are tracked by hooks, so this system is only needed for
+/// mutable components. It should run before the systems reading the index, which otherwise see the values
+/// as of its last run.
+///
+/// ```
+/// # use bevy_ecs::{index::{update_index, Index}, prelude::*};
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// struct Cell(i32, i32);
+///
+/// fn move_units(mut cells: Query<&mut Cell>) {
+///     for mut cell in &mut cells {
+///         cell.0 += 1;
+///     }
+/// }
+///
+/// fn find_units(cells: Index<Cell>) {
+///     println!("{} units at the origin", cells.count(&Cell(0, 0)));
+/// }
+///
+/// let mut world = World::new();
+/// world.register_index::<Cell>();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((move_units, update_index::<Cell>, find_units).chain());
+/// # schedule.run(&mut world);
+/// ```
+pub fn update_index<C: IndexableComponent>(
+    mut index: ResMut<ComponentIndex<C>>,
+    changed: Query<(Entity, &C), Changed<C>>,
+) {
+    for (entity, value) in &changed {
+        if index.values.get(&entity) != Some(value) {
+            index.remove(entity);
+            index.insert(value.clone(), entity);
+        }
+    }
+}
+
+/// A [`SystemParam`] to look up entities by the value of their `C` component.
+///
+/// This is a read-only view of the [`ComponentIndex`] registered with [`World::register_index`].
+/// Systems using it will panic if the index was not registered.
+///
+/// ```
+/// # use bevy_ecs::{index::Index, prelude::*};
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// #[component(immutable)]
+/// struct PlayerId(u32);
+///
+/// #[derive(Component)]
+/// struct Score(u32);
+///
+/// fn print_first_player_score(players: Index<PlayerId>, scores: Query<&Score>) {
+///     if let Some(player) = players.get_single(&PlayerId(1)) {
+///         println!("Score: {}", scores.get(player).unwrap().0);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(print_first_player_score);
+/// ```
+#[derive(SystemParam)]
+pub struct Index<'w, C: IndexableComponent> {
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, C: IndexableComponent> Deref for Index<'w, C> {
+    type Target = ComponentIndex<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+impl World {
+    /// Creates a [`ComponentIndex`] for the component `C`, to look up entities by the value of their `C` component.
+    /// Does nothing if the index already exists.
+    ///
+    /// The index is maintained by the `on_insert` and `on_replace` [hooks](crate::component::ComponentHooks) of `C`,
+    /// and works with every [`StorageType`](crate::component::StorageType). Hooks that `C` already has keep
+    /// running: they are called by the index hooks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `C` already exists in an archetype.
+    pub fn register_index<C: IndexableComponent>(&mut self) {
+        if !self.contains_resource::<IndexedHooks<C>>() {
+            let hooks = self.register_component_hooks::<C>();
+            let previous = IndexedHooks::<C> {
+                on_insert: hooks.on_insert.replace(index_on_insert::<C>),
+                on_replace: hooks.on_replace.replace(index_on_replace::<C>),
+                marker: PhantomData,
+            };
+            self.insert_resource(previous);
+        }
+        if !self.contains_resource::<ComponentIndex<C>>() {
+            self.init_resource::<ComponentIndex<C>>();
+        }
+    }
+}
+
+/// The hooks `C` had before it was indexed, which are called by the index hooks.
+///
+/// This is kept apart from the [`ComponentIndex`] so that the hooks keep running if the index is removed.
+#[derive(Resource)]
+struct IndexedHooks<C> {
+    on_insert: Option<ComponentHook>,
+    on_replace: Option<ComponentHook>,
+    marker: PhantomData<fn() -> C>,
+}
+
+fn index_on_insert<C: IndexableComponent>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    component_id: ComponentId,
+) {
+    if let Some(value) = world.get::<C>(entity).cloned() {
+        if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+            index.insert(value, entity);
+        }
+    }
+    if let Some(on_insert) = world.resource::<IndexedHooks<C>>().on_insert {
+        on_insert(world, entity, component_id);
+    }
+}
+
+fn index_on_replace<C: IndexableComponent>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    component_id: ComponentId,
+) {
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.remove(entity);
+    }
+    if let Some(on_replace) = world.resource::<IndexedHooks<C>>().on_replace {
+        on_replace(world, entity, component_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{update_index, ComponentIndex, Index};
+    use crate::{self as bevy_ecs, component::Component, prelude::*, system::RunSystemOnce};
+
+    #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+    #[component(immutable)]
+    struct Cell(i32, i32);
+
+    #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+    #[component(immutable, storage = "SparseSet")]
+    struct Tag(&'static str);
+
+    fn sorted(entities: impl Iterator<Item = Entity>) -> Vec<Entity> {
+        let mut entities: Vec<_> = entities.collect();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_tracks_table_components() {
+        let mut world = World::new();
+        world.register_index::<Cell>();
+
+        let a = world.spawn(Cell(0, 0)).id();
+        let b = world.spawn(Cell(0, 0)).id();
+        let c = world.spawn(Cell(1, 0)).id();
+
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(sorted(index.get(&Cell(0, 0))), vec![a, b]);
+        assert_eq!(index.get_single(&Cell(1, 0)), Some(c));
+        assert_eq!(index.get_single(&Cell(0, 0)), None);
+        assert_eq!(index.len(), 2);
+
+        // Replacing the value moves the entity.
+        world.entity_mut(b).insert(Cell(1, 0));
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(sorted(index.get(&Cell(0, 0))), vec![a]);
+        assert_eq!(sorted(index.get(&Cell(1, 0))), vec![b, c]);
+
+        world.entity_mut(a).remove::<Cell>();
+        world.despawn(c);
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert!(!index.contains(&Cell(0, 0)));
+        assert_eq!(index.count(&Cell(1, 0)), 1);
+        assert_eq!(index.values().collect::<Vec<_>>(), vec![&Cell(1, 0)]);
+    }
+
+    #[test]
+    fn index_tracks_sparse_set_components() {
+        let mut world = World::new();
+        world.register_index::<Tag>();
+
+        let a = world.spawn(Tag("enemy")).id();
+        let b = world.spawn(Tag("enemy")).id();
+        world.entity_mut(a).insert(Tag("ally"));
+        world.entity_mut(b).despawn();
+
+        let index = world.resource::<ComponentIndex<Tag>>();
+        assert_eq!(index.count(&Tag("enemy")), 0);
+        assert_eq!(index.get_single(&Tag("ally")), Some(a));
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn index_system_param() {
+        #[derive(Resource, Default)]
+        struct Found(Vec<Entity>);
+
+        let mut world = World::new();
+        world.register_index::<Cell>();
+        world.init_resource::<Found>();
+        let entity = world.spawn(Cell(2, 3)).id();
+        world.spawn(Cell(3, 2));
+
+        world.run_system_once(|index: Index<Cell>, mut found: ResMut<Found>| {
+            found.0.extend(index.get(&Cell(2, 3)));
+        });
+        assert_eq!(world.resource::<Found>().0, vec![entity]);
+    }
+
+    #[test]
+    fn index_tracks_mutable_components() {
+        #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Name(&'static str);
+
+        let mut world = World::new();
+        world.register_index::<Name>();
+        let a = world.spawn(Name("a")).id();
+        let b = world.spawn(Name("b")).id();
+
+        world.get_mut::<Name>(a).unwrap().0 = "c";
+        // Changes through `&mut Name` are only seen by `update_index`...
+        assert_eq!(
+            world
+                .resource::<ComponentIndex<Name>>()
+                .get_single(&Name("a")),
+            Some(a)
+        );
+        world.run_system_once(update_index::<Name>);
+        let index = world.resource::<ComponentIndex<Name>>();
+        assert!(!index.contains(&Name("a")));
+        assert_eq!(index.get_single(&Name("c")), Some(a));
+
+        // ...but removals always use the value the entity is indexed by.
+        world.get_mut::<Name>(b).unwrap().0 = "d";
+        world.entity_mut(b).remove::<Name>();
+        world.run_system_once(update_index::<Name>);
+        let index = world.resource::<ComponentIndex<Name>>();
+        assert!(!index.contains(&Name("b")));
+        assert!(!index.contains(&Name("d")));
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn index_keeps_existing_hooks() {
+        #[derive(Resource, Default)]
+        struct Counts {
+            inserted: usize,
+            replaced: usize,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counts>();
+        world
+            .register_component_hooks::<Cell>()
+            .on_insert(|mut world, _, _| world.resource_mut::<Counts>().inserted += 1)
+            .on_replace(|mut world, _, _| world.resource_mut::<Counts>().replaced += 1);
+        world.register_index::<Cell>();
+        world.register_index::<Cell>();
+
+        let entity = world.spawn(Cell(0, 0)).id();
+        world.entity_mut(entity).insert(Cell(1, 1));
+        let counts = world.resource::<Counts>();
+        assert_eq!((counts.inserted, counts.replaced), (2, 1));
+        let index = world.resource::<ComponentIndex<Cell>>();
+        assert_eq!(index.get_single(&Cell(1, 1)), Some(entity));
+        assert!(!index.contains(&Cell(0, 0)));
+    }
+}
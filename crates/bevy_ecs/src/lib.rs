@@ -17,14 +17,18 @@ pub mod bundle;
 pub mod change_detection;
 pub mod component;
 pub mod entity;
+pub mod entity_disabling;
+pub mod error;
 pub mod event;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod observer;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+pub mod relationship;
 pub mod removal_detection;
 pub mod schedule;
 pub mod storage;
@@ -60,9 +64,10 @@ pub mod prelude {
             ParamSet, Query, ReadOnlySystem, Res, ResMut, Resource, System, SystemBuilder,
             SystemParamFunction,
         },
+        traversal::TraversalQueryExt,
         world::{
-            EntityMut, EntityRef, EntityWorldMut, FromWorld, OnAdd, OnInsert, OnRemove, OnReplace,
-            World,
+            EntityMut, EntityRef, EntityWorldMut, FromWorld, OnAdd, OnDespawn, OnInsert, OnRemove,
+            OnReplace, World,
         },
     };
 }
@@ -74,8 +79,9 @@ mod tests {
     use crate::{
         bundle::Bundle,
         change_detection::Ref,
-        component::{Component, ComponentId},
+        component::{Component, ComponentId, RequiredComponentsError},
         entity::Entity,
+        entity_disabling::Disabled,
         query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
         system::Resource,
         world::{EntityRef, Mut, World},
@@ -1393,6 +1399,7 @@ mod tests {
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
         expected.add_write(a_id);
         expected.add_read(b_id);
+        expected.and_without(world.init_component::<Disabled>());
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -1741,6 +1748,381 @@ mod tests {
         );
     }
 
+    #[test]
+    fn required_components() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component)]
+        #[require(Z(new_z))]
+        struct Y {
+            value: String,
+        }
+
+        #[derive(Component)]
+        struct Z(u32);
+
+        impl Default for Y {
+            fn default() -> Self {
+                Self {
+                    value: "hello".to_string(),
+                }
+            }
+        }
+
+        fn new_z() -> Z {
+            Z(7)
+        }
+
+        let mut world = World::new();
+        let id = world.spawn(X).id();
+        assert_eq!(
+            "hello",
+            world.entity(id).get::<Y>().unwrap().value,
+            "Y should have the default value"
+        );
+        assert_eq!(
+            7,
+            world.entity(id).get::<Z>().unwrap().0,
+            "Z should have the value provided by the constructor defined in Y"
+        );
+
+        let id = world
+            .spawn((
+                X,
+                Y {
+                    value: "foo".to_string(),
+                },
+            ))
+            .id();
+        assert_eq!(
+            "foo",
+            world.entity(id).get::<Y>().unwrap().value,
+            "Y should have the manually provided value"
+        );
+        assert_eq!(
+            7,
+            world.entity(id).get::<Z>().unwrap().0,
+            "Z should have the value provided by the constructor defined in Y"
+        );
+
+        let id = world.spawn((X, Z(8))).id();
+        assert_eq!(
+            "hello",
+            world.entity(id).get::<Y>().unwrap().value,
+            "Y should have the default value"
+        );
+        assert_eq!(
+            8,
+            world.entity(id).get::<Z>().unwrap().0,
+            "Z should have the manually provided value"
+        );
+    }
+
+    #[test]
+    fn required_components_insert_existing_hooks() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        #[derive(Resource)]
+        struct Counter {
+            added: usize,
+            inserted: usize,
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter {
+            added: 0,
+            inserted: 0,
+        });
+        world
+            .register_component_hooks::<Y>()
+            .on_add(|mut world, _, _| world.resource_mut::<Counter>().added += 1)
+            .on_insert(|mut world, _, _| world.resource_mut::<Counter>().inserted += 1);
+
+        let id = world.spawn(Y).id();
+        world.entity_mut(id).insert(X);
+        let counter = world.resource::<Counter>();
+        assert_eq!(1, counter.added, "Y should not be added a second time");
+        assert_eq!(
+            1, counter.inserted,
+            "Y was already present, so it should not be inserted again"
+        );
+
+        world.spawn_empty().insert(X);
+        let counter = world.resource::<Counter>();
+        assert_eq!(
+            2, counter.added,
+            "Y should be added as a required component"
+        );
+        assert_eq!(2, counter.inserted, "Y should be inserted as well");
+    }
+
+    #[test]
+    fn required_components_removal_keeps_required() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        #[derive(Component)]
+        struct Z;
+
+        let mut world = World::new();
+        let id = world.spawn((X, Z)).id();
+        world.entity_mut(id).remove::<X>();
+        assert!(
+            world.entity(id).contains::<Y>(),
+            "removing X should not remove its required components"
+        );
+        assert!(world.entity(id).contains::<Z>());
+    }
+
+    #[test]
+    fn required_components_spawn_nonexistent_hooks() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        #[derive(Resource)]
+        struct A(usize);
+
+        #[derive(Resource)]
+        struct I(usize);
+
+        let mut world = World::new();
+        world.insert_resource(A(0));
+        world.insert_resource(I(0));
+        world
+            .register_component_hooks::<Y>()
+            .on_add(|mut world, _, _| world.resource_mut::<A>().0 += 1)
+            .on_insert(|mut world, _, _| world.resource_mut::<I>().0 += 1);
+
+        // Spawn entity and ensure Y was added
+        assert!(world.spawn(X).contains::<Y>());
+
+        assert_eq!(world.resource::<A>().0, 1);
+        assert_eq!(world.resource::<I>().0, 1);
+    }
+
+    #[test]
+    fn required_components_spawn_batch() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default, Debug, PartialEq)]
+        struct Y(u32);
+
+        let mut world = World::new();
+        let entities: Vec<_> = world.spawn_batch([X, X, X]).collect();
+        for entity in entities {
+            assert_eq!(Some(&Y(0)), world.get::<Y>(entity));
+        }
+    }
+
+    #[test]
+    fn required_components_sparse_set() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default, Debug, PartialEq)]
+        #[component(storage = "SparseSet")]
+        struct Y(u32);
+
+        let mut world = World::new();
+        let id = world.spawn(X).id();
+        assert_eq!(Some(&Y(0)), world.get::<Y>(id));
+
+        let id = world.spawn_empty().insert(X).id();
+        assert_eq!(Some(&Y(0)), world.get::<Y>(id));
+    }
+
+    #[test]
+    fn required_components_dynamic_insert() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        let mut world = World::new();
+        let x_id = world.init_component::<X>();
+        let id = world.spawn_empty().id();
+        bevy_ptr::OwningPtr::make(X, |ptr| {
+            // SAFETY: `ptr` points to a value of `X`, which `x_id` identifies.
+            unsafe { world.entity_mut(id).insert_by_id(x_id, ptr) };
+        });
+        assert!(world.entity(id).contains::<Y>());
+    }
+
+    #[test]
+    fn required_components_inheritance_depth() {
+        #[derive(Component)]
+        #[require(Y, Z(z_ten))]
+        struct X;
+
+        #[derive(Component, Default)]
+        #[require(Z)]
+        struct Y;
+
+        #[derive(Component, Default, Debug, PartialEq)]
+        struct Z(u32);
+
+        fn z_ten() -> Z {
+            Z(10)
+        }
+
+        let mut world = World::new();
+        let id = world.spawn(X).id();
+        assert_eq!(
+            Some(&Z(10)),
+            world.get::<Z>(id),
+            "the constructor closest to X should be used"
+        );
+
+        let id = world.spawn(Y).id();
+        assert_eq!(Some(&Z(0)), world.get::<Z>(id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cyclic required components are not allowed")]
+    fn required_components_cycle() {
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        #[require(Z)]
+        struct Y;
+
+        #[derive(Component, Default)]
+        #[require(X)]
+        struct Z;
+
+        World::new().spawn(X);
+    }
+
+    #[test]
+    fn required_components_cycle_panic_does_not_poison_world() {
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        #[require(X)]
+        struct Y;
+
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct Z;
+
+        let mut world = World::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.init_component::<X>();
+        }));
+        assert!(result.is_err());
+
+        // `X` and `Y` are no longer being registered, so requiring them is not a cycle.
+        world.init_component::<Z>();
+    }
+
+    #[test]
+    fn runtime_required_components_cycle() {
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        #[require(Z)]
+        struct Y;
+
+        #[derive(Component, Default)]
+        struct Z;
+
+        let mut world = World::new();
+        world.init_component::<X>();
+        assert!(matches!(
+            world.try_register_required_components::<Z, X>(),
+            Err(RequiredComponentsError::CyclicRequirement(_, _))
+        ));
+        assert!(matches!(
+            world.try_register_required_components::<Z, Z>(),
+            Err(RequiredComponentsError::CyclicRequirement(_, _))
+        ));
+    }
+
+    #[test]
+    fn runtime_required_components() {
+        #[derive(Component)]
+        struct X;
+
+        #[derive(Component)]
+        #[require(Z)]
+        struct Y;
+
+        #[derive(Component, Default, Debug, PartialEq)]
+        struct Z(u32);
+
+        #[derive(Component)]
+        #[require(X)]
+        struct W;
+
+        impl Default for X {
+            fn default() -> Self {
+                X
+            }
+        }
+
+        let mut world = World::new();
+        world.init_component::<W>();
+        world.register_required_components_with::<X, Y>(|| Y);
+
+        let id = world.spawn(X).id();
+        assert!(world.entity(id).contains::<Y>());
+        assert_eq!(Some(&Z(0)), world.get::<Z>(id));
+
+        let id = world.spawn(W).id();
+        assert!(
+            world.entity(id).contains::<Y>(),
+            "components requiring X should also require Y"
+        );
+        assert!(world.entity(id).contains::<Z>());
+
+        assert!(matches!(
+            world.try_register_required_components::<X, Z>(),
+            Err(RequiredComponentsError::ArchetypeExists(_))
+        ));
+    }
+
+    #[test]
+    fn runtime_required_components_duplicate() {
+        #[derive(Component)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        let mut world = World::new();
+        assert!(matches!(
+            world.try_register_required_components::<X, Y>(),
+            Err(RequiredComponentsError::DuplicateRegistration(_, _))
+        ));
+    }
+
     // These structs are primarily compilation tests to test the derive macros. Because they are
     // never constructed, we have to manually silence the `dead_code` lint.
     #[allow(dead_code)]
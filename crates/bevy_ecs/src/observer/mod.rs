@@ -12,6 +12,7 @@ use crate::{archetype::ArchetypeFlags, system::IntoObserverSystem, world::*};
 use crate::{component::ComponentId, prelude::*, world::DeferredWorld};
 use bevy_ptr::Ptr;
 use bevy_utils::{EntityHashMap, HashMap};
+use smallvec::SmallVec;
 use std::marker::PhantomData;
 
 /// Type containing triggered [`Event`] information for a given run of an [`Observer`]. This contains the
@@ -95,6 +96,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The order of the observer relative to the other observers of the same event.
+    order: i32,
 }
 
 impl ObserverDescriptor {
@@ -119,11 +123,26 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set the `order` of the observer. When an event is triggered, observers with a lower order run first.
+    /// Observers with the same order run in the order they were registered in.
+    ///
+    /// The default order is `0`.
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns the order of the observer. See [`ObserverDescriptor::with_order`].
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        self.order = descriptor.order;
     }
 }
 
@@ -140,8 +159,11 @@ pub struct ObserverTrigger {
     pub entity: Entity,
 }
 
+/// The key observers are sorted by when they run: their order, then the order they were registered in.
+type ObserverSortKey = (i32, u64);
+
 // Map between an observer entity and its runner
-type ObserverMap = EntityHashMap<Entity, ObserverRunner>;
+type ObserverMap = EntityHashMap<Entity, (ObserverSortKey, ObserverRunner)>;
 
 /// Collection of [`ObserverRunner`] for [`Observer`] registered to a particular trigger targeted at a specific component.
 #[derive(Default, Debug)]
@@ -171,8 +193,11 @@ pub struct Observers {
     on_insert: CachedObservers,
     on_replace: CachedObservers,
     on_remove: CachedObservers,
+    on_despawn: CachedObservers,
     // Map from trigger type to set of observers
     cache: HashMap<ComponentId, CachedObservers>,
+    // Number of observers registered so far, used to run observers with the same order in registration order
+    registered: u64,
 }
 
 impl Observers {
@@ -182,6 +207,7 @@ impl Observers {
             ON_INSERT => &mut self.on_insert,
             ON_REPLACE => &mut self.on_replace,
             ON_REMOVE => &mut self.on_remove,
+            ON_DESPAWN => &mut self.on_despawn,
             _ => self.cache.entry(event_type).or_default(),
         }
     }
@@ -192,18 +218,67 @@ impl Observers {
             ON_INSERT => Some(&self.on_insert),
             ON_REPLACE => Some(&self.on_replace),
             ON_REMOVE => Some(&self.on_remove),
+            ON_DESPAWN => Some(&self.on_despawn),
             _ => self.cache.get(&event_type),
         }
     }
 
+    /// Returns `true` if there are observers of `event_type` targeting the component or resource `component_id`.
+    pub(crate) fn has_component_observers(
+        &self,
+        event_type: ComponentId,
+        component_id: ComponentId,
+    ) -> bool {
+        self.try_get_observers(event_type)
+            .is_some_and(|cache| cache.component_observers.contains_key(&component_id))
+    }
+
+    /// Returns `true` if there are observers of `event_type` that run regardless of the components
+    /// of the target entity: observers not watching any component, or watching specific entities only.
+    pub(crate) fn has_untargeted_observers(&self, event_type: ComponentId) -> bool {
+        self.try_get_observers(event_type)
+            .is_some_and(|cache| !cache.map.is_empty() || !cache.entity_observers.is_empty())
+    }
+
     /// This will run the observers of the given `event_type`, targeting the given `entity` and `components`.
     pub(crate) fn invoke<T>(
+        world: DeferredWorld,
+        event_type: ComponentId,
+        entity: Entity,
+        components: impl Iterator<Item = ComponentId>,
+        data: &mut T,
+        propagate: &mut bool,
+    ) {
+        Self::invoke_inner(world, event_type, entity, components, data, propagate, true);
+    }
+
+    /// This will run the observers of the given lifecycle `event_type` targeting the resource `resource_id`.
+    ///
+    /// Unlike for components, observers that don't target any component are not run.
+    pub(crate) fn invoke_resource(
+        world: DeferredWorld,
+        event_type: ComponentId,
+        resource_id: ComponentId,
+    ) {
+        Self::invoke_inner(
+            world,
+            event_type,
+            Entity::PLACEHOLDER,
+            std::iter::once(resource_id),
+            &mut (),
+            &mut false,
+            false,
+        );
+    }
+
+    fn invoke_inner<T>(
         mut world: DeferredWorld,
         event_type: ComponentId,
         entity: Entity,
         components: impl Iterator<Item = ComponentId>,
         data: &mut T,
         propagate: &mut bool,
+        include_untargeted: bool,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
         let (mut world, observers) = unsafe {
@@ -218,43 +293,54 @@ impl Observers {
             (world.into_deferred(), observers)
         };
 
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
-            (runner)(
-                world.reborrow(),
-                ObserverTrigger {
-                    observer,
-                    event_type,
-                    entity,
-                },
-                data.into(),
-                propagate,
+        let mut triggered: SmallVec<[(ObserverSortKey, Entity, ObserverRunner); 8]> =
+            SmallVec::new();
+        let mut collect = |map: &ObserverMap| {
+            triggered.extend(
+                map.iter()
+                    .map(|(&observer, &(key, runner))| (key, observer, runner)),
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
+        // Collect observers listening for any kind of this trigger
+        if include_untargeted {
+            collect(&observers.map);
+        }
 
-        // Trigger entity observers listening for this kind of trigger
+        // Collect entity observers listening for this kind of trigger
         if entity != Entity::PLACEHOLDER {
             if let Some(map) = observers.entity_observers.get(&entity) {
-                map.iter().for_each(&mut trigger_observer);
+                collect(map);
             }
         }
 
-        // Trigger observers listening to this trigger targeting a specific component
+        // Collect observers listening to this trigger targeting a specific component
         components.for_each(|id| {
             if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
+                collect(&component_observers.map);
 
                 if entity != Entity::PLACEHOLDER {
                     if let Some(map) = component_observers.entity_map.get(&entity) {
-                        map.iter().for_each(&mut trigger_observer);
+                        collect(map);
                     }
                 }
             }
         });
+
+        // An observer watching several components can be collected more than once,
+        // but the runner makes sure it only runs once per trigger.
+        triggered.sort_unstable_by_key(|&(key, ..)| key);
+        for (_, observer, runner) in triggered {
+            (runner)(
+                world.reborrow(),
+                ObserverTrigger {
+                    observer,
+                    event_type,
+                    entity,
+                },
+                data.into(),
+                propagate,
+            );
+        }
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -263,6 +349,7 @@ impl Observers {
             ON_INSERT => Some(ArchetypeFlags::ON_INSERT_OBSERVER),
             ON_REPLACE => Some(ArchetypeFlags::ON_REPLACE_OBSERVER),
             ON_REMOVE => Some(ArchetypeFlags::ON_REMOVE_OBSERVER),
+            ON_DESPAWN => Some(ArchetypeFlags::ON_DESPAWN_OBSERVER),
             _ => None,
         }
     }
@@ -299,6 +386,14 @@ impl Observers {
         {
             flags.insert(ArchetypeFlags::ON_REMOVE_OBSERVER);
         }
+
+        if self
+            .on_despawn
+            .component_observers
+            .contains_key(&component_id)
+        {
+            flags.insert(ArchetypeFlags::ON_DESPAWN_OBSERVER);
+        }
     }
 }
 
@@ -311,6 +406,36 @@ impl World {
         self.spawn(Observer::new(system))
     }
 
+    /// Spawns an [`Observer`] of the [`Resource`] `R`, and returns its [`Entity`].
+    ///
+    /// The observer runs when the lifecycle events [`OnAdd`], [`OnInsert`], [`OnReplace`] and [`OnRemove`]
+    /// are triggered for the resource, at the same points as its [hooks](World::register_resource_hooks).
+    /// Since resources don't belong to an entity, [`Trigger::entity`] returns [`Entity::PLACEHOLDER`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Settings {
+    ///     vsync: bool,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.observe_resource::<Settings, _, _>(|_: Trigger<OnInsert>, settings: Res<Settings>| {
+    ///     println!("Applying settings, vsync: {}", settings.vsync);
+    /// });
+    /// world.flush();
+    ///
+    /// world.insert_resource(Settings { vsync: true });
+    /// world.modify_resource(|mut settings: Mut<Settings>| settings.vsync = false);
+    /// ```
+    pub fn observe_resource<R: Resource, E: Event, M>(
+        &mut self,
+        system: impl IntoObserverSystem<E, (), M>,
+    ) -> EntityWorldMut {
+        let resource_id = self.components.init_resource::<R>();
+        self.spawn(Observer::new(system).with_component(resource_id))
+    }
+
     /// Triggers the given `event`, which will run any observers watching for it.
     pub fn trigger(&mut self, event: impl Event) {
         TriggerEvent { event, targets: () }.trigger(self);
@@ -336,17 +461,22 @@ impl World {
             (&*observer_state, &mut self.archetypes, &mut self.observers)
         };
         let descriptor = &observer_state.descriptor;
+        let entry = (
+            (descriptor.order, observers.registered),
+            observer_state.runner,
+        );
+        observers.registered += 1;
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
-                cache.map.insert(observer_entity, observer_state.runner);
+                cache.map.insert(observer_entity, entry);
             } else if descriptor.components.is_empty() {
                 // Observer is not targeting any components so register it as an entity observer
                 for &watched_entity in &observer_state.descriptor.entities {
                     let map = cache.entity_observers.entry(watched_entity).or_default();
-                    map.insert(observer_entity, observer_state.runner);
+                    map.insert(observer_entity, entry);
                 }
             } else {
                 // Register observer for each watched component
@@ -363,12 +493,12 @@ impl World {
                             });
                     if descriptor.entities.is_empty() {
                         // Register for all triggers targeting the component
-                        observers.map.insert(observer_entity, observer_state.runner);
+                        observers.map.insert(observer_entity, entry);
                     } else {
                         // Register for each watched entity
                         for &watched_entity in &descriptor.entities {
                             let map = observers.entity_map.entry(watched_entity).or_default();
-                            map.insert(observer_entity, observer_state.runner);
+                            map.insert(observer_entity, entry);
                         }
                     }
                 }
@@ -976,4 +1106,81 @@ mod tests {
         world.flush();
         assert_eq!(2, world.resource::<R>().0);
     }
+
+    #[test]
+    fn observer_explicit_order() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        let entity = world.spawn_empty().id();
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(3))
+                .with_order(10),
+        );
+        world.observe(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(1));
+        world.observe(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(2));
+        // Entity observers are ordered together with global observers.
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.assert_order(0))
+                .with_entity(entity)
+                .with_order(-10),
+        );
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_run_if() {
+        #[derive(Resource)]
+        struct Enabled(bool);
+
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world.insert_resource(Enabled(false));
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<R>| res.0 += 1)
+                .run_if(|enabled: Res<Enabled>| enabled.0),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(0, world.resource::<R>().0);
+
+        world.resource_mut::<Enabled>().0 = true;
+        world.trigger(EventA);
+        assert_eq!(1, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn observer_on_despawn() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+
+        world.observe(
+            |trigger: Trigger<OnDespawn, A>, query: Query<(&A, &B)>, mut res: ResMut<R>| {
+                assert!(query.contains(trigger.entity()));
+                res.assert_order(0);
+            },
+        );
+        world.observe(|_: Trigger<OnRemove, A>, mut res: ResMut<R>| res.assert_order(2));
+        let entity = world.spawn((A, B)).id();
+        world.entity_mut(entity).observe(
+            |trigger: Trigger<OnDespawn>, query: Query<&B>, mut res: ResMut<R>| {
+                assert!(query.contains(trigger.entity()));
+                res.assert_order(1);
+            },
+        );
+        world.flush();
+
+        // Only despawning triggers `OnDespawn`.
+        world.entity_mut(entity).remove::<B>().insert(B);
+        world.flush();
+        assert_eq!(0, world.resource::<R>().0);
+
+        world.despawn(entity);
+        assert_eq!(3, world.resource::<R>().0);
+    }
 }
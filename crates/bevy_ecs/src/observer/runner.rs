@@ -1,8 +1,9 @@
 use crate::{
-    component::{ComponentHooks, ComponentId, StorageType},
+    component::{ComponentHooks, ComponentId, Mutable, StorageType},
     observer::{ObserverDescriptor, ObserverTrigger},
     prelude::*,
     query::DebugCheckedUnwrap,
+    schedule::{BoxedCondition, Condition},
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
@@ -56,10 +57,18 @@ impl ObserverState {
         self.descriptor.components.extend(components);
         self
     }
+
+    /// Set the order of the [`Observer`] relative to the other observers of the same [`Event`].
+    /// See [`ObserverDescriptor::with_order`] for more information.
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.descriptor.order = order;
+        self
+    }
 }
 
 impl Component for ObserverState {
     const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+    type Mutability = Mutable;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks.on_add(|mut world, entity, _| {
@@ -256,6 +265,40 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 ///
 /// You can call [`Observer::watch_entity`] more than once, which allows you to watch multiple entities with the same [`Observer`].
 ///
+/// By default, the order in which the observers of an event run is unspecified. Use [`Observer::with_order`] to make an
+/// observer run before or after the others:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// # #[derive(Event)]
+/// # struct Damage(u32);
+/// # #[derive(Resource)]
+/// # struct Paused(bool);
+/// # world.insert_resource(Paused(false));
+/// // Runs first, and can modify the event for the other observers.
+/// world.spawn(
+///     Observer::new(|mut trigger: Trigger<Damage>| trigger.event_mut().0 /= 2).with_order(-10),
+/// );
+/// world.observe(|trigger: Trigger<Damage>| println!("Took {} damage", trigger.event().0));
+/// ```
+///
+/// Like systems in a schedule, observers can be skipped with [run conditions](Condition):
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// # #[derive(Event)]
+/// # struct Damage(u32);
+/// #[derive(Resource)]
+/// struct Invincible(bool);
+///
+/// world.spawn(
+///     Observer::new(|trigger: Trigger<Damage>| println!("Took {} damage", trigger.event().0))
+///         .run_if(|invincible: Res<Invincible>| !invincible.0),
+/// );
+/// ```
+///
 /// When first added, [`Observer`] will also create an [`ObserverState`] component, which registers the observer with the [`World`] and
 /// serves as the "source of truth" of the observer.
 ///
@@ -263,6 +306,7 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 pub struct Observer<T: 'static, B: Bundle> {
     system: BoxedObserverSystem<T, B>,
     descriptor: ObserverDescriptor,
+    conditions: Vec<BoxedCondition>,
 }
 
 impl<E: Event, B: Bundle> Observer<E, B> {
@@ -272,6 +316,7 @@ impl<E: Event, B: Bundle> Observer<E, B> {
         Self {
             system: Box::new(IntoObserverSystem::into_system(system)),
             descriptor: Default::default(),
+            conditions: Vec::new(),
         }
     }
 
@@ -305,10 +350,31 @@ impl<E: Event, B: Bundle> Observer<E, B> {
         self.descriptor.events.push(event);
         self
     }
+
+    /// Set the order of the [`Observer`] relative to the other observers of the same [`Event`].
+    /// Observers with a lower order run first, and observers with the same order run in the order
+    /// they were registered in. The default order is `0`.
+    ///
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.descriptor.order = order;
+        self
+    }
+
+    /// Only run the [`Observer`] if the given [`Condition`] is `true`.
+    ///
+    /// If called multiple times, the observer will only run if all conditions are `true`.
+    /// Like in schedules, every condition is evaluated each time the observer is triggered.
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        self.conditions
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
 }
 
 impl<E: Event, B: Bundle> Component for Observer<E, B> {
     const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+    type Mutability = Mutable;
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks.on_add(|mut world, entity, _| {
             world.commands().add(move |world: &mut World| {
@@ -324,16 +390,18 @@ impl<E: Event, B: Bundle> Component for Observer<E, B> {
                 };
 
                 // Initialize System
-                let system: *mut dyn ObserverSystem<E, B> =
-                    if let Some(mut observe) = world.get_mut::<Self>(entity) {
-                        descriptor.merge(&observe.descriptor);
-                        &mut *observe.system
-                    } else {
-                        return;
-                    };
-                // SAFETY: World reference is exclusive and initialize does not touch system, so references do not alias
+                let observer: *mut Self = if let Some(mut observe) = world.get_mut::<Self>(entity) {
+                    descriptor.merge(&observe.descriptor);
+                    &mut *observe
+                } else {
+                    return;
+                };
+                // SAFETY: World reference is exclusive and initialize does not touch the observer, so references do not alias
                 unsafe {
-                    (*system).initialize(world);
+                    (*observer).system.initialize(world);
+                    for condition in &mut (*observer).conditions {
+                        condition.initialize(world);
+                    }
                 }
 
                 {
@@ -382,6 +450,32 @@ fn observer_system_runner<E: Event, B: Bundle>(
     }
     state.last_trigger_id = last_trigger;
 
+    // SAFETY: Observer was triggered so must have an `Observer` component.
+    let observer = unsafe {
+        observer_cell
+            .get_mut::<Observer<E, B>>()
+            .debug_checked_unwrap()
+            .into_inner()
+    };
+
+    // not short-circuiting is intentional
+    #[allow(clippy::unnecessary_fold)]
+    let should_run = observer
+        .conditions
+        .iter_mut()
+        .map(|condition| {
+            condition.update_archetype_component_access(world);
+            // SAFETY:
+            // - `update_archetype_component_access` was just called
+            // - conditions are read-only systems, and the only other outstanding references
+            //   to world are private components
+            unsafe { condition.run_unsafe((), world) }
+        })
+        .fold(true, |acc, res| acc && res);
+    if !should_run {
+        return;
+    }
+
     let trigger: Trigger<E, B> = Trigger::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut T`
         unsafe { ptr.deref_mut() },
@@ -395,13 +489,7 @@ fn observer_system_runner<E: Event, B: Bundle>(
     // This transmute is obviously not ideal, but it is safe. Ideally we can remove the
     // static constraint from ObserverSystem, but so far we have not found a way.
     let trigger: Trigger<'static, E, B> = unsafe { std::mem::transmute(trigger) };
-    // SAFETY: Observer was triggered so must have an `Observer` component.
-    let system = unsafe {
-        &mut observer_cell
-            .get_mut::<Observer<E, B>>()
-            .debug_checked_unwrap()
-            .system
-    };
+    let system = &mut observer.system;
 
     system.update_archetype_component_access(world);
 
@@ -550,6 +550,20 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.required.is_subset(&other.required) && self.access().is_subset(other.access())
     }
 
+    /// Returns `true` if this access explicitly mentions the element given by `index`,
+    /// either by accessing it (including archetypal access) or by filtering on it.
+    ///
+    /// Unlike [`Access::has_read`], this doesn't consider access to all elements (e.g. `EntityRef`).
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        self.access.reads_and_writes.contains(index)
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
+
     /// Returns the indices of the elements that this access filters for.
     pub fn with_filters(&self) -> impl Iterator<Item = T> + '_ {
         self.filter_sets
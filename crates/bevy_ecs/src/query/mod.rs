@@ -71,13 +71,17 @@ impl<T> DebugCheckedUnwrap for Option<T> {
 mod tests {
     use bevy_ecs_macros::{QueryData, QueryFilter};
 
+    use crate::batching::BatchingStrategy;
+    use crate::entity::{EntityHashSet, UniqueEntitySlice};
     use crate::prelude::{AnyOf, Changed, Entity, Or, QueryState, With, Without};
     use crate::query::{ArchetypeFilter, Has, QueryCombinationIter, ReadOnlyQueryData};
     use crate::schedule::{IntoSystemConfigs, Schedule};
     use crate::system::{IntoSystem, Query, System, SystemState};
     use crate::{self as bevy_ecs, component::Component, world::World};
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
     use std::any::type_name;
     use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Component, Debug, Hash, Eq, PartialEq, Clone, Copy)]
     struct A(usize);
@@ -804,4 +808,74 @@ mod tests {
         let values = world.query::<&B>().iter(&world).collect::<Vec<&B>>();
         assert_eq!(values, vec![&B(2)]);
     }
+
+    #[test]
+    fn iter_many_unique_mut() {
+        let mut world = World::new();
+        let e1 = world.spawn(A(1)).id();
+        let e2 = world.spawn((A(2), Sparse(0))).id();
+        let e3 = world.spawn(B(3)).id();
+        let e4 = world.spawn(A(4)).id();
+
+        let entities = [e4, e3, e2, e1];
+        let entities = UniqueEntitySlice::from_slice(&entities).unwrap();
+        let mut state = SystemState::<Query<&mut A>>::new(&mut world);
+        let mut query = state.get_mut(&mut world);
+
+        // all items are alive at the same time
+        let mut items: Vec<_> = query.iter_many_unique_mut(entities).collect();
+        assert_eq!(items.len(), 3);
+        for a in &mut items {
+            a.0 *= 10;
+        }
+        let values: Vec<usize> = query
+            .iter_many_unique_mut(entities)
+            .rev()
+            .map(|a| a.0)
+            .collect();
+        assert_eq!(values, vec![10, 20, 40]);
+
+        let set = EntityHashSet::from_iter([e1, e3]);
+        let values: Vec<usize> = world
+            .query::<&mut A>()
+            .iter_many_unique_mut(&mut world, &set)
+            .map(|a| a.0)
+            .collect();
+        assert_eq!(values, vec![10]);
+    }
+
+    #[test]
+    fn par_iter_many() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut world = World::new();
+        let entities: Vec<Entity> = (0..200)
+            .map(|i| {
+                if i % 2 == 0 {
+                    world.spawn(A(i)).id()
+                } else {
+                    world.spawn((A(i), Sparse(i))).id()
+                }
+            })
+            .collect();
+        let missing = world.spawn(B(0)).id();
+
+        let mut state = SystemState::<Query<&mut A>>::new(&mut world);
+        let mut query = state.get_mut(&mut world);
+
+        let set: EntityHashSet = entities.iter().copied().chain([missing]).collect();
+        query
+            .par_iter_many_unique_mut(&set)
+            .batching_strategy(BatchingStrategy::fixed(16))
+            .for_each(|mut a| a.0 += 1);
+
+        let sum = AtomicUsize::new(0);
+        query
+            .par_iter_many(entities.iter().chain(&entities).chain([&missing]))
+            .batching_strategy(BatchingStrategy::fixed(16))
+            .for_each(|a| {
+                sum.fetch_add(a.0, Ordering::Relaxed);
+            });
+        assert_eq!(sum.into_inner(), 2 * (1..=200).sum::<usize>());
+    }
 }
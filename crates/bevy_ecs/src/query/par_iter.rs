@@ -1,8 +1,9 @@
 use crate::{
-    batching::BatchingStrategy, component::Tick, world::unsafe_world_cell::UnsafeWorldCell,
+    batching::BatchingStrategy, component::Tick, entity::Entity,
+    world::unsafe_world_cell::UnsafeWorldCell,
 };
 
-use super::{QueryData, QueryFilter, QueryItem, QueryState};
+use super::{QueryData, QueryFilter, QueryItem, QueryManyUniqueIter, QueryState};
 
 /// A parallel iterator over query results of a [`Query`](crate::system::Query).
 ///
@@ -126,7 +127,7 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
     fn get_batch_size(&self, thread_count: usize) -> usize {
         let max_items = || {
             let id_iter = self.state.matched_storage_ids.iter();
-            if D::IS_DENSE && F::IS_DENSE {
+            if self.state.is_dense {
                 // SAFETY: We only access table metadata.
                 let tables = unsafe { &self.world.world_metadata().storages().tables };
                 id_iter
@@ -146,3 +147,119 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
             .calc_batch_size(max_items, thread_count)
     }
 }
+
+/// A parallel iterator over the query items generated from a list of [`Entity`]s.
+///
+/// Entities that don't match the query are skipped.
+///
+/// This struct is created by the [`Query::par_iter_many`](crate::system::Query::par_iter_many) and
+/// [`Query::par_iter_many_unique_mut`](crate::system::Query::par_iter_many_unique_mut) methods.
+pub struct QueryParManyIter<'w, 's, D: QueryData, F: QueryFilter> {
+    pub(crate) world: UnsafeWorldCell<'w>,
+    pub(crate) state: &'s QueryState<D, F>,
+    /// Must not contain duplicates unless `D` is read-only.
+    pub(crate) entity_list: Vec<Entity>,
+    pub(crate) last_run: Tick,
+    pub(crate) this_run: Tick,
+    pub(crate) batching_strategy: BatchingStrategy,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> QueryParManyIter<'w, 's, D, F> {
+    /// Changes the batching strategy used when iterating.
+    ///
+    /// For more information on how this affects the resultant iteration, see
+    /// [`BatchingStrategy`].
+    pub fn batching_strategy(mut self, strategy: BatchingStrategy) -> Self {
+        self.batching_strategy = strategy;
+        self
+    }
+
+    /// Runs `func` on each query result in parallel.
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[inline]
+    pub fn for_each<FN: Fn(QueryItem<'w, D>) + Send + Sync + Clone>(self, func: FN) {
+        self.for_each_init(|| {}, |_, item| func(item));
+    }
+
+    /// Runs `func` on each query result in parallel on a value returned by `init`.
+    ///
+    /// `init` may be called multiple times per thread, and the values returned may be discarded between tasks on any given thread.
+    /// Callers should avoid using this function as if it were a parallel version
+    /// of [`Iterator::fold`].
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[inline]
+    pub fn for_each_init<FN, INIT, T>(self, init: INIT, func: FN)
+    where
+        FN: Fn(&mut T, QueryItem<'w, D>) + Send + Sync + Clone,
+        INIT: Fn() -> T + Sync + Send + Clone,
+    {
+        let func = |mut init, item| {
+            func(&mut init, item);
+            init
+        };
+        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
+        {
+            let init = init();
+            // SAFETY:
+            // This method can only be called once per instance of QueryParManyIter.
+            // Mutable instances of QueryParManyIter can only be created via an exclusive borrow of a
+            // Query from a set of unique entities, so no two items can alias.
+            unsafe {
+                QueryManyUniqueIter::new(
+                    self.world,
+                    self.state,
+                    &self.entity_list,
+                    self.last_run,
+                    self.this_run,
+                )
+                .fold(init, func);
+            }
+        }
+        #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+        {
+            let thread_count = bevy_tasks::ComputeTaskPool::get().thread_num();
+            if thread_count <= 1 {
+                let init = init();
+                // SAFETY: See the safety comment above.
+                unsafe {
+                    QueryManyUniqueIter::new(
+                        self.world,
+                        self.state,
+                        &self.entity_list,
+                        self.last_run,
+                        self.this_run,
+                    )
+                    .fold(init, func);
+                }
+            } else {
+                // Need a batch size of at least 1.
+                let batch_size = self
+                    .batching_strategy
+                    .calc_batch_size(|| self.entity_list.len(), thread_count)
+                    .max(1);
+                // SAFETY: See the safety comment above.
+                unsafe {
+                    self.state.par_many_fold_init_unchecked_manual(
+                        init,
+                        self.world,
+                        &self.entity_list,
+                        batch_size,
+                        func,
+                        self.last_run,
+                        self.this_run,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
     batching::BatchingStrategy,
     component::{ComponentId, Components, Tick},
-    entity::Entity,
+    entity::{Entity, EntitySet},
     prelude::FromWorld,
     query::{
         Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
@@ -18,13 +18,13 @@ use std::{borrow::Borrow, fmt, mem::MaybeUninit, ptr};
 
 use super::{
     NopWorldQuery, QueryBuilder, QueryData, QueryEntityError, QueryFilter, QueryManyIter,
-    QuerySingleError, ROQueryItem,
+    QueryManyUniqueIter, QuerySingleError, ROQueryItem,
 };
 
 /// An ID for either a table or an archetype. Used for Query iteration.
 ///
 /// Query iteration is exclusively dense (over tables) or archetypal (over archetypes) based on whether
-/// both `D::IS_DENSE` and `F::IS_DENSE` are true or not.
+/// the query [is dense](QueryState::is_dense) or not.
 ///
 /// This is a union instead of an enum as the usage is determined at compile time, as all [`StorageId`]s for
 /// a [`QueryState`] will be all [`TableId`]s or all [`ArchetypeId`]s, and not a mixture of both. This
@@ -32,7 +32,7 @@ use super::{
 /// a safety invariant be verified when disambiguating them.
 ///
 /// # Safety
-/// Must be initialized and accessed as a [`TableId`], if both generic parameters to the query are dense.
+/// Must be initialized and accessed as a [`TableId`], if the query is dense.
 /// Must be initialized and accessed as an [`ArchetypeId`] otherwise.
 #[derive(Clone, Copy)]
 pub(super) union StorageId {
@@ -68,6 +68,8 @@ pub struct QueryState<D: QueryData, F: QueryFilter = ()> {
     pub(crate) component_access: FilteredAccess<ComponentId>,
     // NOTE: we maintain both a bitset and a vec because iterating the vec is faster
     pub(super) matched_storage_ids: Vec<StorageId>,
+    /// Whether the query iterates over tables instead of archetypes. See [`QueryState::is_dense`].
+    pub(super) is_dense: bool,
     pub(crate) fetch_state: D::State,
     pub(crate) filter_state: F::State,
     #[cfg(feature = "trace")]
@@ -144,6 +146,38 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     pub fn matched_archetypes(&self) -> impl Iterator<Item = ArchetypeId> + '_ {
         self.matched_archetypes.ones().map(ArchetypeId::new)
     }
+
+    /// Returns `true` if this query iterates over whole tables instead of archetypes.
+    ///
+    /// This is the case if both `D::IS_DENSE` and `F::IS_DENSE` are true and none of the world's
+    /// [`DefaultQueryFilters`](crate::entity_disabling::DefaultQueryFilters) is a sparse set component,
+    /// as entities with and without such a component share the same table.
+    pub fn is_dense(&self) -> bool {
+        self.is_dense
+    }
+}
+
+/// Collects the ids of the matched tables if `is_dense`, or of the matched archetypes otherwise.
+fn matched_storage_ids(
+    is_dense: bool,
+    matched_tables: &FixedBitSet,
+    matched_archetypes: &FixedBitSet,
+) -> Vec<StorageId> {
+    if is_dense {
+        matched_tables
+            .ones()
+            .map(|id| StorageId {
+                table_id: TableId::from_usize(id),
+            })
+            .collect()
+    } else {
+        matched_archetypes
+            .ones()
+            .map(|id| StorageId {
+                archetype_id: ArchetypeId::new(id),
+            })
+            .collect()
+    }
 }
 
 impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
@@ -194,10 +228,17 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
+        // Exclude disabled entities unless the query explicitly mentions the disabling components.
+        world.default_query_filters().apply(&mut component_access);
+        let is_dense = D::IS_DENSE
+            && F::IS_DENSE
+            && world.default_query_filters().is_dense(world.components());
+
         Self {
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
+            is_dense,
             fetch_state,
             filter_state,
             component_access,
@@ -218,13 +259,20 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        let mut component_access = builder.access().clone();
+        let default_filters = builder.world().default_query_filters();
+        default_filters.apply(&mut component_access);
+        let is_dense =
+            D::IS_DENSE && F::IS_DENSE && default_filters.is_dense(builder.world().components());
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
+            is_dense,
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -411,7 +459,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             let archetype_index = archetype.id().index();
             if !self.matched_archetypes.contains(archetype_index) {
                 self.matched_archetypes.grow_and_insert(archetype_index);
-                if !D::IS_DENSE || !F::IS_DENSE {
+                if !self.is_dense {
                     self.matched_storage_ids.push(StorageId {
                         archetype_id: archetype.id(),
                     });
@@ -420,7 +468,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             let table_index = archetype.table_id().as_usize();
             if !self.matched_tables.contains(table_index) {
                 self.matched_tables.grow_and_insert(table_index);
-                if D::IS_DENSE && F::IS_DENSE {
+                if self.is_dense {
                     self.matched_storage_ids.push(StorageId {
                         table_id: archetype.table_id(),
                     });
@@ -505,10 +553,16 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             std::any::type_name::<(NewD, NewF)>(), std::any::type_name::<(D, F)>()
         );
 
+        let is_dense = self.is_dense && NewD::IS_DENSE && NewF::IS_DENSE;
         QueryState {
             world_id: self.world_id,
             archetype_generation: self.archetype_generation,
-            matched_storage_ids: self.matched_storage_ids.clone(),
+            matched_storage_ids: matched_storage_ids(
+                is_dense,
+                &self.matched_tables,
+                &self.matched_archetypes,
+            ),
+            is_dense,
             fetch_state,
             filter_state,
             component_access: self.component_access.clone(),
@@ -602,26 +656,17 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let mut matched_archetypes = self.matched_archetypes.clone();
         matched_tables.intersect_with(&other.matched_tables);
         matched_archetypes.intersect_with(&other.matched_archetypes);
-        let matched_storage_ids = if NewD::IS_DENSE && NewF::IS_DENSE {
-            matched_tables
-                .ones()
-                .map(|id| StorageId {
-                    table_id: TableId::from_usize(id),
-                })
-                .collect()
-        } else {
-            matched_archetypes
-                .ones()
-                .map(|id| StorageId {
-                    archetype_id: ArchetypeId::new(id),
-                })
-                .collect()
-        };
+        let is_dense = self.is_dense && other.is_dense && NewD::IS_DENSE && NewF::IS_DENSE;
 
         QueryState {
             world_id: self.world_id,
             archetype_generation: self.archetype_generation,
-            matched_storage_ids,
+            matched_storage_ids: matched_storage_ids(
+                is_dense,
+                &matched_tables,
+                &matched_archetypes,
+            ),
+            is_dense,
             fetch_state: new_fetch_state,
             filter_state: new_filter_state,
             component_access: joined_component_access,
@@ -1182,6 +1227,33 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         }
     }
 
+    /// Returns an iterator over the query items generated from a set of unique [`Entity`]s.
+    ///
+    /// Items are returned in the order of the set of entities.
+    /// Entities that don't match the query are skipped.
+    ///
+    /// Unlike [`iter_many_mut`](Self::iter_many_mut), the returned iterator implements [`Iterator`],
+    /// as the [`EntitySet`] guarantees that no two items alias.
+    #[inline]
+    pub fn iter_many_unique_mut<'w, 's, EntityList: EntitySet>(
+        &'s mut self,
+        world: &'w mut World,
+        entities: EntityList,
+    ) -> QueryManyUniqueIter<'w, 's, D, F, EntityList::IntoIter> {
+        self.update_archetypes(world);
+        let change_tick = world.change_tick();
+        let last_change_tick = world.last_change_tick();
+        // SAFETY: Query has unique world access.
+        unsafe {
+            self.iter_many_unique_unchecked_manual(
+                entities,
+                world.as_unsafe_world_cell(),
+                last_change_tick,
+                change_tick,
+            )
+        }
+    }
+
     /// Returns an [`Iterator`] over the query results for the given [`World`].
     ///
     /// This iterator is always guaranteed to return results from each matching entity once and only once.
@@ -1273,6 +1345,28 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         QueryManyIter::new(world, self, entities, last_run, this_run)
     }
 
+    /// Returns an [`Iterator`] for the given [`World`] and set of unique [`Entity`]'s, where the last change and
+    /// the current change tick are given.
+    ///
+    /// Items are returned in the order of the set of entities.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    #[inline]
+    pub(crate) unsafe fn iter_many_unique_unchecked_manual<'w, 's, EntityList: EntitySet>(
+        &'s self,
+        entities: EntityList,
+        world: UnsafeWorldCell<'w>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> QueryManyUniqueIter<'w, 's, D, F, EntityList::IntoIter> {
+        QueryManyUniqueIter::new(world, self, entities, last_run, this_run)
+    }
+
     /// Returns an [`Iterator`] over all possible combinations of `K` query results for the
     /// given [`World`] without repetition.
     /// This can only be called for read-only queries.
@@ -1431,7 +1525,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                     let mut iter = self.iter_unchecked_manual(world, last_run, this_run);
                     let mut accum = init_accum();
                     for storage_id in queue {
-                        if D::IS_DENSE && F::IS_DENSE {
+                        if self.is_dense {
                             let id = storage_id.table_id;
                             let table = &world.storages().tables.get(id).debug_checked_unwrap();
                             accum = iter.fold_over_table_range(
@@ -1465,7 +1559,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                         #[cfg(feature = "trace")]
                         let _span = self.par_iter_span.enter();
                         let accum = init_accum();
-                        if D::IS_DENSE && F::IS_DENSE {
+                        if self.is_dense {
                             let id = storage_id.table_id;
                             let table = world.storages().tables.get(id).debug_checked_unwrap();
                             self.iter_unchecked_manual(world, last_run, this_run)
@@ -1481,7 +1575,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             };
 
             let storage_entity_count = |storage_id: StorageId| -> usize {
-                if D::IS_DENSE && F::IS_DENSE {
+                if self.is_dense {
                     tables[storage_id.table_id].entity_count()
                 } else {
                     archetypes[storage_id.archetype_id].len()
@@ -1514,6 +1608,56 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         });
     }
 
+    /// Runs `func` on each query result for the given list of entities in parallel,
+    /// splitting the list into batches of `batch_size` entities.
+    ///
+    /// Entities that don't match the query are skipped.
+    ///
+    /// # Panics
+    /// The [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// # Safety
+    ///
+    /// This does not check for mutable query correctness. To be safe, make sure mutable queries
+    /// have unique access to the components they query.
+    /// `entity_list` must not contain the same [`Entity`] twice, unless `D` is read-only.
+    /// This does not validate that `world.id()` matches `self.world_id`. Calling this on a `world`
+    /// with a mismatched [`WorldId`] is unsound.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) unsafe fn par_many_fold_init_unchecked_manual<'w, T, FN, INIT>(
+        &self,
+        init_accum: INIT,
+        world: UnsafeWorldCell<'w>,
+        entity_list: &[Entity],
+        batch_size: usize,
+        func: FN,
+        last_run: Tick,
+        this_run: Tick,
+    ) where
+        FN: Fn(T, D::Item<'w>) -> T + Send + Sync + Clone,
+        INIT: Fn() -> T + Sync + Send + Clone,
+    {
+        bevy_tasks::ComputeTaskPool::get().scope(|scope| {
+            for batch in entity_list.chunks(batch_size) {
+                let func = func.clone();
+                let init_accum = init_accum.clone();
+                scope.spawn(async move {
+                    #[cfg(feature = "trace")]
+                    let _span = self.par_iter_span.enter();
+                    let accum = init_accum();
+                    // SAFETY: The caller ensures that the entities are unique or that `D` is read-only,
+                    // and batches never overlap.
+                    unsafe { QueryManyUniqueIter::new(world, self, batch, last_run, this_run) }
+                        .fold(accum, func);
+                });
+            }
+        });
+    }
+
     /// Returns a single immutable query result when there is exactly one entity matching
     /// the query.
     ///
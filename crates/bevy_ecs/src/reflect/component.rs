@@ -60,7 +60,7 @@
 use super::from_reflect_with_fallback;
 use crate::{
     change_detection::Mut,
-    component::Component,
+    component::{Component, ComponentMutability},
     entity::Entity,
     world::{
         unsafe_world_cell::UnsafeEntityCell, EntityMut, EntityWorldMut, FilteredEntityMut,
@@ -147,12 +147,16 @@ impl ReflectComponent {
     ///
     /// # Panics
     ///
-    /// Panics if there is no [`Component`] of the given type.
+    /// Panics if there is no [`Component`] of the given type, or if the component is
+    /// [immutable](crate::component::Component#immutable-components): use [`apply_or_insert`](Self::apply_or_insert) instead.
     pub fn apply<'a>(&self, entity: impl Into<EntityMut<'a>>, component: &dyn Reflect) {
         (self.0.apply)(entity.into(), component);
     }
 
     /// Uses reflection to set the value of this [`Component`] type in the entity to the given value or insert a new one if it does not exist.
+    ///
+    /// [Immutable](crate::component::Component#immutable-components) components are replaced by a new value,
+    /// which triggers their hooks and observers.
     pub fn apply_or_insert(
         &self,
         entity: &mut EntityWorldMut,
@@ -178,6 +182,8 @@ impl ReflectComponent {
     }
 
     /// Gets the value of this [`Component`] type from the entity as a mutable reflected reference.
+    ///
+    /// Returns `None` if the component is [immutable](crate::component::Component#immutable-components).
     pub fn reflect_mut<'a>(
         &self,
         entity: impl Into<FilteredEntityMut<'a>>,
@@ -266,18 +272,37 @@ impl<C: Component + Reflect> FromType<C> for ReflectComponent {
                 entity.insert(component);
             },
             apply: |mut entity, reflected_component| {
-                let mut component = entity.get_mut::<C>().unwrap();
+                assert!(
+                    C::Mutability::MUTABLE,
+                    "Cannot apply a reflected value to the immutable component {}",
+                    std::any::type_name::<C>()
+                );
+                // SAFETY: `C` is mutable
+                let mut component = unsafe { entity.get_mut_assume_mutable::<C>() }.unwrap();
                 component.apply(reflected_component);
             },
             apply_or_insert: |entity, reflected_component, registry| {
-                if let Some(mut component) = entity.get_mut::<C>() {
-                    component.apply(reflected_component);
-                } else {
-                    let component = entity.world_scope(|world| {
-                        from_reflect_with_fallback::<C>(reflected_component, world, registry)
-                    });
-                    entity.insert(component);
+                if C::Mutability::MUTABLE {
+                    // SAFETY: `C` is mutable
+                    if let Some(mut component) = unsafe { entity.get_mut_assume_mutable::<C>() } {
+                        component.apply(reflected_component);
+                        return;
+                    }
                 }
+                // Immutable components are replaced with the updated value, so that their hooks run.
+                let updated = entity.get::<C>().map(|component| {
+                    let mut updated = component.clone_value();
+                    updated.apply(reflected_component);
+                    updated
+                });
+                let component = entity.world_scope(|world| {
+                    from_reflect_with_fallback::<C>(
+                        updated.as_deref().unwrap_or(reflected_component),
+                        world,
+                        registry,
+                    )
+                });
+                entity.insert(component);
             },
             remove: |entity| {
                 entity.remove::<C>();
@@ -293,7 +318,11 @@ impl<C: Component + Reflect> FromType<C> for ReflectComponent {
             },
             reflect: |entity| entity.get::<C>().map(|c| c as &dyn Reflect),
             reflect_mut: |entity| {
-                entity.into_mut::<C>().map(|c| Mut {
+                if !C::Mutability::MUTABLE {
+                    return None;
+                }
+                // SAFETY: `C` is mutable
+                unsafe { entity.into_mut_assume_mutable::<C>() }.map(|c| Mut {
                     value: c.value as &mut dyn Reflect,
                     ticks: c.ticks,
                     #[cfg(feature = "track_change_detection")]
@@ -301,10 +330,14 @@ impl<C: Component + Reflect> FromType<C> for ReflectComponent {
                 })
             },
             reflect_unchecked_mut: |entity| {
+                if !C::Mutability::MUTABLE {
+                    return None;
+                }
                 // SAFETY: reflect_unchecked_mut is an unsafe function pointer used by
-                // `reflect_unchecked_mut` which must be called with an UnsafeEntityCell with access to the component `C` on the `entity`
+                // `reflect_unchecked_mut` which must be called with an UnsafeEntityCell with access to the component `C` on the `entity`,
+                // and `C` is mutable
                 unsafe {
-                    entity.get_mut::<C>().map(|c| Mut {
+                    entity.get_mut_assume_mutable::<C>().map(|c| Mut {
                         value: c.value as &mut dyn Reflect,
                         ticks: c.ticks,
                         #[cfg(feature = "track_change_detection")]
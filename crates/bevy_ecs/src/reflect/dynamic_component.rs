@@ -0,0 +1,524 @@
+//! Definitions for runtime-defined [`Component`](crate::component::Component)s.
+//!
+//! Scripting layers and data-driven games often need to create new component types at runtime.
+//! [`World::register_dynamic_component`] registers such a component from a [`DynamicStruct`]
+//! prototype: its values are stored as [`DynamicStruct`]s in the regular ECS storage, so they
+//! can be queried with [`QueryBuilder`](crate::query::QueryBuilder), observed and saved in scenes
+//! like any other component.
+//!
+//! As dynamic components have no Rust type, they cannot be registered in the [`TypeRegistry`](bevy_reflect::TypeRegistry)
+//! with a [`ReflectComponent`](super::ReflectComponent). Instead, the [`DynamicComponents`] resource
+//! stores a [`ReflectDynamicComponent`] for each of them, which provides the same operations.
+
+use std::alloc::Layout;
+use std::sync::Arc;
+
+use crate as bevy_ecs;
+use crate::{
+    change_detection::Mut,
+    component::{ComponentDescriptor, ComponentId, StorageType},
+    entity::Entity,
+    system::Resource,
+    world::{EntityWorldMut, FilteredEntityMut, FilteredEntityRef, World, WorldId},
+};
+use bevy_ptr::OwningPtr;
+use bevy_reflect::{ApplyError, DynamicStruct, Reflect, Struct};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// A [`Resource`] storing the [`ReflectDynamicComponent`] of every component registered with
+/// [`World::register_dynamic_component`].
+#[derive(Resource, Default)]
+pub struct DynamicComponents {
+    by_name: HashMap<Arc<str>, ComponentId>,
+    components: HashMap<ComponentId, ReflectDynamicComponent>,
+}
+
+impl DynamicComponents {
+    /// Returns the [`ReflectDynamicComponent`] of the dynamic component with the given [`ComponentId`].
+    pub fn get(&self, id: ComponentId) -> Option<&ReflectDynamicComponent> {
+        self.components.get(&id)
+    }
+
+    /// Returns the [`ReflectDynamicComponent`] of the dynamic component with the given name.
+    pub fn get_by_name(&self, name: &str) -> Option<&ReflectDynamicComponent> {
+        self.by_name
+            .get(name)
+            .and_then(|id| self.components.get(id))
+    }
+
+    /// Returns an iterator over the registered dynamic components, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &ReflectDynamicComponent> + '_ {
+        self.components.values()
+    }
+
+    /// Returns the number of registered dynamic components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if no dynamic component has been registered.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+/// A struct used to operate on a component registered with [`World::register_dynamic_component`].
+///
+/// This is the equivalent of [`ReflectComponent`](super::ReflectComponent) for dynamic components:
+/// their values are [`DynamicStruct`]s with the fields of the prototype given at registration.
+/// It can be obtained from the [`DynamicComponents`] resource, and is cheap to clone.
+///
+/// # Panics
+///
+/// All methods panic if they are given an entity from another [`World`] than the one the
+/// component was registered in.
+#[derive(Clone)]
+pub struct ReflectDynamicComponent {
+    world_id: WorldId,
+    id: ComponentId,
+    name: Arc<str>,
+    prototype: Arc<DynamicStruct>,
+}
+
+impl ReflectDynamicComponent {
+    /// Returns the [`ComponentId`] of the dynamic component.
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Returns the name the dynamic component was registered with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the prototype the dynamic component was registered with.
+    ///
+    /// It defines the fields of the component and their default values.
+    pub fn prototype(&self) -> &DynamicStruct {
+        &self.prototype
+    }
+
+    /// Creates a new value of the dynamic component, by applying `value` to a copy of the prototype.
+    ///
+    /// Fields of `value` that are not in the prototype are ignored, and fields of the prototype
+    /// that are not in `value` keep their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` is not a struct, or if one of its fields cannot be applied
+    /// to the corresponding field of the prototype.
+    pub fn from_reflect(
+        &self,
+        value: &dyn Reflect,
+    ) -> Result<DynamicStruct, DynamicComponentError> {
+        let mut component = self.prototype.clone_dynamic();
+        component.try_apply(value)?;
+        Ok(component)
+    }
+
+    /// Inserts the dynamic component into the entity, created from `value` with [`from_reflect`](Self::from_reflect).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be applied to the prototype of the component.
+    /// The entity is left unchanged in this case.
+    pub fn insert(
+        &self,
+        entity: &mut EntityWorldMut,
+        value: &dyn Reflect,
+    ) -> Result<(), DynamicComponentError> {
+        self.assert_world(entity.world().id());
+        let component = self.from_reflect(value)?;
+        OwningPtr::make(component, |ptr| {
+            // SAFETY: The component id is from the world of `entity`, and its layout is the one of `DynamicStruct`.
+            unsafe {
+                entity.insert_by_id(self.id, ptr);
+            }
+        });
+        Ok(())
+    }
+
+    /// Applies `value` to the dynamic component of the entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be applied to the component.
+    /// The component is left unchanged in this case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have the component, or if the component cannot be
+    /// mutably accessed through `entity`.
+    pub fn apply<'a>(
+        &self,
+        entity: impl Into<FilteredEntityMut<'a>>,
+        value: &dyn Reflect,
+    ) -> Result<(), DynamicComponentError> {
+        let Some(mut component) = self.reflect_mut(entity) else {
+            panic!(
+                "Cannot apply a reflected value to the missing dynamic component {}",
+                self.name
+            );
+        };
+        let mut updated = component.clone_dynamic();
+        updated.try_apply(value)?;
+        *component = updated;
+        Ok(())
+    }
+
+    /// Applies `value` to the dynamic component of the entity, or inserts it if the entity
+    /// does not have it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be applied to the component.
+    /// The entity is left unchanged in this case.
+    pub fn apply_or_insert(
+        &self,
+        entity: &mut EntityWorldMut,
+        value: &dyn Reflect,
+    ) -> Result<(), DynamicComponentError> {
+        if self.contains(&*entity) {
+            self.apply(entity, value)
+        } else {
+            self.insert(entity, value)
+        }
+    }
+
+    /// Removes the dynamic component from the entity. Does nothing if it doesn't exist.
+    pub fn remove(&self, entity: &mut EntityWorldMut) {
+        self.assert_world(entity.world().id());
+        entity.remove_by_id(self.id);
+    }
+
+    /// Returns whether the entity contains the dynamic component.
+    pub fn contains<'a>(&self, entity: impl Into<FilteredEntityRef<'a>>) -> bool {
+        let entity = entity.into();
+        self.assert_world(entity.world_id());
+        entity.contains_id(self.id)
+    }
+
+    /// Gets the value of the dynamic component from the entity.
+    ///
+    /// Returns `None` if the entity does not have the component, or if `entity` has no read access to it.
+    pub fn reflect<'a>(
+        &self,
+        entity: impl Into<FilteredEntityRef<'a>>,
+    ) -> Option<&'a DynamicStruct> {
+        let entity = entity.into();
+        self.assert_world(entity.world_id());
+        entity.get_by_id(self.id).map(|ptr| {
+            // SAFETY: The component id is from the world of `entity`, and its values are `DynamicStruct`s.
+            unsafe { ptr.deref::<DynamicStruct>() }
+        })
+    }
+
+    /// Gets the value of the dynamic component from the entity as a mutable reference.
+    ///
+    /// Returns `None` if the entity does not have the component, or if `entity` has no write access to it.
+    pub fn reflect_mut<'a>(
+        &self,
+        entity: impl Into<FilteredEntityMut<'a>>,
+    ) -> Option<Mut<'a, DynamicStruct>> {
+        let entity = entity.into();
+        self.assert_world(entity.world_id());
+        entity.into_mut_by_id(self.id).map(|component| {
+            // SAFETY: The component id is from the world of `entity`, and its values are `DynamicStruct`s.
+            unsafe { component.with_type::<DynamicStruct>() }
+        })
+    }
+
+    /// Gets the value of the dynamic component from the entity `source` of `source_world` and
+    /// [applies or inserts](Self::apply_or_insert) it to the entity `destination` of `destination_world`.
+    ///
+    /// The component is looked up by name in the [`DynamicComponents`] of `destination_world`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the component is not registered in `destination_world`, or if its
+    /// value cannot be applied to the prototype registered there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either entity does not exist, or if the source entity does not have the component.
+    pub fn copy(
+        &self,
+        source_world: &World,
+        destination_world: &mut World,
+        source: Entity,
+        destination: Entity,
+    ) -> Result<(), DynamicComponentError> {
+        let value = self
+            .reflect(source_world.entity(source))
+            .expect("the source entity does not have the dynamic component");
+        let destination_component = destination_world
+            .get_resource::<DynamicComponents>()
+            .and_then(|components| components.get_by_name(&self.name))
+            .cloned()
+            .ok_or_else(|| DynamicComponentError::NotRegistered(self.name.to_string()))?;
+        destination_component.apply_or_insert(&mut destination_world.entity_mut(destination), value)
+    }
+
+    #[inline]
+    fn assert_world(&self, world_id: WorldId) {
+        assert_eq!(
+            self.world_id, world_id,
+            "The dynamic component {} was registered in another world",
+            self.name
+        );
+    }
+}
+
+/// An error returned when registering or operating on a dynamic component.
+#[derive(Error, Debug)]
+pub enum DynamicComponentError {
+    /// A dynamic component with the same name is already registered in the [`World`].
+    #[error("The dynamic component {0} is already registered")]
+    AlreadyRegistered(String),
+    /// No dynamic component with the given name is registered in the [`World`].
+    #[error("The dynamic component {0} is not registered")]
+    NotRegistered(String),
+    /// A reflected value could not be applied to a dynamic component.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+impl World {
+    /// Registers a new component type at runtime, whose values have the fields of `prototype`.
+    ///
+    /// The fields of `prototype` are the default values of the component: values inserted with
+    /// [`ReflectDynamicComponent::insert`] are applied on top of them. The component can then
+    /// be accessed by name through the [`DynamicComponents`] resource, and queried by [`ComponentId`]
+    /// with a [`QueryBuilder`](crate::query::QueryBuilder).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::component::StorageType;
+    /// # use bevy_ecs::world::FilteredEntityRef;
+    /// # use bevy_reflect::{DynamicStruct, GetField};
+    /// let mut world = World::new();
+    ///
+    /// let mut health = DynamicStruct::default();
+    /// health.insert("current", 100.0f32);
+    /// health.insert("max", 100.0f32);
+    /// let health = world
+    ///     .register_dynamic_component("Health", health, StorageType::Table)
+    ///     .unwrap();
+    ///
+    /// let mut damaged = DynamicStruct::default();
+    /// damaged.insert("current", 40.0f32);
+    /// let mut entity = world.spawn_empty();
+    /// health.insert(&mut entity, &damaged).unwrap();
+    ///
+    /// let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+    ///     .ref_id(health.id())
+    ///     .build();
+    /// let value = health.reflect(query.single(&world)).unwrap();
+    /// assert_eq!(value.get_field::<f32>("current"), Some(&40.0));
+    /// assert_eq!(value.get_field::<f32>("max"), Some(&100.0));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a dynamic component with the same name is already registered.
+    pub fn register_dynamic_component(
+        &mut self,
+        name: impl Into<String>,
+        prototype: DynamicStruct,
+        storage_type: StorageType,
+    ) -> Result<ReflectDynamicComponent, DynamicComponentError> {
+        unsafe fn drop_dynamic_struct(ptr: OwningPtr<'_>) {
+            // SAFETY: The values of dynamic components are `DynamicStruct`s.
+            unsafe { ptr.drop_as::<DynamicStruct>() }
+        }
+
+        let name: Arc<str> = name.into().into();
+        if self
+            .get_resource::<DynamicComponents>()
+            .is_some_and(|components| components.by_name.contains_key(&name))
+        {
+            return Err(DynamicComponentError::AlreadyRegistered(name.to_string()));
+        }
+
+        // SAFETY: `drop_dynamic_struct` drops values of the given layout, and `DynamicStruct` is `Send + Sync`.
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                name.to_string(),
+                storage_type,
+                Layout::new::<DynamicStruct>(),
+                Some(drop_dynamic_struct),
+                true,
+            )
+        };
+        let component = ReflectDynamicComponent {
+            world_id: self.id(),
+            id: self.init_component_with_descriptor(descriptor),
+            name,
+            prototype: Arc::new(prototype),
+        };
+
+        let mut components = self.get_resource_or_insert_with(DynamicComponents::default);
+        components
+            .by_name
+            .insert(component.name.clone(), component.id);
+        components
+            .components
+            .insert(component.id, component.clone());
+        Ok(component)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicComponentError, DynamicComponents, ReflectDynamicComponent};
+    use crate as bevy_ecs;
+    use crate::{
+        component::StorageType,
+        observer::Trigger,
+        prelude::*,
+        world::{FilteredEntityMut, FilteredEntityRef, OnAdd},
+    };
+    use bevy_reflect::{DynamicStruct, GetField};
+
+    fn health_prototype() -> DynamicStruct {
+        let mut health = DynamicStruct::default();
+        health.insert("current", 100.0f32);
+        health.insert("max", 100.0f32);
+        health
+    }
+
+    fn current(value: f32) -> DynamicStruct {
+        let mut current = DynamicStruct::default();
+        current.insert("current", value);
+        current
+    }
+
+    #[derive(Resource, Default)]
+    struct Added(usize);
+
+    #[test]
+    fn dynamic_component_insert_query() {
+        for storage_type in [StorageType::Table, StorageType::SparseSet] {
+            let mut world = World::new();
+            let health = world
+                .register_dynamic_component("Health", health_prototype(), storage_type)
+                .unwrap();
+            assert!(matches!(
+                world.register_dynamic_component("Health", health_prototype(), storage_type),
+                Err(DynamicComponentError::AlreadyRegistered(_))
+            ));
+            assert_eq!(
+                world
+                    .resource::<DynamicComponents>()
+                    .get_by_name("Health")
+                    .map(ReflectDynamicComponent::id),
+                Some(health.id())
+            );
+
+            world.init_resource::<Added>();
+            world.spawn(
+                Observer::new(|_: Trigger<OnAdd>, mut added: ResMut<Added>| added.0 += 1)
+                    .with_component(health.id()),
+            );
+            world.flush();
+
+            let a = world.spawn_empty().id();
+            health
+                .insert(&mut world.entity_mut(a), &current(40.0))
+                .unwrap();
+            let b = world.spawn_empty().id();
+            health
+                .insert(&mut world.entity_mut(b), &health_prototype())
+                .unwrap();
+            world.spawn_empty();
+            assert_eq!(world.resource::<Added>().0, 2);
+
+            // Values that don't match the prototype are rejected.
+            let mut invalid = DynamicStruct::default();
+            invalid.insert("current", "full");
+            assert!(health.insert(&mut world.entity_mut(b), &invalid).is_err());
+            assert!(health.apply(world.entity_mut(b), &invalid).is_err());
+            assert_eq!(
+                health
+                    .reflect(world.entity(b))
+                    .unwrap()
+                    .get_field::<f32>("current"),
+                Some(&100.0)
+            );
+
+            let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+                .mut_id(health.id())
+                .build();
+            assert_eq!(query.iter(&world).count(), 2);
+            for entity in query.iter_mut(&mut world) {
+                let mut value = health.reflect_mut(entity).unwrap();
+                *value.get_field_mut::<f32>("current").unwrap() -= 10.0;
+            }
+
+            let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+                .ref_id(health.id())
+                .build();
+            let mut values: Vec<f32> = query
+                .iter(&world)
+                .map(|entity| {
+                    let value = health.reflect(entity).unwrap();
+                    assert_eq!(value.get_field::<f32>("max"), Some(&100.0));
+                    *value.get_field::<f32>("current").unwrap()
+                })
+                .collect();
+            values.sort_by(f32::total_cmp);
+            assert_eq!(values, vec![30.0, 90.0]);
+
+            health.remove(&mut world.entity_mut(a));
+            assert!(!health.contains(world.entity(a)));
+            assert!(health.contains(world.entity(b)));
+        }
+    }
+
+    #[test]
+    fn dynamic_component_copy() {
+        let mut source = World::new();
+        let mut destination = World::new();
+        let health = source
+            .register_dynamic_component("Health", health_prototype(), StorageType::Table)
+            .unwrap();
+        let entity = source.spawn_empty().id();
+        health
+            .insert(&mut source.entity_mut(entity), &current(25.0))
+            .unwrap();
+
+        let target = destination.spawn_empty().id();
+        assert!(matches!(
+            health.copy(&source, &mut destination, entity, target),
+            Err(DynamicComponentError::NotRegistered(_))
+        ));
+
+        // The prototype of the destination world provides the defaults of the missing fields.
+        let mut prototype = health_prototype();
+        prototype.insert("regeneration", 1.0f32);
+        let destination_health = destination
+            .register_dynamic_component("Health", prototype, StorageType::SparseSet)
+            .unwrap();
+        health
+            .copy(&source, &mut destination, entity, target)
+            .unwrap();
+        let value = destination_health
+            .reflect(destination.entity(target))
+            .unwrap();
+        assert_eq!(value.get_field::<f32>("current"), Some(&25.0));
+        assert_eq!(value.get_field::<f32>("regeneration"), Some(&1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_component_other_world() {
+        let mut world = World::new();
+        let health = world
+            .register_dynamic_component("Health", health_prototype(), StorageType::Table)
+            .unwrap();
+        let mut other = World::new();
+        let entity = other.spawn_empty().id();
+        health.contains(other.entity(entity));
+    }
+}
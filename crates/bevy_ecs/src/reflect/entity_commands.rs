@@ -1,3 +1,4 @@
+use crate::error::ErrorContext;
 use crate::prelude::Mut;
 use crate::reflect::AppTypeRegistry;
 use crate::system::{EntityCommands, Resource};
@@ -16,7 +17,7 @@ pub trait ReflectCommandExt {
     ///
     /// # Panics
     ///
-    /// - If the entity doesn't exist.
+    /// - If the entity doesn't exist, with the default [`ErrorHandler`](crate::error::ErrorHandler).
     /// - If [`AppTypeRegistry`] does not have the reflection data for the given [`Component`](crate::component::Component).
     /// - If the component data is invalid. See [`Reflect::apply`] for further details.
     /// - If [`AppTypeRegistry`] is not present in the [`World`].
@@ -195,7 +196,14 @@ fn insert_reflect(
         .expect("component should represent a type.");
     let type_path = type_info.type_path();
     let Some(mut entity) = world.get_entity_mut(entity) else {
-        panic!("error[B0003]: Could not insert a reflected component (of type {type_path}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003");
+        let error = format!("error[B0003]: Could not insert a reflected component (of type {type_path}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003");
+        (world.error_handler())(
+            error.into(),
+            ErrorContext::Command {
+                name: "EntityCommands::insert_reflect".into(),
+            },
+        );
+        return;
     };
     let Some(type_registration) = type_registry.get_with_type_path(type_path) else {
         panic!("Could not get type registration (for component type {type_path}) because it doesn't exist in the TypeRegistry.");
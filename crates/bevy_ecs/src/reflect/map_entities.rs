@@ -1,6 +1,6 @@
 use crate::{
     component::Component,
-    entity::{Entity, EntityHashMap, MapEntities, SceneEntityMapper},
+    entity::{map_component_entities, Entity, EntityHashMap, MapEntities, SceneEntityMapper},
     world::World,
 };
 use bevy_reflect::FromType;
@@ -53,8 +53,8 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
         ReflectMapEntities {
             map_entities: |world, entity_mapper, entities| {
                 for &entity in entities {
-                    if let Some(mut component) = world.get_mut::<C>(entity) {
-                        component.map_entities(entity_mapper);
+                    if let Some(mut entity) = world.get_entity_mut(entity) {
+                        map_component_entities::<C>(&mut entity, entity_mapper);
                     }
                 }
             },
@@ -65,8 +65,8 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
                     .copied()
                     .collect::<Vec<Entity>>();
                 for entity in &entities {
-                    if let Some(mut component) = world.get_mut::<C>(*entity) {
-                        component.map_entities(entity_mapper);
+                    if let Some(mut entity) = world.get_entity_mut(*entity) {
+                        map_component_entities::<C>(&mut entity, entity_mapper);
                     }
                 }
             },
@@ -10,6 +10,7 @@ use bevy_reflect::{Reflect, ReflectFromReflect, TypeRegistry, TypeRegistryArc};
 
 mod bundle;
 mod component;
+mod dynamic_component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
@@ -17,6 +18,7 @@ mod resource;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use dynamic_component::{DynamicComponentError, DynamicComponents, ReflectDynamicComponent};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::{ReflectMapEntities, ReflectMapEntitiesResource};
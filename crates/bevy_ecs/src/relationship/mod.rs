@@ -0,0 +1,526 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships".
+//!
+//! A relationship is made of two components:
+//! - The [`Relationship`] component, inserted on the "source" entity, which points to a single "target" entity.
+//! - The [`RelationshipTarget`] component, present on the "target" entity, which contains the collection of
+//!   every entity whose [`Relationship`] points to it.
+//!
+//! The [`RelationshipTarget`] side is managed by the ECS: inserting, replacing or removing a [`Relationship`]
+//! component (including by despawning its entity) updates the [`RelationshipTarget`] of the target entity.
+//! When the [`RelationshipTarget`] is removed or its entity is despawned, the related entities are updated
+//! according to [`RelationshipTarget::DESPAWN_BEHAVIOR`].
+//!
+//! Relationships are declared with the `relationship` and `relationship_target` attributes of
+//! `#[derive(Component)]`:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[relationship(relationship_target = Owns)]
+//! struct OwnedBy(Entity);
+//!
+//! #[derive(Component)]
+//! #[relationship_target(relationship = OwnedBy, despawn = "Cascade")]
+//! struct Owns(Vec<Entity>);
+//!
+//! let mut world = World::new();
+//! let player = world.spawn_empty().id();
+//! let sword = world.spawn(OwnedBy(player)).id();
+//! world.flush();
+//! assert_eq!(world.get::<Owns>(player).unwrap().0, [sword]);
+//!
+//! // Despawning the player also despawns everything it owns.
+//! world.despawn(player);
+//! assert!(world.get_entity(sword).is_none());
+//! ```
+//!
+//! The [`RelationshipTarget`] is updated by commands queued from component hooks, so changes made directly
+//! through the [`World`] are only visible once the world is flushed (see [`World::flush`]).
+//! Changes made through [`Commands`](crate::system::Commands) are visible as soon as the commands are applied.
+//!
+//! Relationships implement [`Traversal`], so they can be used to propagate observer events from sources to targets.
+
+mod related_methods;
+mod relationship_source_collection;
+
+pub use relationship_source_collection::*;
+
+use crate::{
+    component::{Component, ComponentId, Mutable},
+    entity::Entity,
+    traversal::Traversal,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+
+/// A [`Component`] on a "source" entity that points to a "target" entity, which holds the
+/// matching [`RelationshipTarget`] listing all of its sources.
+///
+/// This is usually implemented with `#[derive(Component)]` and the `#[relationship(relationship_target = T)]`
+/// attribute, on a struct with a single [`Entity`] field. See the [module docs](crate::relationship) for more info.
+///
+/// When implementing this manually, [`Relationship::on_insert`] and [`Relationship::on_replace`] must be registered
+/// as the component's `on_insert` and `on_replace` [hooks](crate::component::ComponentHooks).
+pub trait Relationship: Component + Sized {
+    /// The [`Component`] added to the target entities of this relationship, which lists its sources.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] this relationship points to.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that adds the source entity to the [`RelationshipTarget`] of its target.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        world
+            .commands()
+            .add(move |world: &mut World| add_to_relationship_target::<Self>(world, entity));
+    }
+
+    /// The `on_replace` component hook that removes the source entity from the [`RelationshipTarget`] of its
+    /// previous target.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let Some(target) = world.get::<Self>(entity).map(Self::get) else {
+            return;
+        };
+        world.commands().add(move |world: &mut World| {
+            remove_from_relationship_target::<Self>(world, entity, target);
+        });
+    }
+}
+
+/// What happens to the sources of a relationship when the entity holding its [`RelationshipTarget`] is despawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RelationshipDespawnBehavior {
+    /// The sources are despawned as well, recursively.
+    Cascade,
+    /// The [`Relationship`] component is removed from the sources.
+    #[default]
+    Orphan,
+    /// The sources are related to the target of the despawned entity instead, if it has the same [`Relationship`]
+    /// (e.g. children are attached to their grandparent). Otherwise, this behaves like [`Orphan`](Self::Orphan).
+    Detach,
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the
+/// associated `Relationship` type. See the [`Relationship`] documentation for more information.
+///
+/// This is usually implemented with `#[derive(Component)]` and the `#[relationship_target(relationship = R)]`
+/// attribute, on a struct with a single field implementing [`RelationshipSourceCollection`]. The despawn behavior
+/// can be set with `despawn = "Cascade"`, `"Orphan"` (the default) or `"Detach"`.
+///
+/// When implementing this manually, [`RelationshipTarget::on_replace`] must be registered as the component's
+/// `on_replace` [hook](crate::component::ComponentHooks).
+pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// The collection type that stores the "source" entities for this [`RelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// What happens to the sources when the entity holding this component is despawned.
+    const DESPAWN_BEHAVIOR: RelationshipDespawnBehavior = RelationshipDespawnBehavior::Orphan;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    ///
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate
+    /// the relationship.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    ///
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate
+    /// the relationship.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns `true` if this collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+
+    /// The `on_replace` component hook that updates the sources of this relationship when this component
+    /// is removed, or when its entity is despawned, according to [`RelationshipTarget::DESPAWN_BEHAVIOR`].
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let Some(sources) = world
+            .get::<Self>(entity)
+            .map(|target| target.iter().collect::<Vec<_>>())
+        else {
+            return;
+        };
+        if sources.is_empty() {
+            return;
+        }
+        // Needed to detach the sources, as the relationship of the despawned entity is removed with it.
+        let next_target = world
+            .get::<Self::Relationship>(entity)
+            .map(Relationship::get);
+        world.commands().add(move |world: &mut World| {
+            update_relationship_sources::<Self>(world, entity, sources, next_target);
+        });
+    }
+}
+
+/// Relationships can be traversed from their source to their target, e.g. to propagate observer events.
+impl<R: Relationship> Traversal for R {
+    #[inline]
+    fn traverse(&self) -> Option<Entity> {
+        Some(self.get())
+    }
+}
+
+/// Adds `source` to the [`RelationshipTarget`] of the entity its [`Relationship`] currently points to.
+fn add_to_relationship_target<R: Relationship>(world: &mut World, source: Entity) {
+    let Some(target) = world.get::<R>(source).map(R::get) else {
+        return;
+    };
+    if target == source {
+        warn!(
+            "The {} relationship on entity {source:?} points to itself. The relationship has been removed.",
+            std::any::type_name::<R>()
+        );
+        world.entity_mut(source).remove::<R>();
+        return;
+    }
+    let Some(mut target_entity) = world.get_entity_mut(target) else {
+        warn!(
+            "The {} relationship on entity {source:?} points to entity {target:?}, which does not exist. The relationship has been removed.",
+            std::any::type_name::<R>()
+        );
+        world.entity_mut(source).remove::<R>();
+        return;
+    };
+    if let Some(mut relationship_target) = target_entity.get_mut::<R::RelationshipTarget>() {
+        if !relationship_target.collection().contains(source) {
+            relationship_target.collection_mut_risky().add(source);
+        }
+    } else {
+        let mut collection =
+            <R::RelationshipTarget as RelationshipTarget>::Collection::with_capacity(1);
+        collection.add(source);
+        target_entity.insert(R::RelationshipTarget::from_collection_risky(collection));
+    }
+}
+
+/// Removes `source` from the [`RelationshipTarget`] of its previous `target`, unless it still relates to it.
+/// The [`RelationshipTarget`] is removed once it becomes empty.
+fn remove_from_relationship_target<R: Relationship>(
+    world: &mut World,
+    source: Entity,
+    target: Entity,
+) {
+    if world.get::<R>(source).map(R::get) == Some(target) {
+        return;
+    }
+    let Some(mut target_entity) = world.get_entity_mut(target) else {
+        return;
+    };
+    let Some(mut relationship_target) = target_entity.get_mut::<R::RelationshipTarget>() else {
+        return;
+    };
+    if relationship_target.collection().contains(source) {
+        relationship_target.collection_mut_risky().remove(source);
+    }
+    if relationship_target.is_empty() {
+        target_entity.remove::<R::RelationshipTarget>();
+    }
+}
+
+/// Updates the `sources` that related to `target` after its [`RelationshipTarget`] was removed,
+/// or after `target` was despawned.
+fn update_relationship_sources<T: RelationshipTarget>(
+    world: &mut World,
+    target: Entity,
+    sources: Vec<Entity>,
+    next_target: Option<Entity>,
+) {
+    let despawned = world.get_entity(target).is_none();
+    for source in sources {
+        if world.get::<T::Relationship>(source).map(Relationship::get) != Some(target) {
+            continue;
+        }
+        if !despawned {
+            // The relationship target was re-inserted with this source, e.g. when it was replaced.
+            if world.get::<T>(target).is_some_and(|relationship_target| {
+                relationship_target.collection().contains(source)
+            }) {
+                continue;
+            }
+            world.entity_mut(source).remove::<T::Relationship>();
+            continue;
+        }
+        match T::DESPAWN_BEHAVIOR {
+            RelationshipDespawnBehavior::Cascade => {
+                world.despawn(source);
+            }
+            RelationshipDespawnBehavior::Orphan => {
+                world.entity_mut(source).remove::<T::Relationship>();
+            }
+            RelationshipDespawnBehavior::Detach => match next_target {
+                Some(next_target)
+                    if next_target != source && world.get_entity(next_target).is_some() =>
+                {
+                    world
+                        .entity_mut(source)
+                        .insert(<T::Relationship as Relationship>::from(next_target));
+                }
+                _ => {
+                    world.entity_mut(source).remove::<T::Relationship>();
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::Entity,
+        event::Event,
+        observer::Trigger,
+        relationship::RelationshipTarget,
+        system::{Commands, ResMut, Resource},
+        world::{CommandQueue, World},
+    };
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[relationship(relationship_target = Owns)]
+    struct OwnedBy(Entity);
+
+    #[derive(Component, Debug)]
+    #[relationship_target(relationship = OwnedBy)]
+    struct Owns(Vec<Entity>);
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[relationship(relationship_target = Contains)]
+    struct InContainer {
+        container: Entity,
+    }
+
+    #[derive(Component, Debug)]
+    #[relationship_target(relationship = InContainer, despawn = "Cascade")]
+    struct Contains {
+        items: Vec<Entity>,
+    }
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[relationship(relationship_target = Under)]
+    struct Above(Entity);
+
+    #[derive(Component, Debug)]
+    #[relationship_target(relationship = Above, despawn = "Detach")]
+    struct Under(Vec<Entity>);
+
+    fn owned(world: &World, entity: Entity) -> Option<Vec<Entity>> {
+        world.get::<Owns>(entity).map(|owns| owns.iter().collect())
+    }
+
+    #[test]
+    fn insert_relationship_updates_target() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(OwnedBy(a)).id();
+        let c = world.spawn(OwnedBy(a)).id();
+        world.flush();
+
+        assert_eq!(owned(&world, a), Some(vec![b, c]));
+    }
+
+    #[test]
+    fn replace_relationship_moves_source() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(OwnedBy(a)).id();
+        world.flush();
+
+        world.entity_mut(c).insert(OwnedBy(b));
+        world.flush();
+
+        assert_eq!(owned(&world, a), None, "empty targets are removed");
+        assert_eq!(owned(&world, b), Some(vec![c]));
+    }
+
+    #[test]
+    fn remove_or_despawn_source_updates_target() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(OwnedBy(a)).id();
+        let c = world.spawn(OwnedBy(a)).id();
+        world.flush();
+
+        world.entity_mut(b).remove::<OwnedBy>();
+        world.flush();
+        assert_eq!(owned(&world, a), Some(vec![c]));
+
+        world.despawn(c);
+        assert_eq!(owned(&world, a), None);
+    }
+
+    #[test]
+    fn remove_target_orphans_sources() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(OwnedBy(a)).id();
+        world.flush();
+
+        world.entity_mut(a).remove::<Owns>();
+        world.flush();
+
+        assert!(world.get::<OwnedBy>(b).is_none());
+    }
+
+    #[test]
+    fn despawn_behavior_orphan() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(OwnedBy(a)).id();
+        world.flush();
+
+        world.despawn(a);
+
+        assert!(world.get_entity(b).is_some());
+        assert!(world.get::<OwnedBy>(b).is_none());
+    }
+
+    #[test]
+    fn despawn_behavior_cascade() {
+        let mut world = World::new();
+        let chest = world.spawn_empty().id();
+        let bag = world.spawn(InContainer { container: chest }).id();
+        let coin = world.spawn(InContainer { container: bag }).id();
+        let other = world.spawn_empty().id();
+        world.flush();
+        assert_eq!(world.get::<Contains>(chest).unwrap().items, [bag]);
+
+        world.despawn(chest);
+
+        assert!(world.get_entity(bag).is_none());
+        assert!(world.get_entity(coin).is_none());
+        assert!(world.get_entity(other).is_some());
+    }
+
+    #[test]
+    fn despawn_behavior_detach() {
+        let mut world = World::new();
+        let bottom = world.spawn_empty().id();
+        let middle = world.spawn(Above(bottom)).id();
+        let top = world.spawn(Above(middle)).id();
+        world.flush();
+
+        world.despawn(middle);
+
+        assert_eq!(world.get::<Above>(top), Some(&Above(bottom)));
+        assert_eq!(world.get::<Under>(bottom).unwrap().0, [top]);
+
+        world.despawn(bottom);
+        assert!(world.get::<Above>(top).is_none());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(OwnedBy(a));
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let b = world.spawn(OwnedBy(missing)).id();
+        world.flush();
+
+        assert!(world.get::<OwnedBy>(a).is_none());
+        assert!(world.get::<Owns>(a).is_none());
+        assert!(world.get::<OwnedBy>(b).is_none());
+    }
+
+    #[test]
+    fn relationships_with_commands() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let a = commands.spawn_empty().id();
+        let b = commands.spawn(OwnedBy(a)).id();
+        let c = commands.spawn_empty().id();
+        commands.entity(a).add_related::<OwnedBy>(&[c]);
+        queue.apply(&mut world);
+
+        assert_eq!(owned(&world, a), Some(vec![b, c]));
+
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(a).remove_related::<OwnedBy>(&[b]);
+        queue.apply(&mut world);
+
+        assert_eq!(owned(&world, a), Some(vec![c]));
+        assert!(world.get::<OwnedBy>(b).is_none());
+    }
+
+    #[test]
+    fn related_methods() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+
+        world.entity_mut(a).add_related::<OwnedBy>(&[b, c]);
+        assert_eq!(owned(&world, a), Some(vec![b, c]));
+
+        world.entity_mut(a).remove_related::<OwnedBy>(&[b]);
+        assert_eq!(owned(&world, a), Some(vec![c]));
+        assert!(world.get::<OwnedBy>(b).is_none());
+
+        world.entity_mut(a).despawn_related::<Owns>();
+        assert!(world.get_entity(c).is_none());
+        assert_eq!(owned(&world, a), None);
+    }
+
+    #[test]
+    fn relationship_traversal() {
+        #[derive(Component)]
+        struct Ping;
+
+        impl Event for Ping {
+            type Traversal = OwnedBy;
+
+            const AUTO_PROPAGATE: bool = true;
+        }
+
+        #[derive(Resource, Default)]
+        struct Visited(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Visited>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(OwnedBy(a)).id();
+        let c = world.spawn(OwnedBy(b)).id();
+        world.observe(|trigger: Trigger<Ping>, mut visited: ResMut<Visited>| {
+            visited.0.push(trigger.entity());
+        });
+        world.flush();
+
+        world.trigger_targets(Ping, c);
+        world.flush();
+
+        assert_eq!(world.resource::<Visited>().0, [c, b, a]);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    bundle::Bundle,
+    entity::Entity,
+    relationship::{Relationship, RelationshipTarget},
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+
+impl<'w> EntityWorldMut<'w> {
+    /// Spawns an entity with the given `bundle` that relates to this entity through the [`Relationship`] `R`.
+    pub fn with_related<R: Relationship>(&mut self, bundle: impl Bundle) -> &mut Self {
+        let target = self.id();
+        self.world_scope(|world| {
+            world.spawn((bundle, R::from(target)));
+            world.flush();
+        });
+        self
+    }
+
+    /// Relates the given entities to this entity through the [`Relationship`] `R`.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let target = self.id();
+        self.world_scope(|world| {
+            for &source in related {
+                world.entity_mut(source).insert(R::from(target));
+            }
+            world.flush();
+        });
+        self
+    }
+
+    /// Removes the [`Relationship`] `R` from the given entities, if they relate to this entity.
+    pub fn remove_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let target = self.id();
+        self.world_scope(|world| {
+            for &source in related {
+                remove_relationship_to::<R>(world, source, target);
+            }
+            world.flush();
+        });
+        self
+    }
+
+    /// Despawns every entity that relates to this entity through the [`RelationshipTarget`] `T`.
+    pub fn despawn_related<T: RelationshipTarget>(&mut self) -> &mut Self {
+        let Some(sources) = self
+            .get::<T>()
+            .map(|target| target.iter().collect::<Vec<_>>())
+        else {
+            return self;
+        };
+        self.world_scope(|world| {
+            for source in sources {
+                // Sources may already have been despawned by a cascading relationship.
+                if let Some(source) = world.get_entity_mut(source) {
+                    source.despawn();
+                }
+            }
+        });
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Spawns an entity with the given `bundle` that relates to this entity through the [`Relationship`] `R`.
+    pub fn with_related<R: Relationship>(&mut self, bundle: impl Bundle) -> &mut Self {
+        let target = self.id();
+        self.commands().spawn((bundle, R::from(target)));
+        self
+    }
+
+    /// Relates the given entities to this entity through the [`Relationship`] `R`.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related = related.to_vec();
+        self.add(move |mut entity: EntityWorldMut| {
+            entity.add_related::<R>(&related);
+        })
+    }
+
+    /// Removes the [`Relationship`] `R` from the given entities, if they relate to this entity.
+    pub fn remove_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related = related.to_vec();
+        self.add(move |mut entity: EntityWorldMut| {
+            entity.remove_related::<R>(&related);
+        })
+    }
+
+    /// Despawns every entity that relates to this entity through the [`RelationshipTarget`] `T`.
+    pub fn despawn_related<T: RelationshipTarget>(&mut self) -> &mut Self {
+        self.add(move |mut entity: EntityWorldMut| {
+            entity.despawn_related::<T>();
+        })
+    }
+}
+
+fn remove_relationship_to<R: Relationship>(world: &mut World, source: Entity, target: Entity) {
+    if let Some(mut source) = world.get_entity_mut(source) {
+        if source.get::<R>().map(R::get) == Some(target) {
+            source.remove::<R>();
+        }
+    }
+}
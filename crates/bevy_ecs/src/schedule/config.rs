@@ -1,13 +1,17 @@
 use bevy_utils::all_tuples;
 
 use crate::{
+    error::Result,
     schedule::{
         condition::{BoxedCondition, Condition},
         graph_utils::{Ambiguity, Dependency, DependencyKind, GraphInfo},
         set::{InternedSystemSet, IntoSystemSet, SystemSet},
         Chain,
     },
-    system::{BoxedSystem, IntoSystem, System},
+    system::{
+        never_returns, BoxedSystem, FallibleSystem, IntoSystem, IsDiverging, IsFallible,
+        IsInfallible, Never, System,
+    },
 };
 
 fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
@@ -33,7 +37,7 @@ fn ambiguous_with(graph_info: &mut GraphInfo, set: InternedSystemSet) {
     }
 }
 
-impl<Marker, F> IntoSystemConfigs<Marker> for F
+impl<Marker, F> IntoSystemConfigs<(IsInfallible, Marker)> for F
 where
     F: IntoSystem<(), (), Marker>,
 {
@@ -42,6 +46,24 @@ where
     }
 }
 
+impl<Marker, F> IntoSystemConfigs<(IsFallible, Marker)> for F
+where
+    F: IntoSystem<(), Result, Marker>,
+{
+    fn into_configs(self) -> SystemConfigs {
+        SystemConfigs::new_system(Box::new(FallibleSystem::new(IntoSystem::into_system(self))))
+    }
+}
+
+impl<Marker, F> IntoSystemConfigs<(IsDiverging, Marker)> for F
+where
+    F: IntoSystem<(), Never, Marker>,
+{
+    fn into_configs(self) -> SystemConfigs {
+        SystemConfigs::new_system(Box::new(self.map(never_returns)))
+    }
+}
+
 impl IntoSystemConfigs<()> for BoxedSystem<(), ()> {
     fn into_configs(self) -> SystemConfigs {
         SystemConfigs::new_system(self)
@@ -0,0 +1,547 @@
+use std::{
+    any::Any,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use bevy_tasks::{ComputeTaskPool, Scope, TaskPool};
+use bevy_utils::syncunsafecell::SyncUnsafeCell;
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    component::ComponentId,
+    query::Access,
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, MainThreadExecutor, SystemExecutor,
+        SystemSchedule,
+    },
+    system::BoxedSystem,
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+
+use super::{__rust_begin_short_backtrace, multi_threaded::evaluate_and_fold_conditions};
+
+/// Borrowed data used by the [`JobGraphExecutor`].
+struct Environment<'env, 'sys> {
+    executor: &'env JobGraphExecutor,
+    systems: &'sys [SyncUnsafeCell<BoxedSystem>],
+    system_conditions: &'sys [SyncUnsafeCell<Vec<BoxedCondition>>],
+    set_conditions: &'sys [SyncUnsafeCell<Vec<BoxedCondition>>],
+    sets_with_conditions_of_systems: &'sys [FixedBitSet],
+    world_cell: UnsafeWorldCell<'env>,
+}
+
+impl<'env, 'sys> Environment<'env, 'sys> {
+    fn new(
+        executor: &'env JobGraphExecutor,
+        schedule: &'sys mut SystemSchedule,
+        world: &'env mut World,
+    ) -> Self {
+        Environment {
+            executor,
+            systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
+            system_conditions: SyncUnsafeCell::from_mut(schedule.system_conditions.as_mut_slice())
+                .as_slice_of_cells(),
+            set_conditions: SyncUnsafeCell::from_mut(schedule.set_conditions.as_mut_slice())
+                .as_slice_of_cells(),
+            sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
+            world_cell: world.as_unsafe_world_cell(),
+        }
+    }
+}
+
+/// A system of the schedule, as a node of the compiled job graph.
+struct Job {
+    /// Indices of the jobs that can only start once this job has completed.
+    dependents: Vec<usize>,
+    /// The number of jobs that have to complete before this job can start.
+    num_dependencies: usize,
+    /// Is `true` if the system does not access `!Send` data.
+    is_send: bool,
+    /// Is `true` if the system is exclusive.
+    is_exclusive: bool,
+}
+
+/// References to data required by the jobs.
+/// This is copied to each job so that it can start its dependents when it completes.
+#[derive(Copy, Clone)]
+struct Context<'scope, 'env, 'sys> {
+    environment: &'env Environment<'env, 'sys>,
+    scope: &'scope Scope<'scope, 'env, ()>,
+}
+
+/// Runs the schedule using a thread pool, by compiling it into a static graph of jobs.
+///
+/// When the schedule is initialized, each system becomes a job that waits on a fixed number of
+/// other jobs. On top of the ordering of the schedule, a job waits on every job before it
+/// (in the order of the [`SingleThreadedExecutor`](super::SingleThreadedExecutor)) whose system
+/// or run conditions have conflicting access, is exclusive, or is also `!Send`.
+/// While running, each job decrements the atomic counters of its dependents when it completes,
+/// and spawns those that are left with no dependencies: there is no central loop checking which
+/// systems are ready, which makes this executor faster than the [`MultiThreadedExecutor`](super::MultiThreadedExecutor)
+/// for schedules with many small systems.
+///
+/// Since conflicts are found from the [`ComponentId`] access of the systems instead of the
+/// archetypes they currently match, some systems that the [`MultiThreadedExecutor`](super::MultiThreadedExecutor)
+/// runs in parallel will run one after the other.
+pub struct JobGraphExecutor {
+    /// The compiled job graph, indexed like the systems of the schedule.
+    jobs: Vec<Job>,
+    /// Jobs that don't have any dependencies.
+    starting_jobs: Vec<usize>,
+    /// The number of dependencies each job has that have not completed.
+    num_dependencies_remaining: Vec<AtomicUsize>,
+    /// Results of the conditions of each system set, once they have been evaluated.
+    set_results: Vec<Mutex<Option<bool>>>,
+    /// Systems that have run but have not had their buffers applied.
+    unapplied_systems: Vec<AtomicBool>,
+    /// Systems skipped by stepping.
+    skipped_systems: FixedBitSet,
+    /// Setting when true applies deferred system buffers after all systems have run
+    apply_final_deferred: bool,
+    /// When set, tells the executor that a thread has panicked.
+    panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl Default for JobGraphExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemExecutor for JobGraphExecutor {
+    fn kind(&self) -> ExecutorKind {
+        ExecutorKind::JobGraph
+    }
+
+    fn init(&mut self, schedule: &SystemSchedule) {
+        let sys_count = schedule.system_ids.len();
+        let set_count = schedule.set_ids.len();
+
+        // The access of a job covers the run conditions it may evaluate alongside its system.
+        let accesses: Vec<Access<ComponentId>> = (0..sys_count)
+            .map(|index| {
+                let mut access = schedule.systems[index].component_access().clone();
+                for condition in &schedule.system_conditions[index] {
+                    access.extend(condition.component_access());
+                }
+                for set_idx in schedule.sets_with_conditions_of_systems[index].ones() {
+                    for condition in &schedule.set_conditions[set_idx] {
+                        access.extend(condition.component_access());
+                    }
+                }
+                access
+            })
+            .collect();
+
+        let conflicts = |a: usize, b: usize| {
+            let (system_a, system_b) = (&schedule.systems[a], &schedule.systems[b]);
+            system_a.is_exclusive()
+                || system_b.is_exclusive()
+                || (!system_a.is_send() && !system_b.is_send())
+                || !accesses[a].is_compatible(&accesses[b])
+        };
+
+        let mut dependencies = vec![Vec::new(); sys_count];
+        for (index, dependents) in schedule.system_dependents.iter().enumerate() {
+            for &dependent in dependents {
+                debug_assert!(index < dependent);
+                dependencies[dependent].push(index);
+            }
+        }
+
+        self.jobs = (0..sys_count)
+            .map(|index| Job {
+                dependents: schedule.system_dependents[index].clone(),
+                num_dependencies: schedule.system_dependencies[index],
+                is_send: schedule.systems[index].is_send(),
+                is_exclusive: schedule.systems[index].is_exclusive(),
+            })
+            .collect();
+
+        // Systems are sorted in topological order, so visiting them in order means that the
+        // jobs that complete before the earlier ones are already known.
+        let mut predecessors: Vec<FixedBitSet> = Vec::with_capacity(sys_count);
+        for (index, dependencies) in dependencies.iter().enumerate() {
+            let mut before = FixedBitSet::with_capacity(sys_count);
+            for &dependency in dependencies {
+                before.union_with(&predecessors[dependency]);
+                before.insert(dependency);
+            }
+            for (earlier, predecessors) in predecessors.iter().enumerate() {
+                if before.contains(earlier) || !conflicts(earlier, index) {
+                    continue;
+                }
+                self.jobs[earlier].dependents.push(index);
+                self.jobs[index].num_dependencies += 1;
+                before.union_with(predecessors);
+                before.insert(earlier);
+            }
+            predecessors.push(before);
+        }
+
+        self.starting_jobs = (0..sys_count)
+            .filter(|&index| self.jobs[index].num_dependencies == 0)
+            .collect();
+        self.num_dependencies_remaining = (0..sys_count).map(|_| AtomicUsize::new(0)).collect();
+        self.set_results = (0..set_count).map(|_| Mutex::new(None)).collect();
+        self.unapplied_systems = (0..sys_count).map(|_| AtomicBool::new(false)).collect();
+        self.skipped_systems = FixedBitSet::with_capacity(sys_count);
+    }
+
+    fn run(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &mut World,
+        _skip_systems: Option<&FixedBitSet>,
+    ) {
+        if schedule.systems.is_empty() {
+            return;
+        }
+
+        // reset counts
+        for (remaining, job) in self.num_dependencies_remaining.iter_mut().zip(&self.jobs) {
+            *remaining.get_mut() = job.num_dependencies;
+        }
+        for result in &mut self.set_results {
+            *result.get_mut().unwrap_or_else(PoisonError::into_inner) = None;
+        }
+
+        // If stepping is enabled, make sure we skip those systems that should
+        // not be run.
+        #[cfg(feature = "bevy_debug_stepping")]
+        if let Some(skipped_systems) = _skip_systems {
+            debug_assert_eq!(skipped_systems.len(), self.skipped_systems.len());
+            self.skipped_systems.clone_from(skipped_systems);
+        }
+
+        let thread_executor = world
+            .get_resource::<MainThreadExecutor>()
+            .map(|e| e.0.clone());
+        let thread_executor = thread_executor.as_deref();
+
+        let environment = &Environment::new(self, schedule, world);
+
+        ComputeTaskPool::get_or_init(TaskPool::default).scope_with_executor(
+            false,
+            thread_executor,
+            |scope| {
+                let context = Context { environment, scope };
+                for &index in &environment.executor.starting_jobs {
+                    context.spawn_job(index);
+                }
+            },
+        );
+
+        // End the borrows of self and world in environment by copying out the reference to systems.
+        let systems = environment.systems;
+
+        if self.apply_final_deferred {
+            // Do one final apply buffers after all systems have completed
+            if let Err(payload) = self.apply_deferred(systems, world) {
+                *self.panic_payload.get_mut().unwrap() = Some(payload);
+            }
+        }
+
+        self.skipped_systems.clear();
+
+        // check to see if there was a panic
+        if let Some(payload) = self.panic_payload.get_mut().unwrap().take() {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    fn set_apply_final_deferred(&mut self, value: bool) {
+        self.apply_final_deferred = value;
+    }
+}
+
+impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
+    fn spawn_job(&self, index: usize) {
+        // Move the full context object into the new future.
+        let context = *self;
+        let task = async move { context.run_job(index) };
+
+        let job = &self.environment.executor.jobs[index];
+        if job.is_exclusive {
+            self.scope.spawn_on_scope(task);
+        } else if job.is_send {
+            self.scope.spawn(task);
+        } else {
+            self.scope.spawn_on_external(task);
+        }
+    }
+
+    fn run_job(&self, index: usize) {
+        let executor = self.environment.executor;
+
+        if !executor.skipped_systems.contains(index) {
+            // SAFETY: every job whose access conflicts with this one either completed before
+            // this job was spawned, or waits on it.
+            if let Err(payload) = unsafe { self.run_system(index) } {
+                *executor.panic_payload.lock().unwrap() = Some(payload);
+            }
+        }
+
+        for &dependent in &executor.jobs[index].dependents {
+            if executor.num_dependencies_remaining[dependent].fetch_sub(1, Ordering::AcqRel) == 1 {
+                self.spawn_job(dependent);
+            }
+        }
+    }
+
+    /// Evaluates the conditions of the system and runs it if they are met.
+    ///
+    /// # Safety
+    /// - No job whose access conflicts with the job at `index` can be running.
+    /// - If the system is exclusive, no other job can be running.
+    unsafe fn run_system(&self, index: usize) -> Result<(), Box<dyn Any + Send>> {
+        let environment = self.environment;
+        let executor = environment.executor;
+        let world = environment.world_cell;
+
+        let mut should_run = true;
+        for set_idx in environment.sets_with_conditions_of_systems[index].ones() {
+            let mut result = executor.set_results[set_idx]
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            should_run &= *result.get_or_insert_with(|| {
+                // SAFETY: The conditions of a set are only borrowed while holding its lock.
+                let conditions = unsafe { &mut *environment.set_conditions[set_idx].get() };
+                for condition in conditions.iter_mut() {
+                    condition.update_archetype_component_access(world);
+                }
+                // SAFETY:
+                // - The access of the job includes the conditions of its sets.
+                // - `update_archetype_component_access` has been called for each run condition.
+                unsafe { evaluate_and_fold_conditions(conditions, world) }
+            });
+        }
+
+        // SAFETY: The conditions of a system are only borrowed by its job.
+        let conditions = unsafe { &mut *environment.system_conditions[index].get() };
+        for condition in conditions.iter_mut() {
+            condition.update_archetype_component_access(world);
+        }
+        // SAFETY:
+        // - The access of the job includes the conditions of its system.
+        // - `update_archetype_component_access` has been called for each run condition.
+        should_run &= unsafe { evaluate_and_fold_conditions(conditions, world) };
+
+        if !should_run {
+            return Ok(());
+        }
+
+        // SAFETY: The system is only borrowed by its job, and by `apply_deferred` jobs
+        // which can't run at the same time.
+        let system = unsafe { &mut *environment.systems[index].get() };
+
+        if executor.jobs[index].is_exclusive {
+            // SAFETY: The caller ensures that no other job is running.
+            let world = unsafe { world.world_mut() };
+            if is_apply_deferred(system) {
+                return executor.apply_deferred(environment.systems, world);
+            }
+            let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                __rust_begin_short_backtrace::run(&mut **system, world);
+            }));
+            if let Err(payload) = res {
+                eprintln!("Encountered a panic in system `{}`!", &*system.name());
+                return Err(payload);
+            }
+            return Ok(());
+        }
+
+        let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            system.update_archetype_component_access(world);
+            // SAFETY:
+            // - The caller ensures that no job with conflicting access is running.
+            // - `update_archetype_component_access` has been called.
+            unsafe { __rust_begin_short_backtrace::run_unsafe(&mut **system, world) };
+        }));
+        if let Err(payload) = res {
+            eprintln!("Encountered a panic in system `{}`!", &*system.name());
+            return Err(payload);
+        }
+        executor.unapplied_systems[index].store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl JobGraphExecutor {
+    /// Creates a new job graph executor for use with a [`Schedule`].
+    ///
+    /// [`Schedule`]: crate::schedule::Schedule
+    pub fn new() -> Self {
+        Self {
+            jobs: Vec::new(),
+            starting_jobs: Vec::new(),
+            num_dependencies_remaining: Vec::new(),
+            set_results: Vec::new(),
+            unapplied_systems: Vec::new(),
+            skipped_systems: FixedBitSet::new(),
+            apply_final_deferred: true,
+            panic_payload: Mutex::new(None),
+        }
+    }
+
+    /// Applies the buffers of every system that has run since the last time they were applied.
+    ///
+    /// Must not be called while jobs other than the calling `apply_deferred` job are running.
+    fn apply_deferred(
+        &self,
+        systems: &[SyncUnsafeCell<BoxedSystem>],
+        world: &mut World,
+    ) -> Result<(), Box<dyn Any + Send>> {
+        for (index, unapplied) in self.unapplied_systems.iter().enumerate() {
+            if !unapplied.swap(false, Ordering::Relaxed) {
+                continue;
+            }
+            // SAFETY: none of these systems are running, no other references exist
+            let system = unsafe { &mut *systems[index].get() };
+            let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                system.apply_deferred(world);
+            }));
+            if let Err(payload) = res {
+                eprintln!(
+                    "Encountered a panic when applying buffers for system `{}`!",
+                    &*system.name()
+                );
+                return Err(payload);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{
+        self as bevy_ecs,
+        prelude::{Component, Resource},
+        schedule::{ExecutorKind, IntoSystemConfigs, IntoSystemSetConfigs, Schedule, SystemSet},
+        system::{Commands, NonSendMut, Query, ResMut},
+        world::World,
+    };
+
+    #[derive(Resource)]
+    struct R;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[derive(Component)]
+    struct A(usize);
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Set;
+
+    fn job_graph_schedule() -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::JobGraph);
+        schedule
+    }
+
+    #[test]
+    fn skipped_systems_notify_dependents() {
+        let mut world = World::new();
+        let mut schedule = job_graph_schedule();
+        schedule.add_systems(
+            (
+                (|| {}).run_if(|| false),
+                // This system depends on a system that is always skipped.
+                |mut commands: Commands| {
+                    commands.insert_resource(R);
+                },
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+        assert!(world.get_resource::<R>().is_some());
+    }
+
+    #[test]
+    fn conflicting_systems_run_in_order() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.spawn(A(0));
+
+        fn increment(mut counter: ResMut<Counter>, mut query: Query<&mut A>) {
+            counter.0 += 1;
+            for mut a in &mut query {
+                // Written by the previous system in the schedule.
+                assert_eq!(a.0 + 1, counter.0);
+                a.0 = counter.0;
+            }
+        }
+
+        let mut schedule = job_graph_schedule();
+        for _ in 0..20 {
+            schedule.add_systems(increment);
+        }
+        schedule.add_systems(|| {}).add_systems(|_: Query<&A>| {});
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 20);
+    }
+
+    #[test]
+    fn non_send_systems() {
+        let mut world = World::new();
+        world.insert_non_send_resource(Counter(0));
+
+        let mut schedule = job_graph_schedule();
+        for _ in 0..10 {
+            schedule.add_systems(|mut counter: NonSendMut<Counter>| counter.0 += 1);
+        }
+        schedule.run(&mut world);
+        assert_eq!(world.non_send_resource::<Counter>().0, 10);
+    }
+
+    #[test]
+    fn set_conditions_are_evaluated_once() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let condition_evaluations = evaluations.clone();
+        let mut schedule = job_graph_schedule();
+        schedule.configure_sets(Set.run_if(move || {
+            condition_evaluations.fetch_add(1, Ordering::Relaxed);
+            true
+        }));
+        for _ in 0..5 {
+            schedule.add_systems((|| {}).in_set(Set));
+        }
+        schedule.add_systems(
+            (|mut counter: ResMut<Counter>| counter.0 += 1)
+                .in_set(Set)
+                .run_if(|| false),
+        );
+
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(evaluations.load(Ordering::Relaxed), 2);
+        assert_eq!(world.resource::<Counter>().0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_are_propagated() {
+        let mut world = World::new();
+        fn panicking() {
+            panic!("system panicked");
+        }
+
+        let mut schedule = job_graph_schedule();
+        schedule.add_systems((|| {}, panicking, || {}));
+        schedule.run(&mut world);
+    }
+}
@@ -1,7 +1,9 @@
+mod job_graph;
 mod multi_threaded;
 mod simple;
 mod single_threaded;
 
+pub use self::job_graph::JobGraphExecutor;
 pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
@@ -46,6 +48,14 @@ pub enum ExecutorKind {
     /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
     #[cfg_attr(all(not(target_arch = "wasm32"), feature = "multi_threaded"), default)]
     MultiThreaded,
+    /// Runs the schedule using a thread pool, like [`MultiThreaded`](ExecutorKind::MultiThreaded),
+    /// but compiles it into a static graph of jobs with atomic dependency counters instead of
+    /// checking which systems can run from a central loop.
+    ///
+    /// This has less overhead for schedules with many small systems. Systems with conflicting
+    /// component access that are not ordered run in the same order as with
+    /// [`SingleThreaded`](ExecutorKind::SingleThreaded), even when they match disjoint archetypes.
+    JobGraph,
 }
 
 /// Holds systems and conditions of a [`Schedule`](super::Schedule) sorted in topological order
@@ -719,7 +719,7 @@ fn apply_deferred(
 ///   required by `conditions`.
 /// - `update_archetype_component_access` must have been called
 ///   with `world` for each condition in `conditions`.
-unsafe fn evaluate_and_fold_conditions(
+pub(super) unsafe fn evaluate_and_fold_conditions(
     conditions: &mut [BoxedCondition],
     world: UnsafeWorldCell,
 ) -> bool {
@@ -0,0 +1,280 @@
+use std::fmt::Write;
+
+use crate::schedule::NodeId;
+
+/// The built system graph of a [`Schedule`](super::Schedule), as returned by
+/// [`Schedule::export_graph`](super::Schedule::export_graph).
+///
+/// It can be rendered with [`to_dot`](Self::to_dot) for [Graphviz](https://graphviz.org/),
+/// or with [`to_json`](Self::to_json) for other tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleGraphExport {
+    /// The label of the schedule.
+    pub label: String,
+    /// The systems of the schedule, in the order the single-threaded executor runs them.
+    pub systems: Vec<SystemExport>,
+    /// The named and anonymous system sets of the schedule.
+    ///
+    /// The sets implicitly created for each system type are not included: edges to them
+    /// are redirected to the corresponding systems instead.
+    pub sets: Vec<SystemSetExport>,
+    /// Edges from a system set to each system or system set it directly contains.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// Edges from a system or system set to each system or system set that has to run after it.
+    ///
+    /// Edges between systems come from the flattened graph used by the executor, so they include
+    /// the sync points added by [`ScheduleBuildSettings::auto_insert_apply_deferred`](super::ScheduleBuildSettings::auto_insert_apply_deferred)
+    /// and omit the edges that are implied by others.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// Pairs of systems with conflicting data access and no ordering between them.
+    pub ambiguities: Vec<AmbiguityExport>,
+}
+
+/// A system in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemExport {
+    /// The id of the system in the schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+    /// Whether the system is exclusive.
+    pub is_exclusive: bool,
+    /// Whether the system is an [`apply_deferred`](super::apply_deferred) sync point
+    /// that was automatically inserted when building the schedule.
+    pub is_auto_sync: bool,
+}
+
+/// A system set in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemSetExport {
+    /// The id of the system set in the schedule.
+    pub id: NodeId,
+    /// The name of the system set.
+    ///
+    /// Anonymous sets are named after their members.
+    pub name: String,
+    /// The names of the run conditions of the system set.
+    pub conditions: Vec<String>,
+}
+
+/// An ambiguity between two systems in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguityExport {
+    /// The id of the first system.
+    pub first: NodeId,
+    /// The id of the second system.
+    pub second: NodeId,
+    /// The names of the components and resources both systems access, with at least one of them mutably.
+    ///
+    /// If this is empty, the systems conflict on [`World`](crate::world::World) access.
+    pub components: Vec<String>,
+}
+
+impl ScheduleGraphExport {
+    /// Renders the graph in the Graphviz DOT language.
+    ///
+    /// Systems are boxes, with their run conditions listed below their name, and automatically inserted
+    /// sync points are dashed. System sets are ellipses linked to their members with dotted edges.
+    /// Ambiguities are red undirected edges labeled with the conflicting components.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph {} {{", dot_string(&self.label)).unwrap();
+        writeln!(dot, "  rankdir=LR;").unwrap();
+        writeln!(dot, "  node [shape=box];").unwrap();
+
+        for system in &self.systems {
+            let style = if system.is_auto_sync {
+                ", style=dashed"
+            } else if system.is_exclusive {
+                ", style=bold"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "  {} [label={}{style}];",
+                node_key(system.id),
+                dot_string(&node_label(&system.name, &system.conditions))
+            )
+            .unwrap();
+        }
+        for set in &self.sets {
+            writeln!(
+                dot,
+                "  {} [label={}, shape=ellipse];",
+                node_key(set.id),
+                dot_string(&node_label(&set.name, &set.conditions))
+            )
+            .unwrap();
+        }
+
+        for &(parent, child) in &self.hierarchy {
+            writeln!(
+                dot,
+                "  {} -> {} [style=dotted, arrowhead=none];",
+                node_key(parent),
+                node_key(child)
+            )
+            .unwrap();
+        }
+        for &(before, after) in &self.dependencies {
+            writeln!(dot, "  {} -> {};", node_key(before), node_key(after)).unwrap();
+        }
+        for ambiguity in &self.ambiguities {
+            let label = if ambiguity.components.is_empty() {
+                "World".to_string()
+            } else {
+                ambiguity.components.join(", ")
+            };
+            writeln!(
+                dot,
+                "  {} -> {} [dir=none, color=red, constraint=false, label={}];",
+                node_key(ambiguity.first),
+                node_key(ambiguity.second),
+                dot_string(&label)
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a JSON object.
+    ///
+    /// Nodes are identified by strings like `"system_3"` or `"set_2"`, which are used by the
+    /// `hierarchy`, `dependencies` and `ambiguities` fields to refer to them.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"label\":");
+        json_string(&mut json, &self.label);
+
+        json.push_str(",\"systems\":[");
+        for (i, system) in self.systems.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"id\":");
+            json_string(&mut json, &node_key(system.id));
+            json.push_str(",\"name\":");
+            json_string(&mut json, &system.name);
+            json.push_str(",\"conditions\":");
+            json_strings(&mut json, &system.conditions);
+            write!(
+                json,
+                ",\"exclusive\":{},\"auto_sync\":{}}}",
+                system.is_exclusive, system.is_auto_sync
+            )
+            .unwrap();
+        }
+
+        json.push_str("],\"sets\":[");
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"id\":");
+            json_string(&mut json, &node_key(set.id));
+            json.push_str(",\"name\":");
+            json_string(&mut json, &set.name);
+            json.push_str(",\"conditions\":");
+            json_strings(&mut json, &set.conditions);
+            json.push('}');
+        }
+
+        json.push_str("],\"hierarchy\":");
+        json_edges(&mut json, &self.hierarchy);
+        json.push_str(",\"dependencies\":");
+        json_edges(&mut json, &self.dependencies);
+
+        json.push_str(",\"ambiguities\":[");
+        for (i, ambiguity) in self.ambiguities.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"first\":");
+            json_string(&mut json, &node_key(ambiguity.first));
+            json.push_str(",\"second\":");
+            json_string(&mut json, &node_key(ambiguity.second));
+            json.push_str(",\"components\":");
+            json_strings(&mut json, &ambiguity.components);
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+fn node_key(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn node_label(name: &str, conditions: &[String]) -> String {
+    let mut label = name.to_string();
+    for condition in conditions {
+        write!(label, "\nif {condition}").unwrap();
+    }
+    label
+}
+
+fn dot_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+fn json_strings(json: &mut String, strings: &[String]) {
+    json.push('[');
+    for (i, s) in strings.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json_string(json, s);
+    }
+    json.push(']');
+}
+
+fn json_edges(json: &mut String, edges: &[(NodeId, NodeId)]) {
+    json.push('[');
+    for (i, &(a, b)) in edges.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push('[');
+        json_string(json, &node_key(a));
+        json.push(',');
+        json_string(json, &node_key(b));
+        json.push(']');
+    }
+    json.push(']');
+}
@@ -3,6 +3,7 @@
 mod condition;
 mod config;
 mod executor;
+mod graph_export;
 mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
@@ -12,6 +13,7 @@ mod stepping;
 pub use self::condition::*;
 pub use self::config::*;
 pub use self::executor::*;
+pub use self::graph_export::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
 pub use self::set::*;
@@ -1151,5 +1153,11 @@ mod tests {
         fn multi_threaded_executor() {
             assert_executor_supports_stepping!(ExecutorKind::MultiThreaded);
         }
+
+        /// verify the [`JobGraphExecutor`] supports stepping
+        #[test]
+        fn job_graph_executor() {
+            assert_executor_supports_stepping!(ExecutorKind::JobGraph);
+        }
     }
 }
@@ -17,6 +17,7 @@ use thiserror::Error;
 use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
+    error::ErrorHandler,
     prelude::Component,
     schedule::*,
     system::{BoxedSystem, IntoSystem, Resource, System},
@@ -205,6 +206,7 @@ fn make_executor(kind: ExecutorKind) -> Box<dyn SystemExecutor> {
         ExecutorKind::Simple => Box::new(SimpleExecutor::new()),
         ExecutorKind::SingleThreaded => Box::new(SingleThreadedExecutor::new()),
         ExecutorKind::MultiThreaded => Box::new(MultiThreadedExecutor::new()),
+        ExecutorKind::JobGraph => Box::new(JobGraphExecutor::new()),
     }
 }
 
@@ -263,6 +265,19 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    error_handler: Option<ErrorHandler>,
+}
+
+/// Restores the [`ErrorHandler`] of the enclosing schedule when a [`Schedule`] is done running.
+struct ErrorHandlerGuard<'w> {
+    world: &'w mut World,
+    outer: Option<ErrorHandler>,
+}
+
+impl Drop for ErrorHandlerGuard<'_> {
+    fn drop(&mut self) {
+        self.world.schedule_error_handler = self.outer;
+    }
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -287,6 +302,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            error_handler: None,
         }
     }
 
@@ -373,6 +389,20 @@ impl Schedule {
         self
     }
 
+    /// Returns the [`ErrorHandler`] set for this schedule, if any.
+    pub fn error_handler(&self) -> Option<ErrorHandler> {
+        self.error_handler
+    }
+
+    /// Sets the [`ErrorHandler`] for the errors returned by the systems of this schedule, and by the
+    /// observers and commands they trigger while the schedule runs.
+    ///
+    /// This overrides the handler set with [`World::set_error_handler`]. See [`error`](crate::error) for more details.
+    pub fn set_error_handler(&mut self, handler: ErrorHandler) -> &mut Self {
+        self.error_handler = Some(handler);
+        self
+    }
+
     /// Runs all systems in this schedule on the `world`, using its current execution strategy.
     pub fn run(&mut self, world: &mut World) {
         #[cfg(feature = "trace")]
@@ -382,18 +412,27 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        // Restores the outer handler even if a system panics and the panic is caught.
+        let guard = ErrorHandlerGuard {
+            outer: world.schedule_error_handler,
+            world,
+        };
+        if self.error_handler.is_some() {
+            guard.world.schedule_error_handler = self.error_handler;
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor.run(&mut self.executable, world, None);
+        self.executor.run(&mut self.executable, guard.world, None);
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
+            let skip_systems = match guard.world.get_resource_mut::<Stepping>() {
                 None => None,
                 Some(mut stepping) => stepping.skipped_systems(self),
             };
 
             self.executor
-                .run(&mut self.executable, world, skip_systems.as_ref());
+                .run(&mut self.executable, guard.world, skip_systems.as_ref());
         }
     }
 
@@ -426,6 +465,44 @@ impl Schedule {
         Ok(())
     }
 
+    /// Builds the schedule if needed, and exports its system graph for visualization.
+    ///
+    /// The export includes the systems, system sets, run conditions, ordering edges and ambiguities of the schedule,
+    /// as well as the sync points that were automatically inserted.
+    /// It can be rendered to Graphviz DOT or to JSON with [`ScheduleGraphExport::to_dot`] and [`ScheduleGraphExport::to_json`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct Score(u32);
+    /// fn increase_score(mut score: ResMut<Score>) {
+    ///     score.0 += 1;
+    /// }
+    ///
+    /// fn print_score(score: Res<Score>) {
+    ///     println!("{}", score.0);
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems((increase_score, print_score));
+    ///
+    /// let export = schedule.export_graph(&mut world).unwrap();
+    /// assert_eq!(export.systems.len(), 2);
+    /// // The systems are ambiguous: they access `Score` without an order between them.
+    /// assert_eq!(export.ambiguities.len(), 1);
+    /// assert!(export.to_dot().starts_with("digraph"));
+    /// ```
+    pub fn export_graph(
+        &mut self,
+        world: &mut World,
+    ) -> Result<ScheduleGraphExport, ScheduleBuildError> {
+        self.initialize(world)?;
+        Ok(self
+            .graph
+            .export(&self.executable, world.components(), self.label))
+    }
+
     /// Returns the [`ScheduleGraph`].
     pub fn graph(&self) -> &ScheduleGraph {
         &self.graph
@@ -1527,6 +1604,141 @@ impl ScheduleGraph {
 
         Ok(())
     }
+
+    /// Exports the graph of a built schedule.
+    ///
+    /// The systems and the conditions of the system sets have been moved into `schedule`,
+    /// so they are read from there.
+    fn export(
+        &self,
+        schedule: &SystemSchedule,
+        components: &Components,
+        schedule_label: InternedScheduleLabel,
+    ) -> ScheduleGraphExport {
+        let shorten = |name: &str| {
+            if self.settings.use_shortnames {
+                bevy_utils::get_short_name(name)
+            } else {
+                name.to_string()
+            }
+        };
+        let condition_names =
+            |conditions: &[BoxedCondition]| conditions.iter().map(|c| shorten(&c.name())).collect();
+        let auto_sync_nodes: HashSet<NodeId> = self.auto_sync_node_ids.values().copied().collect();
+
+        let mut names = HashMap::new();
+        let systems: Vec<SystemExport> = schedule
+            .system_ids
+            .iter()
+            .zip(&schedule.systems)
+            .zip(&schedule.system_conditions)
+            .map(|((&id, system), conditions)| {
+                let name = shorten(&system.name());
+                names.insert(id, name.clone());
+                SystemExport {
+                    id,
+                    name,
+                    conditions: condition_names(conditions),
+                    is_exclusive: system.is_exclusive(),
+                    is_auto_sync: auto_sync_nodes.contains(&id),
+                }
+            })
+            .collect();
+
+        let set_conditions: HashMap<NodeId, &[BoxedCondition]> = schedule
+            .set_ids
+            .iter()
+            .zip(&schedule.set_conditions)
+            .map(|(&id, conditions)| (id, conditions.as_slice()))
+            .collect();
+        let is_exported_set =
+            |id: &NodeId| id.is_set() && !self.system_sets[id.index()].is_system_type();
+        // System type sets are replaced by the systems they contain.
+        let resolve = |id: NodeId| -> Vec<NodeId> {
+            if id.is_set() && !is_exported_set(&id) {
+                self.hierarchy
+                    .graph
+                    .neighbors_directed(id, Outgoing)
+                    .collect()
+            } else {
+                vec![id]
+            }
+        };
+
+        // Go in reverse topological order, so that the members of anonymous sets are named first.
+        let mut sets = Vec::new();
+        for &id in self.hierarchy.topsort.iter().rev() {
+            if !is_exported_set(&id) {
+                continue;
+            }
+            let set = &self.system_sets[id.index()];
+            let name = if set.is_anonymous() {
+                let members: Vec<_> = self
+                    .hierarchy
+                    .graph
+                    .neighbors_directed(id, Outgoing)
+                    .flat_map(resolve)
+                    .filter_map(|member| names.get(&member).cloned())
+                    .collect();
+                format!("({})", members.join(", "))
+            } else {
+                shorten(&set.name())
+            };
+            names.insert(id, name.clone());
+            sets.push(SystemSetExport {
+                id,
+                name,
+                conditions: set_conditions
+                    .get(&id)
+                    .map_or_else(Vec::new, |conditions| condition_names(conditions)),
+            });
+        }
+        sets.reverse();
+
+        let mut hierarchy = Vec::new();
+        for (parent, child, _) in self.hierarchy.graph.all_edges() {
+            if is_exported_set(&parent) {
+                hierarchy.extend(resolve(child).into_iter().map(|child| (parent, child)));
+            }
+        }
+
+        let mut dependencies = Vec::new();
+        for (i, dependents) in schedule.system_dependents.iter().enumerate() {
+            for &j in dependents {
+                dependencies.push((schedule.system_ids[i], schedule.system_ids[j]));
+            }
+        }
+        for (before, after, _) in self.dependency.graph.all_edges() {
+            // edges between systems are already covered by the flattened graph
+            if is_exported_set(&before) || is_exported_set(&after) {
+                for before in resolve(before) {
+                    dependencies.extend(resolve(after).into_iter().map(|after| (before, after)));
+                }
+            }
+        }
+
+        let ambiguities = self
+            .conflicting_systems
+            .iter()
+            .map(|(first, second, conflicts)| AmbiguityExport {
+                first: *first,
+                second: *second,
+                components: conflicts
+                    .iter()
+                    .map(|&id| shorten(components.get_name(id).unwrap_or("<unknown>")))
+                    .collect(),
+            })
+            .collect();
+
+        ScheduleGraphExport {
+            label: format!("{schedule_label:?}"),
+            systems,
+            sets,
+            hierarchy,
+            dependencies,
+            ambiguities,
+        }
+    }
 }
 
 /// Values returned by [`ScheduleGraph::process_configs`]
@@ -2632,4 +2844,81 @@ mod tests {
             .expect("CheckSystemRan Resource Should Exist");
         assert_eq!(value.0, 2);
     }
+
+    #[test]
+    fn export_graph() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn insert(mut commands: Commands) {
+            commands.insert_resource(Resource1);
+        }
+        fn read(_: Res<Resource1>) {}
+        fn write_a(_: ResMut<Resource2>) {}
+        fn write_b(_: ResMut<Resource2>) {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Set.run_if(|| true));
+        schedule.add_systems(((insert, read).chain().in_set(Set), write_a, write_b));
+
+        let export = schedule.export_graph(&mut world).unwrap();
+        let id_of = |name: &str| {
+            export
+                .systems
+                .iter()
+                .find(|system| system.name.ends_with(name))
+                .unwrap()
+                .id
+        };
+        let (insert, read) = (id_of("insert"), id_of("read"));
+        let (write_a, write_b) = (id_of("write_a"), id_of("write_b"));
+
+        // the chain goes through the sync point inserted between the two systems
+        let sync = export
+            .systems
+            .iter()
+            .find(|system| system.is_auto_sync)
+            .unwrap()
+            .id;
+        assert_eq!(export.systems.len(), 5);
+        assert!(export.dependencies.contains(&(insert, sync)));
+        assert!(export.dependencies.contains(&(sync, read)));
+        assert!(!export.dependencies.contains(&(insert, read)));
+
+        let set = export.sets.iter().find(|set| set.name == "Set").unwrap();
+        assert_eq!(set.conditions.len(), 1);
+        assert!(export.hierarchy.contains(&(set.id, insert)));
+        assert!(export.hierarchy.contains(&(set.id, read)));
+
+        assert_eq!(export.ambiguities.len(), 1);
+        let ambiguity = &export.ambiguities[0];
+        assert!(
+            (ambiguity.first, ambiguity.second) == (write_a, write_b)
+                || (ambiguity.first, ambiguity.second) == (write_b, write_a)
+        );
+        assert_eq!(ambiguity.components, vec!["Resource2".to_string()]);
+    }
+
+    #[test]
+    fn export_graph_formats() {
+        fn system() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(system.run_if(|| true));
+
+        let export = schedule.export_graph(&mut world).unwrap();
+        let dot = export.to_dot();
+        assert!(dot.starts_with("digraph \"TestSchedule\" {\n"));
+        assert!(dot.contains("system_0 [label=\"system\\nif "));
+
+        let json = export.to_json();
+        assert!(json.starts_with(
+            "{\"label\":\"TestSchedule\",\"systems\":[{\"id\":\"system_0\",\"name\":\"system\",\"conditions\":["
+        ));
+        assert!(json.ends_with(
+            "\"exclusive\":false,\"auto_sync\":false}],\"sets\":[],\"hierarchy\":[],\"dependencies\":[],\"ambiguities\":[]}"
+        ));
+    }
 }
@@ -41,3 +41,66 @@ pub struct Storages {
     /// Backing storage for `!Send` resources.
     pub non_send_resources: Resources<false>,
 }
+
+/// The number of bytes of heap memory used and allocated by a storage.
+///
+/// Returned by the `memory_usage` methods of the storage types, and used by
+/// [`World::memory_stats`](crate::world::World::memory_stats).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of bytes occupied by live values.
+    pub used_bytes: usize,
+    /// The number of bytes allocated, including spare capacity.
+    pub allocated_bytes: usize,
+}
+
+impl MemoryUsage {
+    /// Returns the memory usage of the elements and the spare capacity of `vec`.
+    pub(crate) fn of_vec<T>(vec: &Vec<T>) -> Self {
+        Self {
+            used_bytes: vec.len() * std::mem::size_of::<T>(),
+            allocated_bytes: vec.capacity() * std::mem::size_of::<T>(),
+        }
+    }
+
+    /// Returns the number of allocated bytes that are not used by any value.
+    #[inline]
+    pub fn unused_bytes(&self) -> usize {
+        self.allocated_bytes - self.used_bytes
+    }
+
+    /// Returns the fraction of the allocated memory that is not in use, from `0.0` to `1.0`.
+    ///
+    /// Storages that allocate nothing are not fragmented.
+    #[inline]
+    pub fn fragmentation(&self) -> f32 {
+        if self.allocated_bytes == 0 {
+            0.0
+        } else {
+            self.unused_bytes() as f32 / self.allocated_bytes as f32
+        }
+    }
+}
+
+impl std::ops::Add for MemoryUsage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            used_bytes: self.used_bytes + rhs.used_bytes,
+            allocated_bytes: self.allocated_bytes + rhs.allocated_bytes,
+        }
+    }
+}
+
+impl std::ops::AddAssign for MemoryUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::iter::Sum for MemoryUsage {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), std::ops::Add::add)
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     change_detection::MaybeUnsafeCellLocation,
     component::{ComponentId, ComponentInfo, ComponentTicks, Tick, TickCells},
     entity::Entity,
-    storage::{Column, TableRow},
+    storage::{Column, MemoryUsage, TableRow},
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use nonmax::NonMaxUsize;
@@ -106,6 +106,16 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         self.values.clear();
     }
 
+    /// Returns the number of slots in the sparse array, occupied or not.
+    pub(crate) fn sparse_len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the heap memory used by the slots of the sparse array.
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.values)
+    }
+
     /// Converts the [`SparseArray`] into an immutable variant.
     pub(crate) fn into_immutable(self) -> ImmutableSparseArray<I, V> {
         ImmutableSparseArray {
@@ -155,6 +165,20 @@ impl ComponentSparseSet {
         self.dense.len()
     }
 
+    /// Returns the number of slots in the sparse array mapping entity indices to dense indices.
+    ///
+    /// This grows with the highest entity index that ever had the component, rather than with [`len`](Self::len).
+    #[inline]
+    pub fn sparse_len(&self) -> usize {
+        self.sparse.sparse_len()
+    }
+
+    /// Returns the heap memory used by the dense component values, the dense entity list
+    /// and the sparse array of this sparse set.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.dense.memory_usage() + MemoryUsage::of_vec(&self.entities) + self.sparse.memory_usage()
+    }
+
     /// Returns `true` if the sparse set contains no component values.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -3,7 +3,7 @@ use crate::{
     component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick, TickCells},
     entity::Entity,
     query::DebugCheckedUnwrap,
-    storage::{blob_vec::BlobVec, ImmutableSparseSet, SparseSet},
+    storage::{blob_vec::BlobVec, ImmutableSparseSet, MemoryUsage, SparseSet},
 };
 use bevy_ptr::{OwningPtr, Ptr, PtrMut, UnsafeCellDeref};
 use bevy_utils::HashMap;
@@ -244,6 +244,25 @@ impl Column {
         self.data.is_empty()
     }
 
+    /// Returns the heap memory used by the component values and change detection ticks of this column.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let item_size = self.item_layout().size();
+        let data = MemoryUsage {
+            used_bytes: self.data.len() * item_size,
+            // Zero-sized types report a capacity of `usize::MAX`, but their size is 0.
+            allocated_bytes: self.data.capacity().saturating_mul(item_size),
+        };
+        #[allow(unused_mut)]
+        let mut usage = data
+            + MemoryUsage::of_vec(&self.added_ticks)
+            + MemoryUsage::of_vec(&self.changed_ticks);
+        #[cfg(feature = "track_change_detection")]
+        {
+            usage += MemoryUsage::of_vec(&self.changed_by);
+        }
+        usage
+    }
+
     /// Removes an element from the [`Column`].
     ///
     /// - The value will be dropped if it implements [`Drop`].
@@ -862,6 +881,11 @@ impl Table {
         self.entities.capacity()
     }
 
+    /// Returns the heap memory used by the entities and all of the columns of this table.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.entities) + self.columns.values().map(Column::memory_usage).sum()
+    }
+
     /// Checks if the [`Table`] is empty or not.
     ///
     /// Returns `true` if the table contains no entities, `false` otherwise.
@@ -881,6 +905,11 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the [`Column`]s of the [`Table`], along with the [`ComponentId`] they store.
+    pub fn iter_with_ids(&self) -> impl Iterator<Item = (ComponentId, &Column)> {
+        self.columns.iter().map(|(id, column)| (*id, column))
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         self.entities.clear();
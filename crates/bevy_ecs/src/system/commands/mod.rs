@@ -6,11 +6,13 @@ use super::{Deferred, IntoObserverSystem, IntoSystem, RegisterSystem, Resource};
 use crate::{
     self as bevy_ecs,
     bundle::Bundle,
+    change_detection::Mut,
     component::{ComponentId, ComponentInfo},
-    entity::{Entities, Entity},
+    entity::{Entities, Entity, EntityCloneBuilder},
+    error::{self, ErrorContext, ErrorHandler, IntoResult},
     event::Event,
     observer::{Observer, TriggerEvent, TriggerTargets},
-    system::{RunSystemWithInput, SystemId},
+    system::{RunSystemCachedWithInput, RunSystemWithInput, SystemId, UnregisterSystemCached},
     world::{
         command_queue::RawCommandQueue, Command, CommandQueue, EntityWorldMut, FromWorld,
         SpawnBatchIter, World,
@@ -279,6 +281,7 @@ impl<'w, 's> Commands<'w, 's> {
         EntityCommands {
             entity,
             commands: self.reborrow(),
+            missing_entity_handler: None,
         }
     }
 
@@ -303,6 +306,7 @@ impl<'w, 's> Commands<'w, 's> {
         EntityCommands {
             entity,
             commands: self.reborrow(),
+            missing_entity_handler: None,
         }
     }
 
@@ -452,6 +456,7 @@ impl<'w, 's> Commands<'w, 's> {
         self.entities.contains(entity).then_some(EntityCommands {
             entity,
             commands: self.reborrow(),
+            missing_entity_handler: None,
         })
     }
 
@@ -631,6 +636,32 @@ impl<'w, 's> Commands<'w, 's> {
         self.push(remove_resource::<R>);
     }
 
+    /// Pushes a [`Command`] to the queue for modifying a [`Resource`] with `f`,
+    /// running its `on_replace` and `on_insert` hooks and observers.
+    ///
+    /// Does nothing if the resource doesn't exist.
+    /// See [`World::modify_resource`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Scoreboard {
+    /// #     current_score: u32,
+    /// #     high_score: u32,
+    /// # }
+    /// #
+    /// # fn system(mut commands: Commands) {
+    /// commands.modify_resource(|mut scoreboard: Mut<Scoreboard>| scoreboard.current_score = 0);
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn modify_resource<R: Resource>(&mut self, f: impl FnOnce(Mut<R>) + Send + 'static) {
+        self.push(modify_resource(f));
+    }
+
     /// Runs the system corresponding to the given [`SystemId`].
     /// Systems are ran in an exclusive and single threaded way.
     /// Running slow systems can become a bottleneck.
@@ -640,7 +671,10 @@ impl<'w, 's> Commands<'w, 's> {
     /// There is no way to get the output of a system when run as a command, because the
     /// execution of the system happens later. To get the output of a system, use
     /// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
-    pub fn run_system(&mut self, id: SystemId) {
+    ///
+    /// If the system returns a [`Result`](crate::error::Result), its errors are routed to the
+    /// [`World`]'s current [`ErrorHandler`](crate::error::ErrorHandler).
+    pub fn run_system<O: IntoResult + 'static + Send>(&mut self, id: SystemId<(), O>) {
         self.run_system_with_input(id, ());
     }
 
@@ -653,7 +687,14 @@ impl<'w, 's> Commands<'w, 's> {
     /// There is no way to get the output of a system when run as a command, because the
     /// execution of the system happens later. To get the output of a system, use
     /// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
-    pub fn run_system_with_input<I: 'static + Send>(&mut self, id: SystemId<I>, input: I) {
+    ///
+    /// If the system returns a [`Result`](crate::error::Result), its errors are routed to the
+    /// [`World`]'s current [`ErrorHandler`](crate::error::ErrorHandler).
+    pub fn run_system_with_input<I: 'static + Send, O: IntoResult + 'static + Send>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I,
+    ) {
         self.push(RunSystemWithInput::new_with_input(id, input));
     }
 
@@ -720,6 +761,70 @@ impl<'w, 's> Commands<'w, 's> {
         SystemId::from_entity(entity)
     }
 
+    /// Similar to [`Self::run_system`], but caching the [`SystemId`] in a
+    /// [`CachedSystemId`](crate::system::CachedSystemId) resource.
+    ///
+    /// This lets callbacks run systems without storing their ids anywhere.
+    /// See [`World::register_system_cached`] for more information.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Score(u32);
+    ///
+    /// fn add_point(mut score: ResMut<Score>) {
+    ///     score.0 += 1;
+    /// }
+    ///
+    /// fn on_button_pressed(mut commands: Commands) {
+    ///     commands.run_system_cached(add_point);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(on_button_pressed);
+    /// ```
+    pub fn run_system_cached<
+        O: IntoResult + 'static + Send,
+        M: 'static,
+        S: IntoSystem<(), O, M> + Send + 'static,
+    >(
+        &mut self,
+        system: S,
+    ) {
+        self.run_system_cached_with_input(system, ());
+    }
+
+    /// Similar to [`Self::run_system_with_input`], but caching the [`SystemId`] in a
+    /// [`CachedSystemId`](crate::system::CachedSystemId) resource.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached_with_input<
+        I: 'static + Send,
+        O: IntoResult + 'static + Send,
+        M: 'static,
+        S: IntoSystem<I, O, M> + Send + 'static,
+    >(
+        &mut self,
+        system: S,
+        input: I,
+    ) {
+        self.push(RunSystemCachedWithInput::new_with_input(system, input));
+    }
+
+    /// Removes a system previously registered with [`Commands::run_system_cached`] or
+    /// [`World::register_system_cached`], along with its cached [`SystemId`].
+    ///
+    /// See [`World::unregister_system_cached`] for more information.
+    pub fn unregister_system_cached<
+        I: 'static + Send,
+        O: 'static + Send,
+        M: 'static,
+        S: IntoSystem<I, O, M> + Send + 'static,
+    >(
+        &mut self,
+        system: S,
+    ) {
+        self.push(UnregisterSystemCached::new(system));
+    }
+
     /// Pushes a generic [`Command`] to the command queue.
     ///
     /// `command` can be a built-in command, custom struct that implements [`Command`] or a closure
@@ -859,9 +964,61 @@ pub trait EntityCommand<Marker = ()>: Send + 'static {
 }
 
 /// A list of commands that will be run to modify an [entity](crate::entity).
+///
+/// # Missing entities
+///
+/// The entity may have been despawned by the time the commands are applied. Commands that need it
+/// then hand an [`EntityMissingError`] to an [`ErrorHandler`], which is the first one that is set among:
+/// 1. The handler of this [`EntityCommands`], set with [`on_missing_entity`](Self::on_missing_entity).
+///    The `try_` variants of the commands, like [`try_insert`](Self::try_insert), always use [`error::ignore`].
+/// 2. The handler of the [`World`], set with [`World::set_entity_command_error_handler`].
+/// 3. The default of the command: [`despawn`](Self::despawn) uses [`error::warn`], commands that
+///    remove components or observe the entity use [`error::ignore`], and other commands use the
+///    [`World`]'s [error handler](World::error_handler), which panics by default.
 pub struct EntityCommands<'a> {
     pub(crate) entity: Entity,
     pub(crate) commands: Commands<'a, 'a>,
+    pub(crate) missing_entity_handler: Option<ErrorHandler>,
+}
+
+/// The error handed to an [`ErrorHandler`] when an entity command is applied to an entity that
+/// doesn't exist.
+///
+/// See [`EntityCommands`] for how the handler is chosen.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("error[B0003]: {caller}: Could not apply `{command}` to entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003")]
+pub struct EntityMissingError {
+    /// The entity that doesn't exist.
+    pub entity: Entity,
+    /// The name of the command, like `EntityCommands::insert`.
+    pub command: &'static str,
+    /// Where the command was queued.
+    pub caller: &'static Location<'static>,
+}
+
+impl EntityMissingError {
+    /// Hands this error to `handler` if it is set, then to the [`World`]'s
+    /// [entity command error handler](World::entity_command_error_handler), then to `default`.
+    /// If none of them are set, the error goes to the [`World`]'s [error handler](World::error_handler).
+    ///
+    /// This is useful to implement commands that follow the same rules as [`EntityCommands`].
+    pub fn handle(
+        self,
+        world: &World,
+        handler: Option<ErrorHandler>,
+        default: Option<ErrorHandler>,
+    ) {
+        let handler = handler
+            .or(world.entity_command_error_handler())
+            .or(default)
+            .unwrap_or_else(|| world.error_handler());
+        handler(
+            self.into(),
+            ErrorContext::Command {
+                name: self.command.into(),
+            },
+        );
+    }
 }
 
 impl EntityCommands<'_> {
@@ -889,18 +1046,61 @@ impl EntityCommands<'_> {
         EntityCommands {
             entity: self.entity,
             commands: self.commands.reborrow(),
+            missing_entity_handler: self.missing_entity_handler,
         }
     }
 
+    /// Sets the [`ErrorHandler`] used by the commands queued afterwards through this [`EntityCommands`]
+    /// when the entity doesn't exist anymore by the time they are applied.
+    ///
+    /// This overrides the default of each command and the handler set with
+    /// [`World::set_entity_command_error_handler`]. See [`EntityCommands`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::{error, prelude::*};
+    /// #[derive(Component)]
+    /// struct Stunned;
+    ///
+    /// fn stun(mut commands: Commands, query: Query<Entity>) {
+    ///     for entity in &query {
+    ///         commands
+    ///             .entity(entity)
+    ///             // The entity may be despawned by another system before this is applied.
+    ///             .on_missing_entity(error::warn)
+    ///             .insert(Stunned);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(stun);
+    /// ```
+    pub fn on_missing_entity(&mut self, handler: ErrorHandler) -> &mut Self {
+        self.missing_entity_handler = Some(handler);
+        self
+    }
+
+    /// Returns the [`ErrorHandler`] set with [`on_missing_entity`](Self::on_missing_entity), if any.
+    pub fn missing_entity_handler(&self) -> Option<ErrorHandler> {
+        self.missing_entity_handler
+    }
+
+    /// Returns an [`EntityCommands`] with a smaller lifetime whose commands ignore the entity not existing.
+    fn ignoring_missing_entity(&mut self) -> EntityCommands {
+        let mut commands = self.reborrow();
+        commands.missing_entity_handler = Some(error::ignore);
+        commands
+    }
+
     /// Adds a [`Bundle`] of components to the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// With the default [`ErrorHandler`](crate::error::ErrorHandler), the command will panic when applied
+    /// if the associated entity does not exist.
     ///
-    /// To avoid a panic in this case, use the command [`Self::try_insert`] instead.
+    /// To avoid an error in this case, use the command [`Self::try_insert`] instead.
     ///
     /// # Example
     ///
@@ -945,7 +1145,7 @@ impl EntityCommands<'_> {
     /// ```
     #[track_caller]
     pub fn insert(&mut self, bundle: impl Bundle) -> &mut Self {
-        self.add(insert(bundle))
+        self.add(insert(bundle, self.missing_entity_handler))
     }
 
     /// Adds a dynamic component to an entity.
@@ -954,9 +1154,10 @@ impl EntityCommands<'_> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// With the default [`ErrorHandler`](crate::error::ErrorHandler), the command will panic when applied
+    /// if the associated entity does not exist.
     ///
-    /// To avoid a panic in this case, use the command [`Self::try_insert_by_id`] instead.
+    /// To avoid an error in this case, use the command [`Self::try_insert_by_id`] instead.
     ///
     /// # Safety
     ///
@@ -968,12 +1169,8 @@ impl EntityCommands<'_> {
         component_id: ComponentId,
         value: T,
     ) -> &mut Self {
-        let caller = Location::caller();
         // SAFETY: same invariants as parent call
-        self.add(unsafe {insert_by_id(component_id, value, move |entity| {
-            panic!("error[B0003]: {caller}: Could not insert a component {component_id:?} (with type {}) for entity {entity:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/#b0003", std::any::type_name::<T>());
-        })});
-        self
+        self.add(unsafe { insert_by_id(component_id, value, self.missing_entity_handler) })
     }
 
     /// Attempts to add a dynamic component to an entity.
@@ -984,13 +1181,17 @@ impl EntityCommands<'_> {
     ///
     /// - [`ComponentId`] must be from the same world as `self`.
     /// - `T` must have the same layout as the one passed during `component_id` creation.
+    #[track_caller]
     pub unsafe fn try_insert_by_id<T: Send + 'static>(
         &mut self,
         component_id: ComponentId,
         value: T,
     ) -> &mut Self {
         // SAFETY: same invariants as parent call
-        self.add(unsafe { insert_by_id(component_id, value, |_| {}) });
+        unsafe {
+            self.ignoring_missing_entity()
+                .insert_by_id(component_id, value);
+        }
         self
     }
 
@@ -1044,7 +1245,8 @@ impl EntityCommands<'_> {
     /// ```
     #[track_caller]
     pub fn try_insert(&mut self, bundle: impl Bundle) -> &mut Self {
-        self.add(try_insert(bundle))
+        self.ignoring_missing_entity().insert(bundle);
+        self
     }
 
     /// Removes a [`Bundle`] of components from the entity.
@@ -1082,25 +1284,55 @@ impl EntityCommands<'_> {
     /// }
     /// # bevy_ecs::system::assert_is_system(remove_combat_stats_system);
     /// ```
+    #[track_caller]
     pub fn remove<T>(&mut self) -> &mut Self
     where
         T: Bundle,
     {
-        self.add(remove::<T>)
+        self.add(remove::<T>(self.missing_entity_handler))
+    }
+
+    /// Removes a [`Bundle`] of components from the entity, ignoring the entity not existing
+    /// regardless of the [`ErrorHandler`] that would otherwise be used.
+    ///
+    /// See [`Self::remove`] for more details.
+    #[track_caller]
+    pub fn try_remove<T>(&mut self) -> &mut Self
+    where
+        T: Bundle,
+    {
+        self.ignoring_missing_entity().remove::<T>();
+        self
     }
 
     /// Removes a component from the entity.
+    #[track_caller]
     pub fn remove_by_id(&mut self, component_id: ComponentId) -> &mut Self {
-        self.add(remove_by_id(component_id))
+        self.add(remove_by_id(component_id, self.missing_entity_handler))
+    }
+
+    /// Removes a component from the entity, ignoring the entity not existing.
+    #[track_caller]
+    pub fn try_remove_by_id(&mut self, component_id: ComponentId) -> &mut Self {
+        self.ignoring_missing_entity().remove_by_id(component_id);
+        self
     }
 
     /// Removes all components associated with the entity.
+    #[track_caller]
     pub fn clear(&mut self) -> &mut Self {
-        self.add(clear())
+        self.add(clear(self.missing_entity_handler))
+    }
+
+    /// Removes all components associated with the entity, ignoring the entity not existing.
+    #[track_caller]
+    pub fn try_clear(&mut self) -> &mut Self {
+        self.ignoring_missing_entity().clear();
+        self
     }
 
     /// Despawns the entity.
-    /// This will emit a warning if the entity does not exist.
+    /// By default, this will emit a warning if the entity does not exist.
     ///
     /// See [`World::despawn`] for more details.
     ///
@@ -1128,7 +1360,15 @@ impl EntityCommands<'_> {
     /// ```
     #[track_caller]
     pub fn despawn(&mut self) {
-        self.add(despawn());
+        self.add(despawn(self.missing_entity_handler));
+    }
+
+    /// Despawns the entity, without emitting a warning if it does not exist.
+    ///
+    /// See [`Self::despawn`] for more details.
+    #[track_caller]
+    pub fn try_despawn(&mut self) {
+        self.ignoring_missing_entity().despawn();
     }
 
     /// Pushes an [`EntityCommand`] to the queue, which will get executed for the current [`Entity`].
@@ -1189,20 +1429,82 @@ impl EntityCommands<'_> {
     /// }
     /// # bevy_ecs::system::assert_is_system(remove_combat_stats_system);
     /// ```
+    #[track_caller]
     pub fn retain<T>(&mut self) -> &mut Self
     where
         T: Bundle,
     {
-        self.add(retain::<T>)
+        self.add(retain::<T>(self.missing_entity_handler))
+    }
+
+    /// Removes all components except the given [`Bundle`] from the entity, ignoring the entity not existing.
+    ///
+    /// See [`Self::retain`] for more details.
+    #[track_caller]
+    pub fn try_retain<T>(&mut self) -> &mut Self
+    where
+        T: Bundle,
+    {
+        self.ignoring_missing_entity().retain::<T>();
+        self
     }
 
     /// Logs the components of the entity at the info level.
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// With the default [`ErrorHandler`], the command will panic when applied
+    /// if the associated entity does not exist.
+    #[track_caller]
     pub fn log_components(&mut self) {
-        self.add(log_components);
+        self.add(log_components(self.missing_entity_handler));
+    }
+
+    /// Clones the entity into a newly spawned entity and returns the [`EntityCommands`] of the clone.
+    ///
+    /// Components are cloned using their [`ComponentCloneHandler`](crate::component::ComponentCloneHandler),
+    /// see [`EntityCloneBuilder`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// With the default [`ErrorHandler`], the command will panic when applied
+    /// if the associated entity does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone)]
+    /// struct Health(u32);
+    ///
+    /// fn duplicate_system(mut commands: Commands, query: Query<Entity, With<Health>>) {
+    ///     for entity in &query {
+    ///         let clone = commands.entity(entity).clone_entity().id();
+    ///         # let _ = clone;
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(duplicate_system);
+    /// ```
+    #[track_caller]
+    pub fn clone_entity(&mut self) -> EntityCommands {
+        self.clone_entity_with(|_| {})
+    }
+
+    /// Clones the entity into a newly spawned entity and returns the [`EntityCommands`] of the clone,
+    /// using `f` to configure the [`EntityCloneBuilder`] used for the clone.
+    ///
+    /// # Panics
+    ///
+    /// With the default [`ErrorHandler`], the command will panic when applied
+    /// if the associated entity does not exist.
+    #[track_caller]
+    pub fn clone_entity_with(
+        &mut self,
+        f: impl FnOnce(&mut EntityCloneBuilder) + Send + Sync + 'static,
+    ) -> EntityCommands {
+        let target = self.commands.spawn_empty().id();
+        self.add(clone_entity(target, f, self.missing_entity_handler));
+        self.commands.entity(target)
     }
 
     /// Returns the underlying [`Commands`].
@@ -1211,11 +1513,22 @@ impl EntityCommands<'_> {
     }
 
     /// Creates an [`Observer`] listening for a trigger of type `T` that targets this entity.
+    #[track_caller]
     pub fn observe<E: Event, B: Bundle, M>(
         &mut self,
         system: impl IntoObserverSystem<E, B, M>,
     ) -> &mut Self {
-        self.add(observe(system));
+        self.add(observe(system, self.missing_entity_handler))
+    }
+
+    /// Creates an [`Observer`] listening for a trigger of type `T` that targets this entity,
+    /// ignoring the entity not existing.
+    #[track_caller]
+    pub fn try_observe<E: Event, B: Bundle, M>(
+        &mut self,
+        system: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.ignoring_missing_entity().observe(system);
         self
     }
 }
@@ -1295,116 +1608,162 @@ where
     }
 }
 
-/// A [`Command`] that despawns a specific entity.
-/// This will emit a warning if the entity does not exist.
+/// Wraps `f` into an [`EntityCommand`] that runs it on the entity, or hands an [`EntityMissingError`]
+/// to the [`ErrorHandler`] chosen from `handler` and `default` if the entity doesn't exist.
+///
+/// See [`EntityMissingError::handle`] for how the handler is chosen.
+fn entity_command(
+    command: &'static str,
+    caller: &'static Location<'static>,
+    handler: Option<ErrorHandler>,
+    default: Option<ErrorHandler>,
+    f: impl FnOnce(EntityWorldMut) + Send + 'static,
+) -> impl EntityCommand {
+    move |entity: Entity, world: &mut World| {
+        if let Some(entity) = world.get_entity_mut(entity) {
+            f(entity);
+        } else {
+            EntityMissingError {
+                entity,
+                command,
+                caller,
+            }
+            .handle(world, handler, default);
+        }
+    }
+}
+
+/// An [`EntityCommand`] that despawns an entity.
+/// By default, this will emit a warning if the entity does not exist.
 ///
 /// # Note
 ///
 /// This won't clean up external references to the entity (such as parent-child relationships
 /// if you're using `bevy_hierarchy`), which may leave the world in an invalid state.
 #[track_caller]
-fn despawn() -> impl EntityCommand {
+fn despawn(handler: Option<ErrorHandler>) -> impl EntityCommand {
     let caller = Location::caller();
     move |entity: Entity, world: &mut World| {
-        world.despawn_with_caller(entity, caller);
-    }
-}
-
-/// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity.
-#[track_caller]
-fn insert<T: Bundle>(bundle: T) -> impl EntityCommand {
-    let caller = core::panic::Location::caller();
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
-            entity.insert_with_caller(
-                bundle,
-                #[cfg(feature = "track_change_detection")]
+        // A missing entity is reported through the error handlers instead of the warning of `World::despawn`.
+        if !world.despawn_with_caller(entity, caller, false) {
+            EntityMissingError {
+                entity,
+                command: "EntityCommands::despawn",
                 caller,
-            );
-        } else {
-            panic!("error[B0003]: {caller}: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", std::any::type_name::<T>(), entity);
+            }
+            .handle(world, handler, Some(error::warn));
         }
     }
 }
 
-/// An [`EntityCommand`] that attempts to add the components in a [`Bundle`] to an entity.
+/// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity.
 #[track_caller]
-fn try_insert(bundle: impl Bundle) -> impl EntityCommand {
-    #[cfg(feature = "track_change_detection")]
-    let caller = core::panic::Location::caller();
-    move |entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
+fn insert<T: Bundle>(bundle: T, handler: Option<ErrorHandler>) -> impl EntityCommand {
+    let caller = Location::caller();
+    entity_command(
+        "EntityCommands::insert",
+        caller,
+        handler,
+        None,
+        move |mut entity| {
             entity.insert_with_caller(
                 bundle,
                 #[cfg(feature = "track_change_detection")]
                 caller,
             );
-        }
-    }
+        },
+    )
 }
 
-/// An [`EntityCommand`] that attempts to add the dynamic component to an entity.
+/// An [`EntityCommand`] that adds the dynamic component to an entity.
 ///
 /// # Safety
 ///
 /// - The returned `EntityCommand` must be queued for the world where `component_id` was created.
 /// - `T` must be the type represented by `component_id`.
+#[track_caller]
 unsafe fn insert_by_id<T: Send + 'static>(
     component_id: ComponentId,
     value: T,
-    on_none_entity: impl FnOnce(Entity) + Send + 'static,
+    handler: Option<ErrorHandler>,
 ) -> impl EntityCommand {
-    move |entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
+    entity_command(
+        "EntityCommands::insert_by_id",
+        Location::caller(),
+        handler,
+        None,
+        move |mut entity| {
             // SAFETY:
             // - `component_id` safety is ensured by the caller
             // - `ptr` is valid within the `make` block;
             OwningPtr::make(value, |ptr| unsafe {
                 entity.insert_by_id(component_id, ptr);
             });
-        } else {
-            on_none_entity(entity);
-        }
-    }
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove any components in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn remove<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Some(mut entity) = world.get_entity_mut(entity) {
-        entity.remove::<T>();
-    }
+#[track_caller]
+fn remove<T: Bundle>(handler: Option<ErrorHandler>) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::remove",
+        Location::caller(),
+        handler,
+        Some(error::ignore),
+        |mut entity| {
+            entity.remove::<T>();
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes components with a provided [`ComponentId`] from an entity.
 /// # Panics
 ///
 /// Panics if the provided [`ComponentId`] does not exist in the [`World`].
-fn remove_by_id(component_id: ComponentId) -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
+#[track_caller]
+fn remove_by_id(component_id: ComponentId, handler: Option<ErrorHandler>) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::remove_by_id",
+        Location::caller(),
+        handler,
+        Some(error::ignore),
+        move |mut entity| {
             entity.remove_by_id(component_id);
-        }
-    }
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes all components associated with a provided entity.
-fn clear() -> impl EntityCommand {
-    move |entity: Entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
+#[track_caller]
+fn clear(handler: Option<ErrorHandler>) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::clear",
+        Location::caller(),
+        handler,
+        Some(error::ignore),
+        |mut entity| {
             entity.clear();
-        }
-    }
+        },
+    )
 }
 
 /// An [`EntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove all components except those in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
-fn retain<T: Bundle>(entity: Entity, world: &mut World) {
-    if let Some(mut entity_mut) = world.get_entity_mut(entity) {
-        entity_mut.retain::<T>();
-    }
+#[track_caller]
+fn retain<T: Bundle>(handler: Option<ErrorHandler>) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::retain",
+        Location::caller(),
+        handler,
+        Some(error::ignore),
+        |mut entity| {
+            entity.retain::<T>();
+        },
+    )
 }
 
 /// A [`Command`] that inserts a [`Resource`] into the world using a value
@@ -1420,6 +1779,13 @@ fn remove_resource<R: Resource>(world: &mut World) {
     world.remove_resource::<R>();
 }
 
+/// A [`Command`] that modifies the [resource](Resource) `R` with `f`.
+fn modify_resource<R: Resource>(f: impl FnOnce(Mut<R>) + Send + 'static) -> impl Command {
+    move |world: &mut World| {
+        world.modify_resource(f);
+    }
+}
+
 /// A [`Command`] that inserts a [`Resource`] into the world.
 #[track_caller]
 fn insert_resource<R: Resource>(resource: R) -> impl Command {
@@ -1435,22 +1801,61 @@ fn insert_resource<R: Resource>(resource: R) -> impl Command {
 }
 
 /// [`EntityCommand`] to log the components of a given entity. See [`EntityCommands::log_components`].
-fn log_components(entity: Entity, world: &mut World) {
-    let debug_infos: Vec<_> = world
-        .inspect_entity(entity)
-        .map(ComponentInfo::name)
-        .collect();
-    info!("Entity {entity}: {debug_infos:?}");
+#[track_caller]
+fn log_components(handler: Option<ErrorHandler>) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::log_components",
+        Location::caller(),
+        handler,
+        None,
+        |entity| {
+            let entity_id = entity.id();
+            let debug_infos: Vec<_> = entity
+                .into_world_mut()
+                .inspect_entity(entity_id)
+                .map(ComponentInfo::name)
+                .collect();
+            info!("Entity {entity_id}: {debug_infos:?}");
+        },
+    )
+}
+
+/// An [`EntityCommand`] that clones an entity into `target`. See [`EntityCommands::clone_entity_with`].
+#[track_caller]
+fn clone_entity(
+    target: Entity,
+    f: impl FnOnce(&mut EntityCloneBuilder) + Send + Sync + 'static,
+    handler: Option<ErrorHandler>,
+) -> impl EntityCommand {
+    entity_command(
+        "EntityCommands::clone_entity",
+        Location::caller(),
+        handler,
+        None,
+        move |entity| {
+            let source = entity.id();
+            let mut builder = EntityCloneBuilder::new(entity.into_world_mut());
+            f(&mut builder);
+            builder.clone_entity_to(source, target);
+        },
+    )
 }
 
+/// An [`EntityCommand`] that spawns an [`Observer`] watching an entity. See [`EntityCommands::observe`].
+#[track_caller]
 fn observe<E: Event, B: Bundle, M>(
     observer: impl IntoObserverSystem<E, B, M>,
+    handler: Option<ErrorHandler>,
 ) -> impl EntityCommand {
-    move |entity, world: &mut World| {
-        if let Some(mut entity) = world.get_entity_mut(entity) {
+    entity_command(
+        "EntityCommands::observe",
+        Location::caller(),
+        handler,
+        Some(error::ignore),
+        |mut entity| {
             entity.observe(observer);
-        }
-    }
+        },
+    )
 }
 
 #[cfg(test)]
@@ -1459,6 +1864,7 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::Component,
+        error::{self, Error, ErrorContext},
         system::{Commands, Resource},
         world::{CommandQueue, World},
     };
@@ -1689,4 +2095,77 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn missing_entity_handlers() {
+        static MISSING: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, context: ErrorContext) {
+            assert!(matches!(context, ErrorContext::Command { .. }));
+            MISSING.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let entity = world.spawn_empty().id();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands
+                .entity(entity)
+                .try_insert(W(0u32))
+                .try_remove::<W<u32>>()
+                // Removing components ignores missing entities by default.
+                .remove::<W<u32>>()
+                .clear()
+                .on_missing_entity(count)
+                .insert(W(1u32))
+                .despawn();
+        }
+        queue.apply(&mut world);
+        assert_eq!(MISSING.load(Ordering::Relaxed), 2);
+
+        world.set_entity_command_error_handler(Some(count));
+        let entity = world.spawn_empty().id();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands
+                .entity(entity)
+                .remove::<W<u32>>()
+                .try_retain::<W<u32>>()
+                .try_observe(|_: crate::observer::Trigger<crate::world::OnAdd>| {})
+                .try_despawn();
+            commands
+                .entity(entity)
+                .on_missing_entity(error::ignore)
+                .insert(W(2u32));
+        }
+        queue.apply(&mut world);
+        assert_eq!(MISSING.load(Ordering::Relaxed), 3);
+
+        // Without the handler of the world, despawning only logs a warning again.
+        world.set_entity_command_error_handler(None);
+        let entity = world.spawn_empty().id();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands.entity(entity).despawn();
+        }
+        queue.apply(&mut world);
+        assert_eq!(MISSING.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_on_missing_entity_panics() {
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let entity = world.spawn_empty().id();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands.entity(entity).insert(W(0u32));
+        }
+        queue.apply(&mut world);
+    }
 }
@@ -0,0 +1,359 @@
+use std::{any::TypeId, borrow::Cow};
+
+use super::{ReadOnlySystem, System};
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    error::{Error, ErrorContext, Result},
+    query::Access,
+    schedule::InternedSystemSet,
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
+};
+
+/// Marker for the conversion of systems returning a [`Result`] into systems that can be
+/// added to schedules or observers.
+#[doc(hidden)]
+pub struct IsFallible;
+
+/// Marker for the conversion of systems whose output is used as is, without routing errors.
+#[doc(hidden)]
+pub struct IsInfallible;
+
+/// Marker for the conversion of systems that never return, such as closures that always panic.
+///
+/// The output of a closure like `|| panic!()` could be either `()` or a [`Result`], so without this
+/// conversion, using such a closure as a system would depend on never type fallback.
+#[doc(hidden)]
+pub struct IsDiverging;
+
+/// Names the output type of a function pointer, which is used to name the never type `!`.
+#[doc(hidden)]
+pub trait FnOutput {
+    type Output;
+}
+
+impl<T> FnOutput for fn() -> T {
+    type Output = T;
+}
+
+/// The never type `!`, which can't be named directly on stable Rust.
+pub(crate) type Never = <fn() -> ! as FnOutput>::Output;
+
+/// Adapts the output of a system that never returns to `()`.
+pub(crate) fn never_returns(never: Never) {
+    match never {}
+}
+
+/// A [`System`] that runs a system returning a [`Result`] and routes its errors to the
+/// [`World`]'s current [`ErrorHandler`](crate::error::ErrorHandler).
+///
+/// Systems returning a [`Result`] are automatically wrapped in this type when they are added to a
+/// [`Schedule`](crate::schedule::Schedule) or used as an [`Observer`](crate::observer::Observer).
+/// See [the `error` module docs](crate::error) for more info.
+pub struct FallibleSystem<S> {
+    system: S,
+    context: fn(Cow<'static, str>, Tick) -> ErrorContext,
+}
+
+impl<S> FallibleSystem<S>
+where
+    S: System<Out = Result>,
+{
+    /// Wraps the given system so that its errors are reported as errors of a system.
+    pub fn new(system: S) -> Self {
+        Self {
+            system,
+            context: |name, last_run| ErrorContext::System { name, last_run },
+        }
+    }
+
+    /// Wraps the given system so that its errors are reported as errors of an observer.
+    pub fn new_observer(system: S) -> Self {
+        Self {
+            system,
+            context: |name, last_run| ErrorContext::Observer { name, last_run },
+        }
+    }
+
+    fn handle_error(&self, error: Error, world: &World) {
+        let context = (self.context)(self.system.name(), self.system.get_last_run());
+        (world.error_handler())(error, context);
+    }
+}
+
+impl<S> System for FallibleSystem<S>
+where
+    S: System<Out = Result>,
+{
+    type In = S::In;
+    type Out = ();
+
+    fn name(&self) -> Cow<'static, str> {
+        self.system.name()
+    }
+
+    fn type_id(&self) -> TypeId {
+        // Use the type of the wrapped system so that it can still be referred to through its `SystemTypeSet`.
+        self.system.type_id()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system.component_access()
+    }
+
+    #[inline]
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.system.archetype_component_access()
+    }
+
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    fn has_deferred(&self) -> bool {
+        self.system.has_deferred()
+    }
+
+    #[inline]
+    unsafe fn run_unsafe(&mut self, input: Self::In, world: UnsafeWorldCell) {
+        // SAFETY: `system.run_unsafe` has the same invariants as `self.run_unsafe`.
+        if let Err(error) = unsafe { self.system.run_unsafe(input, world) } {
+            // SAFETY: The error handler is world metadata.
+            self.handle_error(error, unsafe { world.world_metadata() });
+        }
+    }
+
+    #[inline]
+    fn run(&mut self, input: Self::In, world: &mut World) {
+        if let Err(error) = self.system.run(input, world) {
+            self.handle_error(error, world);
+        }
+    }
+
+    #[inline]
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    #[inline]
+    fn queue_deferred(&mut self, world: DeferredWorld) {
+        self.system.queue_deferred(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.system.initialize(world);
+    }
+
+    #[inline]
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
+
+// SAFETY: The inner system is read-only, and error handlers have no access to the world.
+unsafe impl<S> ReadOnlySystem for FallibleSystem<S> where S: ReadOnlySystem<Out = Result> {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    use crate::{
+        self as bevy_ecs,
+        error::{Error, ErrorContext, Result},
+        prelude::*,
+        schedule::{ExecutorKind, Schedule},
+    };
+
+    #[derive(Event)]
+    struct Ping;
+
+    #[derive(Debug)]
+    struct Failure;
+
+    impl std::fmt::Display for Failure {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failure")
+        }
+    }
+
+    impl std::error::Error for Failure {}
+
+    fn failing_system() -> Result {
+        Err(Failure.into())
+    }
+
+    #[test]
+    #[should_panic = "Encountered an error in system"]
+    fn errors_panic_by_default() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(failing_system);
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn world_error_handler() {
+        static CONTEXTS: Mutex<Vec<ErrorContext>> = Mutex::new(Vec::new());
+        fn record(error: Error, context: ErrorContext) {
+            assert!(error.is::<Failure>());
+            CONTEXTS.lock().unwrap().push(context);
+        }
+
+        let mut world = World::new();
+        world.set_error_handler(record);
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            failing_system,
+            (|| Ok(())).before(failing_system),
+            (|_: &mut World| failing_system()).after(failing_system),
+        ));
+        schedule.run(&mut world);
+
+        let contexts = CONTEXTS.lock().unwrap();
+        assert_eq!(contexts.len(), 2);
+        assert!(contexts.iter().all(|context| context.kind() == "system"));
+        assert!(contexts
+            .iter()
+            .any(|context| context.name().ends_with("failing_system")));
+    }
+
+    #[test]
+    fn schedule_error_handler_overrides_world() {
+        static SCHEDULE_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        static WORLD_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count_schedule(_: Error, _: ErrorContext) {
+            SCHEDULE_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+        fn count_world(_: Error, _: ErrorContext) {
+            WORLD_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.set_error_handler(count_world);
+        let mut schedule = Schedule::default();
+        schedule.set_error_handler(count_schedule);
+        schedule.add_systems(failing_system);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(SCHEDULE_ERRORS.load(Ordering::Relaxed), 2);
+        assert_eq!(WORLD_ERRORS.load(Ordering::Relaxed), 0);
+
+        // The override only applies while the schedule runs.
+        let id = world.register_system(failing_system);
+        world.commands().run_system(id);
+        world.flush();
+        assert_eq!(SCHEDULE_ERRORS.load(Ordering::Relaxed), 2);
+        assert_eq!(WORLD_ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn schedule_error_handler_is_restored_after_panic() {
+        static SCHEDULE_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        static WORLD_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count_schedule(_: Error, _: ErrorContext) {
+            SCHEDULE_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+        fn count_world(_: Error, _: ErrorContext) {
+            WORLD_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.set_error_handler(count_world);
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.set_error_handler(count_schedule);
+        schedule.add_systems(|| panic!("system panicked"));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            schedule.run(&mut world);
+        }));
+        assert!(result.is_err());
+
+        let id = world.register_system(failing_system);
+        world.commands().run_system(id);
+        world.flush();
+        assert_eq!(SCHEDULE_ERRORS.load(Ordering::Relaxed), 0);
+        assert_eq!(WORLD_ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn fallible_observers() {
+        static OBSERVER_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, context: ErrorContext) {
+            assert_eq!(context.kind(), "observer");
+            OBSERVER_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.set_error_handler(count);
+        world.observe(|_: Trigger<Ping>| -> Result { Err(Failure.into()) });
+        world.flush();
+        world.trigger(Ping);
+        world.trigger(Ping);
+        assert_eq!(OBSERVER_ERRORS.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn fallible_one_shot_systems() {
+        static ONE_SHOT_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, context: ErrorContext) {
+            assert_eq!(context.kind(), "system");
+            ONE_SHOT_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.set_error_handler(count);
+        let id = world.register_system(failing_system);
+
+        // The output is returned as is when running the system directly.
+        assert!(world.run_system(id).unwrap().is_err());
+        assert_eq!(ONE_SHOT_ERRORS.load(Ordering::Relaxed), 0);
+
+        world.commands().run_system(id);
+        world.flush();
+        assert_eq!(ONE_SHOT_ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn command_errors() {
+        static COMMAND_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, context: ErrorContext) {
+            assert_eq!(context.kind(), "command");
+            COMMAND_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        #[derive(Component)]
+        struct A;
+
+        let mut world = World::new();
+        world.set_error_handler(count);
+        let entity = world.spawn_empty().id();
+        let mut commands = world.commands();
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(A);
+        world.flush();
+        assert_eq!(COMMAND_ERRORS.load(Ordering::Relaxed), 1);
+    }
+}
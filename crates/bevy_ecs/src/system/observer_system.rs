@@ -1,8 +1,12 @@
 use bevy_utils::all_tuples;
 
 use crate::{
+    error::Result,
     prelude::{Bundle, Trigger},
-    system::{System, SystemParam, SystemParamFunction, SystemParamItem},
+    system::{
+        never_returns, AdapterSystem, FallibleSystem, IsDiverging, IsFallible, IsInfallible, Never,
+        System, SystemParam, SystemParamFunction, SystemParamItem,
+    },
 };
 
 use super::IntoSystem;
@@ -39,7 +43,7 @@ impl<
         Out,
         E: 'static,
         B: Bundle,
-    > IntoObserverSystem<E, B, M, Out> for S
+    > IntoObserverSystem<E, B, (IsInfallible, M), Out> for S
 where
     S::System: ObserverSystem<E, B, Out>,
 {
@@ -50,6 +54,36 @@ where
     }
 }
 
+impl<
+        S: IntoSystem<Trigger<'static, E, B>, Result, M> + Send + 'static,
+        M,
+        E: 'static,
+        B: Bundle,
+    > IntoObserverSystem<E, B, (IsFallible, M)> for S
+{
+    type System = FallibleSystem<S::System>;
+
+    fn into_system(this: Self) -> Self::System {
+        FallibleSystem::new_observer(IntoSystem::into_system(this))
+    }
+}
+
+impl<
+        S: IntoSystem<Trigger<'static, E, B>, Never, M> + Send + 'static,
+        M,
+        E: 'static,
+        B: Bundle,
+    > IntoObserverSystem<E, B, (IsDiverging, M)> for S
+{
+    type System = AdapterSystem<fn(Never), S::System>;
+
+    fn into_system(this: Self) -> Self::System {
+        let system = IntoSystem::into_system(this);
+        let name = system.name();
+        AdapterSystem::new(never_returns, system, name)
+    }
+}
+
 macro_rules! impl_system_function {
     ($($param: ident),*) => {
         #[allow(non_snake_case)]
@@ -1,10 +1,12 @@
 use crate::{
+    archetype::ArchetypeEntity,
     batching::BatchingStrategy,
     component::Tick,
-    entity::Entity,
+    entity::{Entity, EntitySet},
     query::{
         QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter, QueryManyIter,
-        QueryParIter, QuerySingleError, QueryState, ROQueryItem, ReadOnlyQueryData,
+        QueryManyUniqueIter, QueryParIter, QueryParManyIter, QuerySingleError, QueryState,
+        ROQueryItem, ReadOnlyQueryData,
     },
     world::unsafe_world_cell::UnsafeWorldCell,
 };
@@ -405,6 +407,17 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         unsafe { Query::new(self.world, new_state, self.last_run, self.this_run) }
     }
 
+    /// Returns an [`Iterator`] over the entities of the archetypes matched by this query.
+    ///
+    /// Unlike [`Query::iter`], this doesn't check the query filter, so some of the entities may not
+    /// match the query.
+    pub(crate) fn iter_archetype_entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        let archetypes = self.world.archetypes();
+        self.state
+            .matched_archetypes()
+            .flat_map(move |id| archetypes[id].entities().iter().map(ArchetypeEntity::id))
+    }
+
     /// Returns an [`Iterator`] over the read-only query items.
     ///
     /// This iterator is always guaranteed to return results from each matching entity once and only once.
@@ -658,6 +671,56 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the query items generated from a set of unique [`Entity`]s.
+    ///
+    /// Items are returned in the order of the set of entities. Entities that don't match the query are skipped.
+    ///
+    /// Since the [`EntitySet`] guarantees that each entity is yielded at most once, the returned
+    /// iterator can hand out mutable items that are alive at the same time, unlike [`iter_many_mut`].
+    /// Runtime-sized lists can be checked once with [`UniqueEntitySlice::from_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity::UniqueEntitySlice;
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// #[derive(Resource)]
+    /// struct Contacts(Vec<Entity>);
+    ///
+    /// fn system(contacts: Res<Contacts>, mut query: Query<&mut Velocity>) {
+    ///     let Ok(contacts) = UniqueEntitySlice::from_slice(&contacts.0) else {
+    ///         return;
+    ///     };
+    ///     let mut velocities: Vec<Mut<Velocity>> = query.iter_many_unique_mut(contacts).collect();
+    ///     let average = velocities.iter().map(|v| v.0).sum::<f32>() / velocities.len() as f32;
+    ///     for velocity in &mut velocities {
+    ///         velocity.0 = average;
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    ///
+    /// [`iter_many_mut`]: Self::iter_many_mut
+    /// [`UniqueEntitySlice::from_slice`]: crate::entity::UniqueEntitySlice::from_slice
+    #[inline]
+    pub fn iter_many_unique_mut<EntityList: EntitySet>(
+        &mut self,
+        entities: EntityList,
+    ) -> QueryManyUniqueIter<'_, 's, D, F, EntityList::IntoIter> {
+        // SAFETY: `self.world` has permission to access the required components.
+        unsafe {
+            self.state.iter_many_unique_unchecked_manual(
+                entities,
+                self.world,
+                self.last_run,
+                self.this_run,
+            )
+        }
+    }
+
     /// Returns an [`Iterator`] over the query items.
     ///
     /// This iterator is always guaranteed to return results from each matching entity once and only once.
@@ -809,6 +872,75 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns a parallel iterator over the read-only query items generated from an [`Entity`] list.
+    ///
+    /// Entities that don't match the query are skipped, and entities that appear more than once
+    /// in the list are visited more than once. Iteration order and thread assignment is not guaranteed.
+    ///
+    /// If the `multithreaded` feature is disabled, iterating with this operates identically to [`Iterator::for_each`]
+    /// on [`QueryManyIter`].
+    ///
+    /// This can only be called for read-only queries, see [`par_iter_many_unique_mut`] for write-queries.
+    ///
+    /// [`par_iter_many_unique_mut`]: Self::par_iter_many_unique_mut
+    #[inline]
+    pub fn par_iter_many<EntityList: IntoIterator>(
+        &self,
+        entities: EntityList,
+    ) -> QueryParManyIter<'_, '_, D::ReadOnly, F>
+    where
+        EntityList::Item: Borrow<Entity>,
+    {
+        QueryParManyIter {
+            world: self.world,
+            state: self.state.as_readonly(),
+            entity_list: entities.into_iter().map(|e| *e.borrow()).collect(),
+            last_run: self.last_run,
+            this_run: self.this_run,
+            batching_strategy: BatchingStrategy::new(),
+        }
+    }
+
+    /// Returns a parallel iterator over the query items generated from a set of unique [`Entity`]s.
+    ///
+    /// Entities that don't match the query are skipped. Iteration order and thread assignment is not guaranteed.
+    ///
+    /// If the `multithreaded` feature is disabled, iterating with this operates identically to [`Iterator::for_each`]
+    /// on [`QueryManyUniqueIter`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity::EntityHashSet;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Resource)]
+    /// struct Burning(EntityHashSet);
+    ///
+    /// fn burn_system(burning: Res<Burning>, mut query: Query<&mut Health>) {
+    ///     query.par_iter_many_unique_mut(&burning.0).for_each(|mut health| {
+    ///         health.0 = health.0.saturating_sub(1);
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(burn_system);
+    /// ```
+    #[inline]
+    pub fn par_iter_many_unique_mut<EntityList: EntitySet>(
+        &mut self,
+        entities: EntityList,
+    ) -> QueryParManyIter<'_, '_, D, F> {
+        QueryParManyIter {
+            world: self.world,
+            state: self.state,
+            entity_list: entities.into_iter().map(|e| *e.borrow()).collect(),
+            last_run: self.last_run,
+            this_run: self.this_run,
+            batching_strategy: BatchingStrategy::new(),
+        }
+    }
+
     /// Returns the read-only query item for the given [`Entity`].
     ///
     /// In case of a nonexisting entity or mismatched component, a [`QueryEntityError`] is returned instead.
@@ -978,6 +1110,7 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
     ///
     /// - [`get_many`](Self::get_many) to get read-only query items.
     /// - [`many_mut`](Self::many_mut) for the panicking version.
+    /// - [`iter_many_unique_mut`](Self::iter_many_unique_mut) for a runtime-sized set of entities.
     #[inline]
     pub fn get_many_mut<const N: usize>(
         &mut self,
@@ -1,8 +1,10 @@
 use crate::entity::Entity;
-use crate::system::{BoxedSystem, IntoSystem};
+use crate::error::{ErrorContext, IntoResult};
+use crate::system::{BoxedSystem, IntoSystem, Resource, System};
 use crate::world::{Command, World};
 use crate::{self as bevy_ecs};
 use bevy_ecs_macros::Component;
+use std::marker::PhantomData;
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -313,8 +315,115 @@ impl World {
         }
         Ok(result)
     }
+
+    /// Registers a system or returns its cached [`SystemId`].
+    ///
+    /// If you want to run the system immediately and you don't need its `SystemId`, see
+    /// [`World::run_system_cached`].
+    ///
+    /// The first time this function is called for a particular system, it will register it and
+    /// store its [`SystemId`] in a [`CachedSystemId`] resource for later. If you would rather
+    /// manage the `SystemId` yourself, or register multiple copies of the same system, use
+    /// [`World::register_system`] instead.
+    ///
+    /// # Limitations
+    ///
+    /// This function only accepts ZST (zero-sized) systems to guarantee that any two systems of
+    /// the same type must be equal. This means that closures that capture the environment, and
+    /// function pointers, are not accepted.
+    ///
+    /// If you want to access values from the environment within a system, consider passing them in
+    /// as inputs via [`World::run_system_cached_with_input`]. If that's not an option, consider
+    /// [`World::register_system`] instead.
+    pub fn register_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> SystemId<I, O> {
+        assert!(
+            std::mem::size_of::<S>() == 0,
+            "Non-ZST systems (e.g. capturing closures, function pointers) cannot be cached.",
+        );
+
+        if let Some(cached) = self.get_resource::<CachedSystemId<S::System>>() {
+            // The system may have been removed with `World::remove_system`.
+            if self.get_entity(cached.0.entity).is_some() {
+                return cached.0;
+            }
+        }
+
+        let id = self.register_system(system);
+        self.insert_resource(CachedSystemId::<S::System>(id));
+        id
+    }
+
+    /// Removes a cached system and its [`CachedSystemId`] resource.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn unregister_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        _system: S,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>> {
+        let id = self
+            .remove_resource::<CachedSystemId<S::System>>()
+            .ok_or(RegisteredSystemError::SystemNotCached)?;
+        self.remove_system(id.0)
+    }
+
+    /// Runs a cached system, registering it if necessary.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Clicks(u32);
+    ///
+    /// fn on_click(mut clicks: ResMut<Clicks>, mut calls: Local<u32>) {
+    ///     clicks.0 += 1;
+    ///     *calls += 1;
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// world.init_resource::<Clicks>();
+    ///
+    /// // The system is registered on the first call, and reused afterwards.
+    /// world.run_system_cached(on_click).unwrap();
+    /// world.run_system_cached(on_click).unwrap();
+    /// assert_eq!(world.resource::<Clicks>().0, 2);
+    /// ```
+    pub fn run_system_cached<O: 'static, M, S: IntoSystem<(), O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> Result<O, RegisteredSystemError<(), O>> {
+        self.run_system_cached_with_input(system, ())
+    }
+
+    /// Runs a cached system with an input, registering it if necessary.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached_with_input<
+        I: 'static,
+        O: 'static,
+        M,
+        S: IntoSystem<I, O, M> + 'static,
+    >(
+        &mut self,
+        system: S,
+        input: I,
+    ) -> Result<O, RegisteredSystemError<I, O>> {
+        let id = self.register_system_cached(system);
+        self.run_system_with_input(id, input)
+    }
 }
 
+/// A cached [`SystemId`] distinguished by the unique function type of its system.
+///
+/// This resource is inserted by [`World::register_system_cached`].
+#[derive(Resource)]
+pub struct CachedSystemId<S: System>(pub SystemId<S::In, S::Out>);
+
 /// The [`Command`] type for [`World::run_system`] or [`World::run_system_with_input`].
 ///
 /// This command runs systems in an exclusive and single threaded way.
@@ -326,9 +435,12 @@ impl World {
 /// There is no way to get the output of a system when run as a command, because the
 /// execution of the system happens later. To get the output of a system, use
 /// [`World::run_system`] or [`World::run_system_with_input`] instead of running the system as a command.
+///
+/// Systems returning a [`Result`](crate::error::Result) can be run as well: their errors are routed to the
+/// [`World`]'s current [`ErrorHandler`](crate::error::ErrorHandler).
 #[derive(Debug, Clone)]
-pub struct RunSystemWithInput<I: 'static> {
-    system_id: SystemId<I>,
+pub struct RunSystemWithInput<I: 'static, O: 'static = ()> {
+    system_id: SystemId<I, O>,
     input: I,
 }
 
@@ -352,18 +464,34 @@ impl RunSystem {
     }
 }
 
-impl<I: 'static> RunSystemWithInput<I> {
+impl<I: 'static, O: 'static> RunSystemWithInput<I, O> {
     /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
     /// in order to run the specified system with the provided [`In<_>`](crate::system::In) input value.
-    pub fn new_with_input(system_id: SystemId<I>, input: I) -> Self {
+    pub fn new_with_input(system_id: SystemId<I, O>, input: I) -> Self {
         Self { system_id, input }
     }
 }
 
-impl<I: 'static + Send> Command for RunSystemWithInput<I> {
+impl<I: 'static + Send, O: IntoResult + 'static + Send> Command for RunSystemWithInput<I, O> {
     #[inline]
     fn apply(self, world: &mut World) {
-        let _ = world.run_system_with_input(self.system_id, self.input);
+        let Ok(Err(error)) = world
+            .run_system_with_input(self.system_id, self.input)
+            .map(IntoResult::into_result)
+        else {
+            return;
+        };
+        let context = match world.get::<RegisteredSystem<I, O>>(self.system_id.entity) {
+            Some(registered) => ErrorContext::System {
+                name: registered.system.name(),
+                last_run: registered.system.get_last_run(),
+            },
+            // The system removed itself from the world.
+            None => ErrorContext::Command {
+                name: std::any::type_name::<Self>().into(),
+            },
+        };
+        (world.error_handler())(error, context);
     }
 }
 
@@ -396,6 +524,93 @@ impl<I: 'static + Send, O: 'static + Send> Command for RegisterSystem<I, O> {
     }
 }
 
+/// The [`Command`] type for [`World::run_system_cached`] or [`World::run_system_cached_with_input`].
+///
+/// This command registers the system the first time it is applied, and reuses it afterwards.
+/// See [`World::register_system_cached`] for more information.
+///
+/// Systems returning a [`Result`](crate::error::Result) can be run as well: their errors are routed to the
+/// [`World`]'s current [`ErrorHandler`](crate::error::ErrorHandler).
+pub struct RunSystemCachedWithInput<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M>,
+{
+    system: S,
+    input: I,
+    _marker: PhantomData<fn() -> (O, M)>,
+}
+
+impl<S, I, O, M> RunSystemCachedWithInput<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M>,
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    /// in order to run the specified cached system with the provided [`In<_>`](crate::system::In) input value.
+    pub fn new_with_input(system: S, input: I) -> Self {
+        Self {
+            system,
+            input,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, I, O, M> Command for RunSystemCachedWithInput<S, I, O, M>
+where
+    I: 'static + Send,
+    O: IntoResult + 'static + Send,
+    M: 'static,
+    S: IntoSystem<I, O, M> + Send + 'static,
+{
+    fn apply(self, world: &mut World) {
+        let id = world.register_system_cached(self.system);
+        RunSystemWithInput::new_with_input(id, self.input).apply(world);
+    }
+}
+
+/// The [`Command`] type for [`World::unregister_system_cached`].
+pub struct UnregisterSystemCached<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M>,
+{
+    system: S,
+    _marker: PhantomData<fn(I) -> (O, M)>,
+}
+
+impl<S, I, O, M> UnregisterSystemCached<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M>,
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    /// in order to remove the specified cached system.
+    pub fn new(system: S) -> Self {
+        Self {
+            system,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, I, O, M> Command for UnregisterSystemCached<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    M: 'static,
+    S: IntoSystem<I, O, M> + Send + 'static,
+{
+    fn apply(self, world: &mut World) {
+        let _ = world.unregister_system_cached(self.system);
+    }
+}
+
 /// An operation with stored systems failed.
 #[derive(Error)]
 pub enum RegisteredSystemError<I = (), O = ()> {
@@ -404,6 +619,11 @@ pub enum RegisteredSystemError<I = (), O = ()> {
     /// Did you forget to register it?
     #[error("System {0:?} was not registered")]
     SystemIdNotRegistered(SystemId<I, O>),
+    /// A cached system was removed by value, but no system with its type was found.
+    ///
+    /// Did you forget to register it?
+    #[error("Cached system was not found")]
+    SystemNotCached,
     /// A system tried to run itself recursively.
     #[error("System {0:?} tried to run itself recursively")]
     Recursive(SystemId<I, O>),
@@ -418,6 +638,7 @@ impl<I, O> std::fmt::Debug for RegisteredSystemError<I, O> {
             Self::SystemIdNotRegistered(arg0) => {
                 f.debug_tuple("SystemIdNotRegistered").field(arg0).finish()
             }
+            Self::SystemNotCached => write!(f, "SystemNotCached"),
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
         }
@@ -618,4 +839,72 @@ mod tests {
         let _ = world.run_system(nested_id);
         assert_eq!(*world.resource::<Counter>(), Counter(5));
     }
+
+    #[test]
+    fn cached_system() {
+        use crate::system::RegisteredSystemError;
+
+        fn four() -> i32 {
+            4
+        }
+
+        let mut world = World::new();
+        let old = world.register_system_cached(four);
+        let new = world.register_system_cached(four);
+        assert_eq!(old, new);
+
+        let result = world.unregister_system_cached(four);
+        assert!(result.is_ok());
+        let new = world.register_system_cached(four);
+        assert_ne!(old, new);
+
+        let output = world.run_system(old);
+        assert!(matches!(
+            output,
+            Err(RegisteredSystemError::SystemIdNotRegistered(x)) if x == old,
+        ));
+        let output = world.run_system(new);
+        assert!(matches!(output, Ok(x) if x == four()));
+        let output = world.run_system_cached(four);
+        assert!(matches!(output, Ok(x) if x == four()));
+        let output = world.run_system_cached_with_input(four, ());
+        assert!(matches!(output, Ok(x) if x == four()));
+        world.unregister_system_cached(four).unwrap();
+        assert!(matches!(
+            world.unregister_system_cached(four),
+            Err(RegisteredSystemError::SystemNotCached)
+        ));
+    }
+
+    #[test]
+    fn cached_system_keeps_state() {
+        fn doubling(In(amount): In<u8>, mut last: Local<u8>, mut counter: ResMut<Counter>) {
+            *last = last.max(1) * amount;
+            counter.0 = *last;
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        world.run_system_cached_with_input(doubling, 2).unwrap();
+        world.run_system_cached_with_input(doubling, 2).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+
+        world.commands().run_system_cached_with_input(doubling, 3);
+        world.flush();
+        assert_eq!(*world.resource::<Counter>(), Counter(12));
+
+        // Unregistering the system drops its state.
+        world.commands().unregister_system_cached(doubling);
+        world.flush();
+        world.run_system_cached_with_input(doubling, 2).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    #[should_panic = "Non-ZST systems"]
+    fn cached_system_rejects_captures() {
+        let mut world = World::new();
+        let value = 1;
+        world.register_system_cached(move || value);
+    }
 }
@@ -1,8 +1,14 @@
 //! A trait for components that let you traverse the ECS.
 
+use std::{collections::VecDeque, marker::PhantomData};
+
+use smallvec::SmallVec;
+
 use crate::{
-    component::{Component, StorageType},
-    entity::Entity,
+    component::{Component, Mutable, StorageType},
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    query::{QueryData, QueryFilter, WorldQuery},
+    system::Query,
 };
 
 /// A component that can point to another entity, and which can be used to define a path through the ECS.
@@ -17,6 +23,8 @@ use crate::{
 /// for documenting possible looping behavior, and consumers of those implementations are responsible for
 /// avoiding infinite loops in their code.
 ///
+/// To walk a traversal from a system, use the methods of [`TraversalQueryExt`], which stop at the first loop.
+///
 /// [specify the direction]: crate::event::Event::Traversal
 /// [event propagation]: crate::observer::Trigger::propagate
 /// [observers]: crate::observer::Observer
@@ -40,4 +48,361 @@ impl Traversal for TraverseNone {
 
 impl Component for TraverseNone {
     const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
+}
+
+/// An extension trait for [`Query`] that adds methods to walk any [`Traversal`] component.
+///
+/// This generalizes the hierarchy queries of `bevy_hierarchy` to custom links between entities,
+/// such as the edges of a dialog tree. Unlike following [`Traversal::traverse`] by hand, the
+/// iterators stop when they meet an entity they have already visited, so cycles cannot cause
+/// infinite loops. They are lazy, so they can be exited early with any [`Iterator`] adapter.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::traversal::{Traversal, TraversalQueryExt};
+/// #[derive(Component)]
+/// struct NextLine(Entity);
+///
+/// impl Traversal for NextLine {
+///     fn traverse(&self) -> Option<Entity> {
+///         Some(self.0)
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Choice;
+///
+/// fn next_choice(
+///     line: Entity,
+///     next_lines: Query<&NextLine>,
+///     choices: Query<(), With<Choice>>,
+/// ) -> Option<Entity> {
+///     next_lines
+///         .iter_traversal_ancestors(line)
+///         .find(|&line| choices.contains(line))
+/// }
+/// ```
+pub trait TraversalQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
+    /// Returns an [`Iterator`] of [`Entity`]s over the entities reached by following the [`Traversal`]
+    /// from `entity`, excluding `entity` itself.
+    ///
+    /// Can only be called on a [`Query`] of a [`Traversal`] component (i.e. `Query<&T>`). Stops at the
+    /// first entity that is not matched by the query, or that was already visited.
+    fn iter_traversal_ancestors<T: Traversal>(
+        &'w self,
+        entity: Entity,
+    ) -> TraversalAncestorIter<'w, 's, D, F, T>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>;
+
+    /// Returns an [`Iterator`] of [`Entity`]s over the entities whose [`Traversal`] leads to `entity`,
+    /// excluding `entity` itself.
+    ///
+    /// Can only be called on a [`Query`] of a [`Traversal`] component (i.e. `Query<&T>`).
+    /// Only the entities matched by the query are visited.
+    ///
+    /// Traverses the entities breadth-first. Since a [`Traversal`] only points from an entity to the next one,
+    /// this looks up the [`Traversal`] of every entity of the query once when called.
+    fn iter_traversal_descendants<T: Traversal>(
+        &'w self,
+        entity: Entity,
+    ) -> TraversalDescendantIter<'w, 's, D, F, T>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>;
+
+    /// Returns the last entity reached by following the [`Traversal`] from `entity`,
+    /// or `entity` if its [`Traversal`] doesn't lead anywhere.
+    ///
+    /// Can only be called on a [`Query`] of a [`Traversal`] component (i.e. `Query<&T>`).
+    /// If the [`Traversal`] loops, returns the last entity before the loop closes.
+    fn traversal_root<T: Traversal>(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>;
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> TraversalQueryExt<'w, 's, D, F> for Query<'w, 's, D, F> {
+    fn iter_traversal_ancestors<T: Traversal>(
+        &'w self,
+        entity: Entity,
+    ) -> TraversalAncestorIter<'w, 's, D, F, T>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+    {
+        TraversalAncestorIter::new(self, entity)
+    }
+
+    fn iter_traversal_descendants<T: Traversal>(
+        &'w self,
+        entity: Entity,
+    ) -> TraversalDescendantIter<'w, 's, D, F, T>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+    {
+        TraversalDescendantIter::new(self, entity)
+    }
+
+    fn traversal_root<T: Traversal>(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+    {
+        self.iter_traversal_ancestors(entity)
+            .last()
+            .unwrap_or(entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s reached by following a [`Traversal`].
+///
+/// Created with [`TraversalQueryExt::iter_traversal_ancestors`].
+pub struct TraversalAncestorIter<'w, 's, D: QueryData, F: QueryFilter, T: Traversal>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    query: &'w Query<'w, 's, D, F>,
+    next: Option<Entity>,
+    visited: EntityHashSet,
+    cycle: Option<Entity>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, T: Traversal> TraversalAncestorIter<'w, 's, D, F, T>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    /// Returns a new [`TraversalAncestorIter`].
+    pub fn new(query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        TraversalAncestorIter {
+            query,
+            next: Some(entity),
+            visited: EntityHashSet::from_iter([entity]),
+            cycle: None,
+        }
+    }
+
+    /// Returns the entity that was reached a second time, if the iterator stopped because of a cycle.
+    pub fn detected_cycle(&self) -> Option<Entity> {
+        self.cycle
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, T: Traversal> Iterator
+    for TraversalAncestorIter<'w, 's, D, F, T>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.query.get(self.next?).ok().and_then(T::traverse);
+        self.next = next.filter(|&next| {
+            let is_new = self.visited.insert(next);
+            if !is_new {
+                self.cycle = Some(next);
+            }
+            is_new
+        });
+        self.next
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s whose [`Traversal`] leads to an [`Entity`].
+///
+/// Created with [`TraversalQueryExt::iter_traversal_descendants`]. Traverses the entities breadth-first.
+pub struct TraversalDescendantIter<'w, 's, D: QueryData, F: QueryFilter, T: Traversal>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    sources: EntityHashMap<SmallVec<[Entity; 4]>>,
+    queue: VecDeque<Entity>,
+    visited: EntityHashSet,
+    cycle: Option<Entity>,
+    marker: PhantomData<&'w Query<'w, 's, D, F>>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, T: Traversal> TraversalDescendantIter<'w, 's, D, F, T>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    /// Returns a new [`TraversalDescendantIter`].
+    pub fn new(query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        let mut sources = EntityHashMap::<SmallVec<[Entity; 4]>>::default();
+        for source in query.iter_archetype_entities() {
+            if let Some(target) = query.get(source).ok().and_then(T::traverse) {
+                sources.entry(target).or_default().push(source);
+            }
+        }
+
+        TraversalDescendantIter {
+            queue: sources.remove(&entity).into_iter().flatten().collect(),
+            sources,
+            visited: EntityHashSet::from_iter([entity]),
+            cycle: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the entity that was reached a second time, if the iterator met a cycle.
+    ///
+    /// The entities of the cycle are only visited once.
+    pub fn detected_cycle(&self) -> Option<Entity> {
+        self.cycle
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, T: Traversal> Iterator
+    for TraversalDescendantIter<'w, 's, D, F, T>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w T>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entity = self.queue.pop_front()?;
+            if !self.visited.insert(entity) {
+                self.cycle = Some(entity);
+                continue;
+            }
+
+            if let Some(sources) = self.sources.remove(&entity) {
+                self.queue.extend(sources);
+            }
+            return Some(entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Traversal, TraversalQueryExt};
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::system::SystemState;
+
+    #[derive(Component)]
+    struct Link(Entity);
+
+    impl Traversal for Link {
+        fn traverse(&self) -> Option<Entity> {
+            Some(self.0)
+        }
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn traversal_ancestors() {
+        let mut world = World::new();
+        let [a, b, c, d] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(b).insert(Link(a));
+        world.entity_mut(c).insert((Link(b), Marker));
+        world.entity_mut(d).insert(Link(c));
+
+        let mut system_state =
+            SystemState::<(Query<&Link>, Query<&Link, Without<Marker>>)>::new(&mut world);
+        let (links, unmarked_links) = system_state.get(&world);
+
+        let mut ancestors = links.iter_traversal_ancestors(d);
+        assert_eq!(vec![c, b, a], ancestors.by_ref().collect::<Vec<_>>());
+        assert_eq!(None, ancestors.detected_cycle());
+        assert_eq!(a, links.traversal_root(d));
+        assert_eq!(a, links.traversal_root(a));
+
+        // The walk stops at entities that are not matched by the query.
+        assert_eq!(
+            vec![c],
+            unmarked_links
+                .iter_traversal_ancestors(d)
+                .collect::<Vec<_>>()
+        );
+
+        // Early exit.
+        assert_eq!(
+            Some(b),
+            links
+                .iter_traversal_ancestors(d)
+                .find(|&entity| entity != c)
+        );
+    }
+
+    #[test]
+    fn traversal_ancestors_cycle() {
+        let mut world = World::new();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).insert(Link(b));
+        world.entity_mut(b).insert(Link(c));
+        world.entity_mut(c).insert(Link(a));
+        let self_loop = world.spawn_empty().id();
+        world.entity_mut(self_loop).insert(Link(self_loop));
+
+        let mut system_state = SystemState::<Query<&Link>>::new(&mut world);
+        let links = system_state.get(&world);
+
+        let mut ancestors = links.iter_traversal_ancestors(a);
+        assert_eq!(vec![b, c], ancestors.by_ref().collect::<Vec<_>>());
+        assert_eq!(Some(a), ancestors.detected_cycle());
+        assert_eq!(c, links.traversal_root(a));
+
+        let mut ancestors = links.iter_traversal_ancestors(self_loop);
+        assert_eq!(None, ancestors.next());
+        assert_eq!(Some(self_loop), ancestors.detected_cycle());
+    }
+
+    #[test]
+    fn traversal_descendants() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let a = world.spawn(Link(root)).id();
+        let b = world.spawn((Link(root), Marker)).id();
+        let c = world.spawn(Link(a)).id();
+        let d = world.spawn(Link(b)).id();
+        let e = world.spawn(Link(d)).id();
+
+        let mut system_state =
+            SystemState::<(Query<&Link>, Query<&Link, Without<Marker>>)>::new(&mut world);
+        let (links, unmarked_links) = system_state.get(&world);
+
+        let mut descendants = links.iter_traversal_descendants(root);
+        let first_level = [descendants.next().unwrap(), descendants.next().unwrap()];
+        assert!(first_level.contains(&a) && first_level.contains(&b));
+        let mut rest = descendants.by_ref().collect::<Vec<_>>();
+        assert_eq!(e, rest.pop().unwrap());
+        rest.sort();
+        assert_eq!(vec![c, d], rest);
+        assert_eq!(None, descendants.detected_cycle());
+
+        assert_eq!(
+            vec![e],
+            links.iter_traversal_descendants(d).collect::<Vec<_>>()
+        );
+        assert_eq!(0, links.iter_traversal_descendants(e).count());
+
+        // Entities that are not matched by the query are not visited, nor their descendants.
+        let mut unmarked = unmarked_links
+            .iter_traversal_descendants(root)
+            .collect::<Vec<_>>();
+        unmarked.sort();
+        assert_eq!(vec![a, c], unmarked);
+    }
+
+    #[test]
+    fn traversal_descendants_cycle() {
+        let mut world = World::new();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).insert(Link(c));
+        world.entity_mut(b).insert(Link(a));
+        world.entity_mut(c).insert(Link(b));
+        let d = world.spawn(Link(b)).id();
+
+        let mut system_state = SystemState::<Query<&Link>>::new(&mut world);
+        let links = system_state.get(&world);
+
+        let mut descendants = links.iter_traversal_descendants(a);
+        assert_eq!(b, descendants.next().unwrap());
+        let mut rest = descendants.by_ref().collect::<Vec<_>>();
+        rest.sort();
+        assert_eq!(vec![c, d], rest);
+        assert_eq!(Some(a), descendants.detected_cycle());
+    }
 }
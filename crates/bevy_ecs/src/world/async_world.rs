@@ -0,0 +1,389 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+use concurrent_queue::ConcurrentQueue;
+
+use crate::{
+    self as bevy_ecs,
+    bundle::Bundle,
+    component::Component,
+    entity::Entity,
+    event::{Event, EventId, Events},
+    system::Resource,
+    world::{Command, CommandQueue, World},
+};
+
+/// A handle to a [`World`] that can be used from async tasks, e.g. tasks spawned on the
+/// [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool).
+///
+/// Every access is sent as a [`Command`] to the world, and applied at its next sync point,
+/// when [`World::apply_async_commands`] runs (once per frame in apps using the `TaskPoolPlugin`).
+/// The returned futures complete once the access has been applied, so awaiting them also waits
+/// for the next sync point: loading sequences, cutscenes or network flows can be written
+/// as plain async code instead of state machines polled by systems.
+///
+/// This handle is a [`Resource`], and can be cloned out of the world with [`World::async_world`]
+/// or `Res<AsyncWorld>`. The futures it returns are `'static`, so they can be moved into tasks freely.
+///
+/// If the [`World`] is dropped before an access is applied, its future never completes.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::{system::RunSystemOnce, world::AsyncWorld};
+/// # use bevy_tasks::{AsyncComputeTaskPool, TaskPool};
+/// #[derive(Component)]
+/// struct Dialogue(&'static str);
+///
+/// #[derive(Event, Clone)]
+/// struct Skip;
+///
+/// fn start_cutscene(async_world: Res<AsyncWorld>) {
+///     let world = async_world.clone();
+///     AsyncComputeTaskPool::get()
+///         .spawn(async move {
+///             let line = world.spawn(Dialogue("Welcome!")).await;
+///             // Wait for the player to skip the line, then replace it.
+///             world.next_event::<Skip>().await;
+///             world.insert(line, Dialogue("Let's go.")).await;
+///             world.next_frame().await;
+///             world.despawn(line).await;
+///         })
+///         .detach();
+/// }
+/// # AsyncComputeTaskPool::get_or_init(TaskPool::new);
+/// # let mut world = World::new();
+/// # world.init_resource::<Events<Skip>>();
+/// # world.init_resource::<AsyncWorld>();
+/// # world.run_system_once(start_cutscene);
+/// ```
+#[derive(Resource, Clone)]
+pub struct AsyncWorld {
+    commands: Arc<ConcurrentQueue<CommandQueue>>,
+}
+
+impl Default for AsyncWorld {
+    fn default() -> Self {
+        Self {
+            commands: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+}
+
+impl AsyncWorld {
+    /// Sends a [`Command`] to be applied to the world at its next sync point, without waiting for it.
+    pub fn queue(&self, command: impl Command) {
+        let mut commands = CommandQueue::default();
+        commands.push(command);
+        self.append(commands);
+    }
+
+    /// Sends a [`CommandQueue`] to be applied to the world at its next sync point, without waiting for it.
+    pub fn append(&self, commands: CommandQueue) {
+        // The queue is unbounded and never closed, so this cannot fail.
+        let _ = self.commands.push(commands);
+    }
+
+    /// Runs `f` on the world at its next sync point, and returns a future resolving to its output.
+    ///
+    /// All other methods of this type are built on it.
+    pub fn run<R, F>(&self, f: F) -> impl Future<Output = R> + Send + 'static
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let reply = Arc::new(Mutex::new(Reply {
+            value: None,
+            waker: None,
+        }));
+        let sender = reply.clone();
+        self.queue(move |world: &mut World| {
+            let value = f(world);
+            let mut reply = sender.lock().unwrap_or_else(PoisonError::into_inner);
+            reply.value = Some(value);
+            if let Some(waker) = reply.waker.take() {
+                waker.wake();
+            }
+        });
+        ReplyFuture(reply)
+    }
+
+    /// Waits for the next sync point of the world.
+    pub fn next_frame(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.run(|_| ())
+    }
+
+    /// Waits for the next [`Event`] of type `E` sent after this method was called, and returns a copy of it.
+    ///
+    /// The events are checked once per sync point.
+    ///
+    /// # Panics
+    ///
+    /// The world will panic if the [`Events<E>`] resource does not exist.
+    pub fn next_event<E: Event + Clone>(&self) -> impl Future<Output = E> + Send + 'static {
+        let world = self.clone();
+        async move {
+            let mut cursor = world
+                .run(|world| world.resource::<Events<E>>().get_cursor_current())
+                .await;
+            loop {
+                let (event, returned_cursor) = world
+                    .run(move |world| {
+                        let event = cursor.read(world.resource::<Events<E>>()).next().cloned();
+                        (event, cursor)
+                    })
+                    .await;
+                if let Some(event) = event {
+                    return event;
+                }
+                cursor = returned_cursor;
+            }
+        }
+    }
+
+    /// Spawns a new entity with the given `bundle` and returns its id.
+    pub fn spawn<B: Bundle>(&self, bundle: B) -> impl Future<Output = Entity> + Send + 'static {
+        self.run(move |world| world.spawn(bundle).id())
+    }
+
+    /// Despawns the `entity`. Returns `false` if the entity did not exist.
+    pub fn despawn(&self, entity: Entity) -> impl Future<Output = bool> + Send + 'static {
+        self.run(move |world| world.despawn(entity))
+    }
+
+    /// Inserts the `bundle` on the `entity`. Returns `false` if the entity did not exist.
+    pub fn insert<B: Bundle>(
+        &self,
+        entity: Entity,
+        bundle: B,
+    ) -> impl Future<Output = bool> + Send + 'static {
+        self.run(move |world| {
+            world
+                .get_entity_mut(entity)
+                .map(|mut entity| {
+                    entity.insert(bundle);
+                })
+                .is_some()
+        })
+    }
+
+    /// Removes the bundle `B` from the `entity` and returns it, if the entity had all of its components.
+    pub fn take<B: Bundle>(
+        &self,
+        entity: Entity,
+    ) -> impl Future<Output = Option<B>> + Send + 'static {
+        self.run(move |world| world.get_entity_mut(entity)?.take::<B>())
+    }
+
+    /// Returns a clone of the component `C` of the `entity`, if it exists.
+    pub fn get<C: Component + Clone>(
+        &self,
+        entity: Entity,
+    ) -> impl Future<Output = Option<C>> + Send + 'static {
+        self.run(move |world| world.get::<C>(entity).cloned())
+    }
+
+    /// Returns a clone of the resource `R`, if it exists.
+    pub fn get_resource<R: Resource + Clone>(
+        &self,
+    ) -> impl Future<Output = Option<R>> + Send + 'static {
+        self.run(|world| world.get_resource::<R>().cloned())
+    }
+
+    /// Inserts the resource `value`, replacing any existing resource of the same type.
+    pub fn insert_resource<R: Resource>(
+        &self,
+        value: R,
+    ) -> impl Future<Output = ()> + Send + 'static {
+        self.run(move |world| world.insert_resource(value))
+    }
+
+    /// Removes the resource `R` and returns it, if it exists.
+    pub fn remove_resource<R: Resource>(&self) -> impl Future<Output = Option<R>> + Send + 'static {
+        self.run(World::remove_resource::<R>)
+    }
+
+    /// Sends an [`Event`]. Returns its id, or `None` if the [`Events<E>`] resource does not exist.
+    pub fn send_event<E: Event>(
+        &self,
+        event: E,
+    ) -> impl Future<Output = Option<EventId<E>>> + Send + 'static {
+        self.run(move |world| world.send_event(event))
+    }
+}
+
+/// The output of a command sent by an [`AsyncWorld`], and the waker of the task awaiting it.
+struct Reply<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+struct ReplyFuture<T>(Arc<Mutex<Reply<T>>>);
+
+impl<T> Future for ReplyFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut reply = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match reply.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                reply.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl World {
+    /// Returns a handle to this world that can be used from async tasks.
+    /// See [`AsyncWorld`] for more info.
+    pub fn async_world(&mut self) -> AsyncWorld {
+        self.get_resource_or_insert_with(AsyncWorld::default)
+            .clone()
+    }
+
+    /// Applies the commands sent by the [`AsyncWorld`] handles of this world, and wakes up
+    /// the tasks waiting for them.
+    ///
+    /// Only the commands sent before this method is called are applied, so that tasks woken up
+    /// by them cannot run ahead of the world by more than one sync point.
+    pub fn apply_async_commands(&mut self) {
+        let Some(async_world) = self.get_resource::<AsyncWorld>() else {
+            return;
+        };
+        let commands = async_world.commands.clone();
+        let len = commands.len();
+        for mut queue in commands.try_iter().take(len) {
+            queue.apply(self);
+        }
+    }
+}
+
+/// A system that applies the commands sent by [`AsyncWorld`] handles.
+///
+/// See [`World::apply_async_commands`].
+pub fn apply_async_commands(world: &mut World) {
+    world.apply_async_commands();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+
+    use bevy_tasks::{block_on, poll_once};
+
+    use super::AsyncWorld;
+    use crate::{self as bevy_ecs, prelude::*, world::CommandQueue};
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(i32);
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Score(u32);
+
+    #[derive(Event, Clone, Debug, PartialEq)]
+    struct Ping(u32);
+
+    /// Polls `future` once between each sync point of the `world`, and returns its output
+    /// along with the number of sync points it took.
+    fn run_until_complete<T>(world: &mut World, future: impl Future<Output = T>) -> (T, usize) {
+        let mut future = Box::pin(future);
+        let mut sync_points = 0;
+        loop {
+            if let Some(output) = block_on(poll_once(&mut future)) {
+                return (output, sync_points);
+            }
+            assert!(sync_points < 100, "the future did not complete");
+            world.apply_async_commands();
+            sync_points += 1;
+        }
+    }
+
+    #[test]
+    fn async_world_access() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+
+        let (entity, sync_points) = run_until_complete(&mut world, {
+            let world = async_world.clone();
+            async move {
+                let entity = world.spawn(Position(0)).await;
+                world.insert(entity, Position(1)).await;
+                assert_eq!(world.get::<Position>(entity).await, Some(Position(1)));
+                world.insert_resource(Score(3)).await;
+                assert_eq!(world.get_resource::<Score>().await, Some(Score(3)));
+                entity
+            }
+        });
+        assert_eq!(sync_points, 5);
+        assert_eq!(world.get::<Position>(entity), Some(&Position(1)));
+
+        let (removed, _) = run_until_complete(&mut world, {
+            let world = async_world.clone();
+            async move {
+                let position = world.take::<Position>(entity).await;
+                assert!(world.despawn(entity).await);
+                assert!(!world.despawn(entity).await);
+                (position, world.remove_resource::<Score>().await)
+            }
+        });
+        assert_eq!(removed, (Some(Position(1)), Some(Score(3))));
+        assert!(world.get_entity(entity).is_none());
+    }
+
+    #[test]
+    fn async_world_commands() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+
+        async_world.queue(|world: &mut World| world.insert_resource(Score(1)));
+        assert!(!world.contains_resource::<Score>());
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Score>(), &Score(1));
+
+        let mut commands = CommandQueue::default();
+        commands.push(|world: &mut World| world.resource_mut::<Score>().0 += 1);
+        commands.push(|world: &mut World| world.resource_mut::<Score>().0 *= 10);
+        async_world.append(commands);
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Score>(), &Score(20));
+    }
+
+    #[test]
+    fn async_world_waits_for_frames_and_events() {
+        let mut world = World::new();
+        world.init_resource::<Events<Ping>>();
+        let async_world = world.async_world();
+
+        let (_, sync_points) = run_until_complete(&mut world, {
+            let world = async_world.clone();
+            async move {
+                for _ in 0..3 {
+                    world.next_frame().await;
+                }
+            }
+        });
+        assert_eq!(sync_points, 3);
+
+        let mut future = Box::pin(async_world.next_event::<Ping>());
+        for _ in 0..3 {
+            assert!(block_on(poll_once(&mut future)).is_none());
+            world.apply_async_commands();
+        }
+        world.send_event(Ping(7));
+        assert!(block_on(poll_once(&mut future)).is_none());
+        world.apply_async_commands();
+        assert_eq!(block_on(poll_once(&mut future)), Some(Ping(7)));
+    }
+
+    #[test]
+    fn async_world_without_handles() {
+        let mut world = World::new();
+        world.apply_async_commands();
+        assert!(!world.contains_resource::<AsyncWorld>());
+    }
+}
@@ -13,6 +13,8 @@ pub const ON_INSERT: ComponentId = ComponentId::new(1);
 pub const ON_REPLACE: ComponentId = ComponentId::new(2);
 /// [`ComponentId`] for [`OnRemove`]
 pub const ON_REMOVE: ComponentId = ComponentId::new(3);
+/// [`ComponentId`] for [`OnDespawn`]
+pub const ON_DESPAWN: ComponentId = ComponentId::new(4);
 
 /// Trigger emitted when a component is added to an entity. See [`crate::component::ComponentHooks::on_add`]
 /// for more information.
@@ -36,3 +38,15 @@ pub struct OnReplace;
 #[derive(Event)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub struct OnRemove;
+
+/// Trigger emitted when an entity is despawned, before any of its components are removed.
+///
+/// Unlike [`OnRemove`], observers of this trigger can still access every component of the entity,
+/// which makes it the right place for cleanup logic that needs the whole entity. Observers watching
+/// the despawned entity itself, like the ones added with [`EntityWorldMut::observe`], also run.
+///
+/// Observers targeting components run if the entity has any of them: `Trigger<OnDespawn, Collider>`
+/// runs for every despawned entity with a `Collider`.
+#[derive(Event)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct OnDespawn;
@@ -2,7 +2,7 @@ use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
     bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle},
     change_detection::MutUntyped,
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    component::{Component, ComponentId, ComponentTicks, Components, Mutable, StorageType},
     entity::{Entities, Entity, EntityLocation},
     event::Event,
     observer::{Observer, Observers},
@@ -16,7 +16,7 @@ use bevy_ptr::{OwningPtr, Ptr};
 use std::{any::TypeId, marker::PhantomData};
 use thiserror::Error;
 
-use super::{unsafe_world_cell::UnsafeEntityCell, Ref, ON_REMOVE, ON_REPLACE};
+use super::{unsafe_world_cell::UnsafeEntityCell, Ref, ON_DESPAWN, ON_REMOVE, ON_REPLACE};
 
 /// A read-only reference to a particular [`Entity`] and all of its components.
 ///
@@ -384,7 +384,7 @@ impl<'w> EntityMut<'w> {
     /// Gets mutable access to the component of type `T` for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn get_mut<T: Component>(&mut self) -> Option<Mut<'_, T>> {
+    pub fn get_mut<T: Component<Mutability = Mutable>>(&mut self) -> Option<Mut<'_, T>> {
         // SAFETY: &mut self implies exclusive access for duration of returned value
         unsafe { self.0.get_mut() }
     }
@@ -393,11 +393,43 @@ impl<'w> EntityMut<'w> {
     /// with the world `'w` lifetime for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn into_mut<T: Component>(self) -> Option<Mut<'w, T>> {
+    pub fn into_mut<T: Component<Mutability = Mutable>>(self) -> Option<Mut<'w, T>> {
         // SAFETY: consuming `self` implies exclusive access
         unsafe { self.0.get_mut() }
     }
 
+    /// Gets mutable access to the component of type `T` for the current entity, even if it is
+    /// [immutable](Component#immutable-components).
+    /// Returns `None` if the entity does not have a component of type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be mutable, or the returned reference must not be used to change its value in a way
+    /// that its hooks and observers need to know about.
+    #[inline]
+    pub unsafe fn get_mut_assume_mutable<T: Component>(&mut self) -> Option<Mut<'_, T>> {
+        // SAFETY:
+        // - &mut self implies exclusive access for duration of returned value
+        // - the caller ensures that the component can be mutated
+        unsafe { self.0.get_mut_assume_mutable() }
+    }
+
+    /// Consumes self and gets mutable access to the component of type `T` with the world `'w` lifetime,
+    /// even if it is [immutable](Component#immutable-components).
+    /// Returns `None` if the entity does not have a component of type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be mutable, or the returned reference must not be used to change its value in a way
+    /// that its hooks and observers need to know about.
+    #[inline]
+    pub unsafe fn into_mut_assume_mutable<T: Component>(self) -> Option<Mut<'w, T>> {
+        // SAFETY:
+        // - consuming `self` implies exclusive access
+        // - the caller ensures that the component can be mutated
+        unsafe { self.0.get_mut_assume_mutable() }
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     #[inline]
@@ -679,7 +711,7 @@ impl<'w> EntityWorldMut<'w> {
     /// Gets mutable access to the component of type `T` for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn get_mut<T: Component>(&mut self) -> Option<Mut<'_, T>> {
+    pub fn get_mut<T: Component<Mutability = Mutable>>(&mut self) -> Option<Mut<'_, T>> {
         // SAFETY: &mut self implies exclusive access for duration of returned value
         unsafe { self.as_unsafe_entity_cell().get_mut() }
     }
@@ -688,11 +720,27 @@ impl<'w> EntityWorldMut<'w> {
     /// with the world `'w` lifetime for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn into_mut<T: Component>(self) -> Option<Mut<'w, T>> {
+    pub fn into_mut<T: Component<Mutability = Mutable>>(self) -> Option<Mut<'w, T>> {
         // SAFETY: consuming `self` implies exclusive access
         unsafe { self.into_unsafe_entity_cell().get_mut() }
     }
 
+    /// Gets mutable access to the component of type `T` for the current entity, even if it is
+    /// [immutable](Component#immutable-components).
+    /// Returns `None` if the entity does not have a component of type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be mutable, or the returned reference must not be used to change its value in a way
+    /// that its hooks and observers need to know about.
+    #[inline]
+    pub unsafe fn get_mut_assume_mutable<T: Component>(&mut self) -> Option<Mut<'_, T>> {
+        // SAFETY:
+        // - &mut self implies exclusive access for duration of returned value
+        // - the caller ensures that the component can be mutated
+        unsafe { self.as_unsafe_entity_cell().get_mut_assume_mutable() }
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     #[inline]
@@ -1233,6 +1281,8 @@ impl<'w> EntityWorldMut<'w> {
         let world = self.world;
         world.flush_entities();
         let archetype = &world.archetypes[self.location.archetype_id];
+        let has_despawn_observer = archetype.has_despawn_observer()
+            || world.observers.has_untargeted_observers(ON_DESPAWN);
 
         // SAFETY: Archetype cannot be mutably aliased by DeferredWorld
         let (archetype, mut deferred_world) = unsafe {
@@ -1243,6 +1293,13 @@ impl<'w> EntityWorldMut<'w> {
 
         // SAFETY: All components in the archetype exist in world
         unsafe {
+            if has_despawn_observer {
+                deferred_world.trigger_observers(
+                    ON_DESPAWN,
+                    self.entity,
+                    &archetype.components().collect::<Vec<ComponentId>>(),
+                );
+            }
             deferred_world.trigger_on_replace(archetype, self.entity, archetype.components());
             if archetype.has_replace_observer() {
                 deferred_world.trigger_observers(
@@ -1485,7 +1542,7 @@ pub enum Entry<'w, 'a, T: Component> {
 }
 
 impl<'w, 'a, T: Component> Entry<'w, 'a, T> {
-    /// Provides in-place mutable access to an occupied entry.
+    /// Replaces the component of the entry, and returns an [`OccupiedEntry`].
     ///
     /// # Examples
     ///
@@ -1495,23 +1552,28 @@ impl<'w, 'a, T: Component> Entry<'w, 'a, T> {
     /// struct Comp(u32);
     ///
     /// # let mut world = World::new();
-    /// let mut entity = world.spawn(Comp(0));
+    /// let mut entity = world.spawn_empty();
     ///
-    /// entity.entry::<Comp>().and_modify(|mut c| c.0 += 1);
-    /// assert_eq!(world.query::<&Comp>().single(&world).0, 1);
+    /// let entry = entity.entry().insert_entry(Comp(4));
+    /// assert_eq!(entry.get(), &Comp(4));
+    ///
+    /// let entry = entity.entry().insert_entry(Comp(2));
+    /// assert_eq!(entry.get(), &Comp(2));
     /// ```
     #[inline]
-    pub fn and_modify<F: FnOnce(Mut<'_, T>)>(self, f: F) -> Self {
+    pub fn insert_entry(self, component: T) -> OccupiedEntry<'w, 'a, T> {
         match self {
             Entry::Occupied(mut entry) => {
-                f(entry.get_mut());
-                Entry::Occupied(entry)
+                entry.insert(component);
+                entry
             }
-            Entry::Vacant(entry) => Entry::Vacant(entry),
+            Entry::Vacant(entry) => entry.insert_entry(component),
         }
     }
+}
 
-    /// Replaces the component of the entry, and returns an [`OccupiedEntry`].
+impl<'w, 'a, T: Component<Mutability = Mutable>> Entry<'w, 'a, T> {
+    /// Provides in-place mutable access to an occupied entry.
     ///
     /// # Examples
     ///
@@ -1521,22 +1583,19 @@ impl<'w, 'a, T: Component> Entry<'w, 'a, T> {
     /// struct Comp(u32);
     ///
     /// # let mut world = World::new();
-    /// let mut entity = world.spawn_empty();
-    ///
-    /// let entry = entity.entry().insert_entry(Comp(4));
-    /// assert_eq!(entry.get(), &Comp(4));
+    /// let mut entity = world.spawn(Comp(0));
     ///
-    /// let entry = entity.entry().insert_entry(Comp(2));
-    /// assert_eq!(entry.get(), &Comp(2));
+    /// entity.entry::<Comp>().and_modify(|mut c| c.0 += 1);
+    /// assert_eq!(world.query::<&Comp>().single(&world).0, 1);
     /// ```
     #[inline]
-    pub fn insert_entry(self, component: T) -> OccupiedEntry<'w, 'a, T> {
+    pub fn and_modify<F: FnOnce(Mut<'_, T>)>(self, f: F) -> Self {
         match self {
             Entry::Occupied(mut entry) => {
-                entry.insert(component);
-                entry
+                f(entry.get_mut());
+                Entry::Occupied(entry)
             }
-            Entry::Vacant(entry) => entry.insert_entry(component),
+            Entry::Vacant(entry) => Entry::Vacant(entry),
         }
     }
 
@@ -1594,7 +1653,7 @@ impl<'w, 'a, T: Component> Entry<'w, 'a, T> {
     }
 }
 
-impl<'w, 'a, T: Component + Default> Entry<'w, 'a, T> {
+impl<'w, 'a, T: Component<Mutability = Mutable> + Default> Entry<'w, 'a, T> {
     /// Ensures the entry has this component by inserting the default value if empty, and
     /// returns a mutable reference to this component in the entry.
     ///
@@ -1651,12 +1710,7 @@ impl<'w, 'a, T: Component> OccupiedEntry<'w, 'a, T> {
         self.entity_world.get::<T>().unwrap()
     }
 
-    /// Gets a mutable reference to the component in the entry.
-    ///
-    /// If you need a reference to the `OccupiedEntry` which may outlive the destruction of
-    /// the `Entry` value, see [`into_mut`].
-    ///
-    /// [`into_mut`]: Self::into_mut
+    /// Replaces the component of the entry.
     ///
     /// # Examples
     ///
@@ -1669,27 +1723,17 @@ impl<'w, 'a, T: Component> OccupiedEntry<'w, 'a, T> {
     /// let mut entity = world.spawn(Comp(5));
     ///
     /// if let Entry::Occupied(mut o) = entity.entry::<Comp>() {
-    ///     o.get_mut().0 += 10;
-    ///     assert_eq!(o.get().0, 15);
-    ///
-    ///     // We can use the same Entry multiple times.
-    ///     o.get_mut().0 += 2
+    ///     o.insert(Comp(10));
     /// }
     ///
-    /// assert_eq!(world.query::<&Comp>().single(&world).0, 17);
+    /// assert_eq!(world.query::<&Comp>().single(&world).0, 10);
     /// ```
     #[inline]
-    pub fn get_mut(&mut self) -> Mut<'_, T> {
-        // This shouldn't panic because if we have an OccupiedEntry the component must exist.
-        self.entity_world.get_mut::<T>().unwrap()
+    pub fn insert(&mut self, component: T) {
+        self.entity_world.insert(component);
     }
 
-    /// Converts the `OccupiedEntry` into a mutable reference to the value in the entry with
-    /// a lifetime bound to the `EntityWorldMut`.
-    ///
-    /// If you need multiple references to the `OccupiedEntry`, see [`get_mut`].
-    ///
-    /// [`get_mut`]: Self::get_mut
+    /// Removes the component from the entry and returns it.
     ///
     /// # Examples
     ///
@@ -1702,18 +1746,25 @@ impl<'w, 'a, T: Component> OccupiedEntry<'w, 'a, T> {
     /// let mut entity = world.spawn(Comp(5));
     ///
     /// if let Entry::Occupied(o) = entity.entry::<Comp>() {
-    ///     o.into_mut().0 += 10;
+    ///     assert_eq!(o.take(), Comp(5));
     /// }
     ///
-    /// assert_eq!(world.query::<&Comp>().single(&world).0, 15);
+    /// assert_eq!(world.query::<&Comp>().iter(&world).len(), 0);
     /// ```
     #[inline]
-    pub fn into_mut(self) -> Mut<'a, T> {
+    pub fn take(self) -> T {
         // This shouldn't panic because if we have an OccupiedEntry the component must exist.
-        self.entity_world.get_mut().unwrap()
+        self.entity_world.take().unwrap()
     }
+}
 
-    /// Replaces the component of the entry.
+impl<'w, 'a, T: Component<Mutability = Mutable>> OccupiedEntry<'w, 'a, T> {
+    /// Gets a mutable reference to the component in the entry.
+    ///
+    /// If you need a reference to the `OccupiedEntry` which may outlive the destruction of
+    /// the `Entry` value, see [`into_mut`].
+    ///
+    /// [`into_mut`]: Self::into_mut
     ///
     /// # Examples
     ///
@@ -1726,17 +1777,27 @@ impl<'w, 'a, T: Component> OccupiedEntry<'w, 'a, T> {
     /// let mut entity = world.spawn(Comp(5));
     ///
     /// if let Entry::Occupied(mut o) = entity.entry::<Comp>() {
-    ///     o.insert(Comp(10));
+    ///     o.get_mut().0 += 10;
+    ///     assert_eq!(o.get().0, 15);
+    ///
+    ///     // We can use the same Entry multiple times.
+    ///     o.get_mut().0 += 2
     /// }
     ///
-    /// assert_eq!(world.query::<&Comp>().single(&world).0, 10);
+    /// assert_eq!(world.query::<&Comp>().single(&world).0, 17);
     /// ```
     #[inline]
-    pub fn insert(&mut self, component: T) {
-        self.entity_world.insert(component);
+    pub fn get_mut(&mut self) -> Mut<'_, T> {
+        // This shouldn't panic because if we have an OccupiedEntry the component must exist.
+        self.entity_world.get_mut::<T>().unwrap()
     }
 
-    /// Removes the component from the entry and returns it.
+    /// Converts the `OccupiedEntry` into a mutable reference to the value in the entry with
+    /// a lifetime bound to the `EntityWorldMut`.
+    ///
+    /// If you need multiple references to the `OccupiedEntry`, see [`get_mut`].
+    ///
+    /// [`get_mut`]: Self::get_mut
     ///
     /// # Examples
     ///
@@ -1749,15 +1810,15 @@ impl<'w, 'a, T: Component> OccupiedEntry<'w, 'a, T> {
     /// let mut entity = world.spawn(Comp(5));
     ///
     /// if let Entry::Occupied(o) = entity.entry::<Comp>() {
-    ///     assert_eq!(o.take(), Comp(5));
+    ///     o.into_mut().0 += 10;
     /// }
     ///
-    /// assert_eq!(world.query::<&Comp>().iter(&world).len(), 0);
+    /// assert_eq!(world.query::<&Comp>().single(&world).0, 15);
     /// ```
     #[inline]
-    pub fn take(self) -> T {
+    pub fn into_mut(self) -> Mut<'a, T> {
         // This shouldn't panic because if we have an OccupiedEntry the component must exist.
-        self.entity_world.take().unwrap()
+        self.entity_world.get_mut().unwrap()
     }
 }
 
@@ -1768,7 +1829,7 @@ pub struct VacantEntry<'w, 'a, T: Component> {
 }
 
 impl<'w, 'a, T: Component> VacantEntry<'w, 'a, T> {
-    /// Inserts the component into the `VacantEntry` and returns a mutable reference to it.
+    /// Inserts the component into the `VacantEntry` and returns an `OccupiedEntry`.
     ///
     /// # Examples
     ///
@@ -1781,19 +1842,23 @@ impl<'w, 'a, T: Component> VacantEntry<'w, 'a, T> {
     /// let mut entity = world.spawn_empty();
     ///
     /// if let Entry::Vacant(v) = entity.entry::<Comp>() {
-    ///     v.insert(Comp(10));
+    ///     v.insert_entry(Comp(10));
     /// }
     ///
     /// assert_eq!(world.query::<&Comp>().single(&world).0, 10);
     /// ```
     #[inline]
-    pub fn insert(self, component: T) -> Mut<'a, T> {
+    pub fn insert_entry(self, component: T) -> OccupiedEntry<'w, 'a, T> {
         self.entity_world.insert(component);
-        // This shouldn't panic because we just added this component
-        self.entity_world.get_mut::<T>().unwrap()
+        OccupiedEntry {
+            entity_world: self.entity_world,
+            _marker: PhantomData,
+        }
     }
+}
 
-    /// Inserts the component into the `VacantEntry` and returns an `OccupiedEntry`.
+impl<'w, 'a, T: Component<Mutability = Mutable>> VacantEntry<'w, 'a, T> {
+    /// Inserts the component into the `VacantEntry` and returns a mutable reference to it.
     ///
     /// # Examples
     ///
@@ -1806,18 +1871,16 @@ impl<'w, 'a, T: Component> VacantEntry<'w, 'a, T> {
     /// let mut entity = world.spawn_empty();
     ///
     /// if let Entry::Vacant(v) = entity.entry::<Comp>() {
-    ///     v.insert_entry(Comp(10));
+    ///     v.insert(Comp(10));
     /// }
     ///
     /// assert_eq!(world.query::<&Comp>().single(&world).0, 10);
     /// ```
     #[inline]
-    pub fn insert_entry(self, component: T) -> OccupiedEntry<'w, 'a, T> {
+    pub fn insert(self, component: T) -> Mut<'a, T> {
         self.entity_world.insert(component);
-        OccupiedEntry {
-            entity_world: self.entity_world,
-            _marker: PhantomData,
-        }
+        // This shouldn't panic because we just added this component
+        self.entity_world.get_mut::<T>().unwrap()
     }
 }
 
@@ -1975,6 +2038,13 @@ impl<'w> FilteredEntityRef<'w> {
             .then(|| unsafe { self.entity.get_by_id(component_id) })
             .flatten()
     }
+
+    /// Returns the id of the [`World`] the entity belongs to.
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub(crate) fn world_id(&self) -> crate::world::WorldId {
+        self.entity.world().id()
+    }
 }
 
 impl<'w> From<FilteredEntityMut<'w>> for FilteredEntityRef<'w> {
@@ -2182,7 +2252,7 @@ impl<'w> FilteredEntityMut<'w> {
     /// Gets mutable access to the component of type `T` for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn get_mut<T: Component>(&mut self) -> Option<Mut<'_, T>> {
+    pub fn get_mut<T: Component<Mutability = Mutable>>(&mut self) -> Option<Mut<'_, T>> {
         let id = self.entity.world().components().get_id(TypeId::of::<T>())?;
         self.access
             .has_write(id)
@@ -2195,7 +2265,7 @@ impl<'w> FilteredEntityMut<'w> {
     /// with the world `'w` lifetime for the current entity.
     /// Returns `None` if the entity does not have a component of type `T`.
     #[inline]
-    pub fn into_mut<T: Component>(self) -> Option<Mut<'w, T>> {
+    pub fn into_mut<T: Component<Mutability = Mutable>>(self) -> Option<Mut<'w, T>> {
         let id = self.entity.world().components().get_id(TypeId::of::<T>())?;
         self.access
             .has_write(id)
@@ -2204,6 +2274,24 @@ impl<'w> FilteredEntityMut<'w> {
             .flatten()
     }
 
+    /// Consumes self and gets mutable access to the component of type `T` with the world `'w` lifetime,
+    /// even if it is [immutable](Component#immutable-components).
+    /// Returns `None` if the entity does not have a component of type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be mutable, or the returned reference must not be used to change its value in a way
+    /// that its hooks and observers need to know about.
+    #[inline]
+    pub unsafe fn into_mut_assume_mutable<T: Component>(self) -> Option<Mut<'w, T>> {
+        let id = self.entity.world().components().get_id(TypeId::of::<T>())?;
+        self.access
+            .has_write(id)
+            // SAFETY: We have write access, and the caller ensures that the component can be mutated
+            .then(|| unsafe { self.entity.get_mut_assume_mutable() })
+            .flatten()
+    }
+
     /// Retrieves the change ticks for the given component. This can be useful for implementing change
     /// detection in custom runtimes.
     #[inline]
@@ -2251,6 +2339,29 @@ impl<'w> FilteredEntityMut<'w> {
             .then(|| unsafe { self.entity.get_mut_by_id(component_id) })
             .flatten()
     }
+
+    /// Consumes `self` and gets a [`MutUntyped<'w>`] of the component of the given [`ComponentId`]
+    /// from the entity.
+    ///
+    /// **You should prefer to use the typed API [`FilteredEntityMut::into_mut`] where possible and only
+    /// use this in cases where the actual component types are not known at
+    /// compile time.**
+    #[inline]
+    pub fn into_mut_by_id(self, component_id: ComponentId) -> Option<MutUntyped<'w>> {
+        self.access
+            .has_write(component_id)
+            // SAFETY: We have write access, and consuming `self` ensures that no other references
+            // to the component exist.
+            .then(|| unsafe { self.entity.get_mut_by_id(component_id) })
+            .flatten()
+    }
+
+    /// Returns the id of the [`World`] the entity belongs to.
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub(crate) fn world_id(&self) -> crate::world::WorldId {
+        self.entity.world().id()
+    }
 }
 
 impl<'a> From<EntityMut<'a>> for FilteredEntityMut<'a> {
@@ -2533,7 +2644,10 @@ mod tests {
     use std::panic::AssertUnwindSafe;
 
     use crate::world::{FilteredEntityMut, FilteredEntityRef};
-    use crate::{self as bevy_ecs, component::ComponentId, prelude::*, system::assert_is_system};
+    use crate::{
+        self as bevy_ecs, component::ComponentId, prelude::*, system::assert_is_system,
+        world::DeferredWorld,
+    };
 
     #[test]
     fn sorted_remove() {
@@ -3095,4 +3209,57 @@ mod tests {
         assert!(e.get_mut_by_id(a_id).is_none());
         assert!(e.get_change_ticks_by_id(a_id).is_none());
     }
+
+    #[derive(Component, Clone, Copy, Debug, PartialEq)]
+    #[component(immutable, on_insert = count_inserts)]
+    struct ImmutableComponent(u32);
+
+    #[derive(Resource, Default)]
+    struct Inserts(Vec<u32>);
+
+    fn count_inserts(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let value = world.get::<ImmutableComponent>(entity).unwrap().0;
+        world.resource_mut::<Inserts>().0.push(value);
+    }
+
+    #[test]
+    fn immutable_components_are_replaced() {
+        let mut world = World::new();
+        world.init_resource::<Inserts>();
+        let entity = world.spawn(ImmutableComponent(1)).id();
+        world.entity_mut(entity).insert(ImmutableComponent(2));
+        world
+            .entity_mut(entity)
+            .entry::<ImmutableComponent>()
+            .insert_entry(ImmutableComponent(3));
+
+        assert_eq!(world.resource::<Inserts>().0, vec![1, 2, 3]);
+        assert_eq!(
+            world.get::<ImmutableComponent>(entity),
+            Some(&ImmutableComponent(3))
+        );
+    }
+
+    #[test]
+    fn immutable_components_have_no_untyped_mutable_access() {
+        let mut world = World::new();
+        world.init_resource::<Inserts>();
+        let id = world.init_component::<ImmutableComponent>();
+        assert!(!world.components().get_info(id).unwrap().mutable());
+        let mutable_id = world.init_component::<TestComponent>();
+        assert!(world.components().get_info(mutable_id).unwrap().mutable());
+
+        let entity = world.spawn((ImmutableComponent(1), TestComponent(1))).id();
+        let mut entity = world.entity_mut(entity);
+        assert!(entity.get_by_id(id).is_some());
+        assert!(entity.get_mut_by_id(id).is_none());
+        assert!(entity.get_mut_by_id(mutable_id).is_some());
+
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(id)
+            .build();
+        let mut filtered = query.single_mut(&mut world);
+        assert!(filtered.get::<ImmutableComponent>().is_some());
+        assert!(filtered.get_mut_by_id(id).is_none());
+    }
 }
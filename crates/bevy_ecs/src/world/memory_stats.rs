@@ -0,0 +1,334 @@
+//! Statistics about the memory used by the components, archetypes and tables of a [`World`].
+//!
+//! [`World::memory_stats`] walks the [`Archetypes`](crate::archetype::Archetypes),
+//! [`Tables`](crate::storage::Tables) and [`SparseSets`](crate::storage::SparseSets) of a world and reports,
+//! for each of them, how many entities they hold and how much memory they use. This is mostly useful to
+//! track down archetype fragmentation, for example when marker components are added and removed on many
+//! entities in different combinations.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! #[derive(Component)]
+//! struct Position(f32);
+//!
+//! #[derive(Component)]
+//! struct Marker<const N: usize>;
+//!
+//! let mut world = World::new();
+//! world.spawn((Position(0.0), Marker::<0>));
+//! world.spawn((Position(1.0), Marker::<1>));
+//! world.spawn((Position(2.0), Marker::<0>, Marker::<1>));
+//!
+//! let stats = world.memory_stats();
+//! let position = world.component_id::<Position>().unwrap();
+//! let position_stats = stats.component(position).unwrap();
+//! assert_eq!(position_stats.entity_count, 3);
+//! // Two marker components split the positions across three archetypes.
+//! assert_eq!(position_stats.archetype_count, 3);
+//! assert_eq!(position_stats.table_count, 3);
+//! ```
+
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentId, StorageType},
+    storage::{MemoryUsage, SparseSetIndex, TableId},
+    world::World,
+};
+
+/// A snapshot of the memory used by the components, archetypes and tables of a [`World`].
+///
+/// Created by [`World::memory_stats`].
+#[derive(Debug, Clone, Default)]
+pub struct WorldMemoryStats {
+    /// Statistics for every component that is stored in at least one archetype.
+    pub components: Vec<ComponentMemoryStats>,
+    /// Statistics for every archetype, in [`ArchetypeId`] order.
+    pub archetypes: Vec<ArchetypeMemoryStats>,
+    /// Statistics for every table, in [`TableId`] order.
+    pub tables: Vec<TableMemoryStats>,
+}
+
+/// Memory statistics of a single component type, across all of the storages it is stored in.
+#[derive(Debug, Clone)]
+pub struct ComponentMemoryStats {
+    /// The id of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: String,
+    /// Where the component is stored.
+    pub storage_type: StorageType,
+    /// The number of entities that have this component.
+    pub entity_count: usize,
+    /// The number of archetypes that contain this component.
+    pub archetype_count: usize,
+    /// The number of archetypes containing this component that have no entities.
+    pub empty_archetype_count: usize,
+    /// The number of tables that contain this component. Always `0` for sparse set components.
+    pub table_count: usize,
+    /// The memory used by the values and change ticks of this component.
+    pub memory: MemoryUsage,
+    /// The sizes of the sparse set of this component, if it is stored in one.
+    pub sparse_set: Option<SparseSetStats>,
+}
+
+impl ComponentMemoryStats {
+    /// Returns the average number of entities per archetype containing this component.
+    ///
+    /// A low value for a frequently used component is a sign of archetype fragmentation.
+    pub fn average_entities_per_archetype(&self) -> f32 {
+        if self.archetype_count == 0 {
+            0.0
+        } else {
+            self.entity_count as f32 / self.archetype_count as f32
+        }
+    }
+}
+
+/// The sizes of the [`ComponentSparseSet`](crate::storage::ComponentSparseSet) of a component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SparseSetStats {
+    /// The number of values in the dense storage, which is the number of entities with the component.
+    pub dense_len: usize,
+    /// The number of slots in the sparse array, which grows with the highest entity index that had the component.
+    pub sparse_len: usize,
+}
+
+/// Memory statistics of a single [`Archetype`](crate::archetype::Archetype).
+#[derive(Debug, Clone, Copy)]
+pub struct ArchetypeMemoryStats {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The id of the table storing the table components of this archetype.
+    pub table_id: TableId,
+    /// The number of entities in this archetype.
+    pub entity_count: usize,
+    /// The number of components in this archetype, including sparse set components.
+    pub component_count: usize,
+    /// The memory used by the entity list of this archetype.
+    pub memory: MemoryUsage,
+}
+
+/// Memory statistics of a single [`Table`](crate::storage::Table).
+#[derive(Debug, Clone, Copy)]
+pub struct TableMemoryStats {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities in this table.
+    pub entity_count: usize,
+    /// The number of entities this table can store without reallocating.
+    pub entity_capacity: usize,
+    /// The number of components in this table.
+    pub component_count: usize,
+    /// The number of archetypes sharing this table.
+    pub archetype_count: usize,
+    /// The memory used by the entities and columns of this table.
+    pub memory: MemoryUsage,
+}
+
+impl WorldMemoryStats {
+    /// Returns the statistics of the component with the given id, if it is stored in any archetype.
+    pub fn component(&self, id: ComponentId) -> Option<&ComponentMemoryStats> {
+        self.components.iter().find(|stats| stats.id == id)
+    }
+
+    /// Returns the total memory used by all component storages, archetypes and tables.
+    ///
+    /// Table columns are only counted once, as part of the [`tables`](Self::tables).
+    pub fn total_memory(&self) -> MemoryUsage {
+        let sparse_sets = self
+            .components
+            .iter()
+            .filter(|stats| stats.sparse_set.is_some())
+            .map(|stats| stats.memory)
+            .sum::<MemoryUsage>();
+        let archetypes = self
+            .archetypes
+            .iter()
+            .map(|stats| stats.memory)
+            .sum::<MemoryUsage>();
+        let tables = self
+            .tables
+            .iter()
+            .map(|stats| stats.memory)
+            .sum::<MemoryUsage>();
+        sparse_sets + archetypes + tables
+    }
+
+    /// Returns the number of archetypes that have no entities.
+    pub fn empty_archetype_count(&self) -> usize {
+        self.archetypes
+            .iter()
+            .filter(|stats| stats.entity_count == 0)
+            .count()
+    }
+
+    /// Returns the number of tables that have no entities.
+    pub fn empty_table_count(&self) -> usize {
+        self.tables
+            .iter()
+            .filter(|stats| stats.entity_count == 0)
+            .count()
+    }
+
+    /// Returns the components sorted by the number of archetypes they are spread over, most fragmented first.
+    pub fn components_by_archetype_count(&self) -> Vec<&ComponentMemoryStats> {
+        let mut components: Vec<_> = self.components.iter().collect();
+        components.sort_by_key(|stats| std::cmp::Reverse(stats.archetype_count));
+        components
+    }
+}
+
+impl World {
+    /// Collects the entity counts and memory usage of every component, archetype and table in the world.
+    ///
+    /// This walks all of the storages of the world, so it is relatively expensive and intended for
+    /// debugging and diagnostics. See the [`memory_stats`](crate::world::memory_stats) module for an example.
+    pub fn memory_stats(&self) -> WorldMemoryStats {
+        let tables = &self.storages.tables;
+        let sparse_sets = &self.storages.sparse_sets;
+
+        let mut components: Vec<Option<ComponentMemoryStats>> = std::iter::repeat_with(|| None)
+            .take(self.components.len())
+            .collect();
+        let mut table_archetype_counts = vec![0; tables.len()];
+
+        let archetypes = self
+            .archetypes
+            .iter()
+            .map(|archetype| {
+                table_archetype_counts[archetype.table_id().as_usize()] += 1;
+                for id in archetype.components() {
+                    let info = self.components.get_info(id).unwrap();
+                    let stats = components[id.sparse_set_index()].get_or_insert_with(|| {
+                        ComponentMemoryStats {
+                            id,
+                            name: info.name().to_string(),
+                            storage_type: info.storage_type(),
+                            entity_count: 0,
+                            archetype_count: 0,
+                            empty_archetype_count: 0,
+                            table_count: 0,
+                            memory: MemoryUsage::default(),
+                            sparse_set: None,
+                        }
+                    });
+                    stats.archetype_count += 1;
+                    if archetype.is_empty() {
+                        stats.empty_archetype_count += 1;
+                    }
+                }
+                ArchetypeMemoryStats {
+                    id: archetype.id(),
+                    table_id: archetype.table_id(),
+                    entity_count: archetype.len(),
+                    component_count: archetype.component_count(),
+                    memory: archetype.memory_usage(),
+                }
+            })
+            .collect();
+
+        let tables = tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| {
+                for (id, column) in table.iter_with_ids() {
+                    if let Some(stats) = &mut components[id.sparse_set_index()] {
+                        stats.entity_count += column.len();
+                        stats.table_count += 1;
+                        stats.memory += column.memory_usage();
+                    }
+                }
+                TableMemoryStats {
+                    id: TableId::from_usize(index),
+                    entity_count: table.entity_count(),
+                    entity_capacity: table.entity_capacity(),
+                    component_count: table.component_count(),
+                    archetype_count: table_archetype_counts[index],
+                    memory: table.memory_usage(),
+                }
+            })
+            .collect();
+
+        for (id, sparse_set) in sparse_sets.iter() {
+            if let Some(stats) = &mut components[id.sparse_set_index()] {
+                stats.entity_count += sparse_set.len();
+                stats.memory += sparse_set.memory_usage();
+                stats.sparse_set = Some(SparseSetStats {
+                    dense_len: sparse_set.len(),
+                    sparse_len: sparse_set.sparse_len(),
+                });
+            }
+        }
+
+        WorldMemoryStats {
+            components: components.into_iter().flatten().collect(),
+            archetypes,
+            tables,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{component::Component, world::World};
+
+    #[derive(Component)]
+    #[allow(dead_code)]
+    struct A(u64);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    #[allow(dead_code)]
+    struct Sparse(u32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn memory_stats() {
+        let mut world = World::new();
+        world.spawn_batch((0..10).map(A));
+        world.spawn_batch((0..5).map(|i| (A(i), Marker)));
+        let entity = world.spawn((A(0), Sparse(0))).id();
+        world.despawn(entity);
+        world.spawn(Sparse(1));
+
+        let stats = world.memory_stats();
+        let a = stats.component(world.component_id::<A>().unwrap()).unwrap();
+        assert_eq!(a.entity_count, 15);
+        assert_eq!(a.archetype_count, 3);
+        assert_eq!(a.empty_archetype_count, 1);
+        assert_eq!(a.table_count, 2);
+        assert_eq!(a.sparse_set, None);
+        assert!(a.memory.used_bytes >= 15 * std::mem::size_of::<A>());
+        assert!(a.memory.allocated_bytes >= a.memory.used_bytes);
+
+        let marker = stats
+            .component(world.component_id::<Marker>().unwrap())
+            .unwrap();
+        assert_eq!(marker.entity_count, 5);
+        assert_eq!(marker.average_entities_per_archetype(), 5.0);
+
+        let sparse = stats
+            .component(world.component_id::<Sparse>().unwrap())
+            .unwrap();
+        assert_eq!(sparse.entity_count, 1);
+        assert_eq!(sparse.table_count, 0);
+        let sparse_set = sparse.sparse_set.unwrap();
+        assert_eq!(sparse_set.dense_len, 1);
+        assert_eq!(sparse_set.sparse_len, 16);
+
+        assert_eq!(stats.archetypes.len(), world.archetypes().len());
+        assert_eq!(stats.tables.len(), world.storages().tables.len());
+        assert_eq!(
+            stats.empty_archetype_count(),
+            world.archetypes().iter().filter(|a| a.is_empty()).count()
+        );
+        assert_eq!(
+            stats.components_by_archetype_count()[0].id,
+            world.component_id::<A>().unwrap()
+        );
+        assert!(stats.total_memory().used_bytes > 0);
+    }
+}
@@ -1,21 +1,26 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod async_world;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
 mod entity_ref;
 pub mod error;
 mod identifier;
+pub mod memory_stats;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+#[cfg(feature = "bevy_reflect")]
+pub mod snapshot;
 
 pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+pub use async_world::{apply_async_commands, AsyncWorld};
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_ref::{
@@ -30,10 +35,16 @@ use crate::{
     bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles},
     change_detection::{MutUntyped, TicksMut},
     component::{
-        Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
-        Components, Tick,
+        Component, ComponentCloneHandlers, ComponentDescriptor, ComponentHooks, ComponentId,
+        ComponentInfo, ComponentTicks, Components, Mutable, RequiredComponents,
+        RequiredComponentsError, Tick,
+    },
+    entity::{
+        AllocAtWithoutReplacement, Entities, Entity, EntityCloneBuilder, EntityHashSet,
+        EntityLocation,
     },
-    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityHashSet, EntityLocation},
+    entity_disabling::{DefaultQueryFilters, Disabled},
+    error::ErrorHandler,
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
@@ -129,6 +140,10 @@ pub struct World {
     pub(crate) last_check_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    pub(crate) default_query_filters: DefaultQueryFilters,
+    pub(crate) error_handler: ErrorHandler,
+    pub(crate) schedule_error_handler: Option<ErrorHandler>,
+    pub(crate) entity_command_error_handler: Option<ErrorHandler>,
 }
 
 impl Default for World {
@@ -149,6 +164,10 @@ impl Default for World {
             last_check_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            default_query_filters: DefaultQueryFilters::default(),
+            error_handler: crate::error::panic,
+            schedule_error_handler: None,
+            entity_command_error_handler: None,
         };
         world.bootstrap();
         world
@@ -175,6 +194,10 @@ impl World {
         assert_eq!(ON_INSERT, self.init_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.init_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.init_component::<OnRemove>());
+        assert_eq!(ON_DESPAWN, self.init_component::<OnDespawn>());
+        let disabled = self.init_component::<Disabled>();
+        self.default_query_filters
+            .register_disabling_component(disabled);
     }
     /// Creates a new empty [`World`].
     ///
@@ -265,6 +288,57 @@ impl World {
         self.components.init_component::<T>(&mut self.storages)
     }
 
+    /// Retrieves the [`DefaultQueryFilters`] applied to the queries created for this world.
+    ///
+    /// See [`entity_disabling`](crate::entity_disabling) for more details.
+    pub fn default_query_filters(&self) -> &DefaultQueryFilters {
+        &self.default_query_filters
+    }
+
+    /// Retrieves a mutable reference to the [`DefaultQueryFilters`] applied to the queries created
+    /// for this world. Changes only affect queries created afterwards.
+    ///
+    /// See [`entity_disabling`](crate::entity_disabling) for more details.
+    pub fn default_query_filters_mut(&mut self) -> &mut DefaultQueryFilters {
+        &mut self.default_query_filters
+    }
+
+    /// Returns the [`ErrorHandler`] that errors returned by systems, observers and commands are currently
+    /// routed to.
+    ///
+    /// This is the handler of the [`Schedule`] that is being run, if it has one, and the handler set with
+    /// [`World::set_error_handler`] otherwise. See [`error`](crate::error) for more details.
+    #[inline]
+    pub fn error_handler(&self) -> ErrorHandler {
+        self.schedule_error_handler.unwrap_or(self.error_handler)
+    }
+
+    /// Sets the [`ErrorHandler`] for the errors returned by systems, observers and commands of this world.
+    /// Defaults to [`panic`](crate::error::panic).
+    ///
+    /// Schedules can override it with [`Schedule::set_error_handler`].
+    pub fn set_error_handler(&mut self, handler: ErrorHandler) {
+        self.error_handler = handler;
+    }
+
+    /// Returns the [`ErrorHandler`] set with [`World::set_entity_command_error_handler`], if any.
+    #[inline]
+    pub fn entity_command_error_handler(&self) -> Option<ErrorHandler> {
+        self.entity_command_error_handler
+    }
+
+    /// Sets the [`ErrorHandler`] used by entity commands applied to entities that don't exist, in place
+    /// of the default of each command. Passing `None` restores the default of each command.
+    ///
+    /// Commands queued through an [`EntityCommands`] can override it with
+    /// [`EntityCommands::on_missing_entity`]. See [`EntityCommands`] for more details.
+    ///
+    /// [`EntityCommands`]: crate::system::EntityCommands
+    /// [`EntityCommands::on_missing_entity`]: crate::system::EntityCommands::on_missing_entity
+    pub fn set_entity_command_error_handler(&mut self, handler: Option<ErrorHandler>) {
+        self.entity_command_error_handler = handler;
+    }
+
     /// Returns a mutable reference to the [`ComponentHooks`] for a [`Component`] type.
     ///
     /// Will panic if `T` exists in any archetypes.
@@ -286,6 +360,151 @@ impl World {
         self.components.get_hooks_mut(id)
     }
 
+    /// Returns a mutable reference to the [`ComponentHooks`] for a [`Resource`] type.
+    ///
+    /// Resource hooks are run by [`World::insert_resource`], [`World::remove_resource`] and
+    /// [`World::modify_resource`], as well as the equivalent [`Commands`]:
+    /// - `on_add` when the resource is inserted while it didn't exist,
+    /// - `on_insert` after each insertion, and after each modification with [`World::modify_resource`],
+    /// - `on_replace` before an existing value is overwritten, modified with [`World::modify_resource`] or removed,
+    /// - `on_remove` before the resource is removed.
+    ///
+    /// Since resources don't belong to an entity, hooks are called with [`Entity::PLACEHOLDER`],
+    /// and the resource should be accessed through the [`DeferredWorld`] instead.
+    /// Hooks are not run for `!Send` resources.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Volume(f32);
+    ///
+    /// let mut world = World::new();
+    /// world
+    ///     .register_resource_hooks::<Volume>()
+    ///     .on_insert(|world, _, _| println!("New volume: {}", world.resource::<Volume>().0));
+    ///
+    /// world.insert_resource(Volume(0.5));
+    /// world.modify_resource(|mut volume: Mut<Volume>| volume.0 = 0.8);
+    /// ```
+    pub fn register_resource_hooks<R: Resource>(&mut self) -> &mut ComponentHooks {
+        let id = self.components.init_resource::<R>();
+        // SAFETY: We just created this resource
+        unsafe { self.components.get_hooks_mut(id).debug_checked_unwrap() }
+    }
+
+    /// Registers the given component `R` as a [required component] for `T`.
+    ///
+    /// When `T` is added to an entity, `R` and its own required components will also be added
+    /// if `R` was not already provided. The [`Default`] `constructor` will be used for the creation of `R`.
+    /// If a custom constructor is desired, use [`World::register_required_components_with`] instead.
+    ///
+    /// For the non-panicking version, see [`World::try_register_required_components`].
+    ///
+    /// Note that requirements must currently be registered before `T` is inserted into the world
+    /// for the first time. This limitation may be fixed in the future.
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is already a directly required component for `T`, if `R` is `T` or (recursively)
+    /// requires `T`, or if `T` has already been used in a [`Bundle`] (for example by spawning or inserting it).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct A;
+    ///
+    /// #[derive(Component, Default, PartialEq, Eq, Debug)]
+    /// struct B(usize);
+    ///
+    /// let mut world = World::default();
+    ///
+    /// // Register B as required by A.
+    /// world.register_required_components::<A, B>();
+    ///
+    /// // This will implicitly also insert B with its Default constructor.
+    /// let id = world.spawn(A).id();
+    /// assert_eq!(&B(0), world.entity(id).get::<B>().unwrap());
+    /// ```
+    pub fn register_required_components<T: Component, R: Component + Default>(&mut self) {
+        self.try_register_required_components::<T, R>().unwrap();
+    }
+
+    /// Registers the given component `R` as a [required component] for `T`, using `constructor`
+    /// to create `R` when it is missing.
+    ///
+    /// For the non-panicking version, see [`World::try_register_required_components_with`].
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is already a directly required component for `T`, if `R` is `T` or (recursively)
+    /// requires `T`, or if `T` has already been used in a [`Bundle`] (for example by spawning or inserting it).
+    pub fn register_required_components_with<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) {
+        self.try_register_required_components_with::<T, R>(constructor)
+            .unwrap();
+    }
+
+    /// Tries to register the given component `R` as a [required component] for `T`, using its
+    /// [`Default`] implementation as the constructor.
+    ///
+    /// This is the non-panicking version of [`World::register_required_components`].
+    ///
+    /// [required component]: Component#required-components
+    pub fn try_register_required_components<T: Component, R: Component + Default>(
+        &mut self,
+    ) -> Result<(), RequiredComponentsError> {
+        self.try_register_required_components_with::<T, R>(R::default)
+    }
+
+    /// Tries to register the given component `R` as a [required component] for `T`, using `constructor`
+    /// to create `R` when it is missing.
+    ///
+    /// This is the non-panicking version of [`World::register_required_components_with`].
+    ///
+    /// [required component]: Component#required-components
+    pub fn try_register_required_components_with<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) -> Result<(), RequiredComponentsError> {
+        let requiree = self.init_component::<T>();
+
+        // Bundles cache the required components of their components, so they would become stale.
+        if self
+            .bundles
+            .iter()
+            .any(|bundle| bundle.contributed_components().contains(&requiree))
+        {
+            return Err(RequiredComponentsError::ArchetypeExists(requiree));
+        }
+
+        // SAFETY: We just checked that no bundle contains `requiree` or a component requiring it.
+        unsafe {
+            self.components
+                .register_required_components(&mut self.storages, requiree, constructor)
+        }
+    }
+
+    /// Retrieves the [required components](RequiredComponents) for the given component type, if it exists.
+    pub fn get_required_components<C: Component>(&self) -> Option<&RequiredComponents> {
+        let id = self.components().component_id::<C>()?;
+        let component_info = self.components().get_info(id)?;
+        Some(component_info.required_components())
+    }
+
+    /// Retrieves a mutable reference to the [`ComponentCloneHandlers`], which decide how each
+    /// component is cloned when cloning entities.
+    pub fn get_component_clone_handlers_mut(&mut self) -> &mut ComponentCloneHandlers {
+        self.components.get_component_clone_handlers_mut()
+    }
+
     /// Initializes a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::init_component`] in that it uses a [`ComponentDescriptor`]
@@ -1089,7 +1308,10 @@ impl World {
     /// position.x = 1.0;
     /// ```
     #[inline]
-    pub fn get_mut<T: Component>(&mut self, entity: Entity) -> Option<Mut<T>> {
+    pub fn get_mut<T: Component<Mutability = Mutable>>(
+        &mut self,
+        entity: Entity,
+    ) -> Option<Mut<T>> {
         // SAFETY:
         // - `as_unsafe_world_cell` is the only thing that is borrowing world
         // - `as_unsafe_world_cell` provides mutable permission to everything
@@ -1097,6 +1319,47 @@ impl World {
         unsafe { self.as_unsafe_world_cell().get_entity(entity)?.get_mut() }
     }
 
+    /// Spawns a clone of the `source` entity and returns the new entity.
+    ///
+    /// Components are cloned using their [`ComponentCloneHandler`](crate::component::ComponentCloneHandler),
+    /// see [`EntityCloneBuilder`] for more details. Use [`World::clone_entity_with`] to configure
+    /// which components are cloned and how.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, world::World};
+    ///
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Health(10)).id();
+    /// let clone = world.clone_entity(entity);
+    /// assert_eq!(world.get::<Health>(clone), Some(&Health(10)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` does not exist.
+    pub fn clone_entity(&mut self, source: Entity) -> Entity {
+        EntityCloneBuilder::new(self).clone_entity(source)
+    }
+
+    /// Spawns a clone of the `source` entity and returns the new entity, using `f` to configure
+    /// the [`EntityCloneBuilder`] used for the clone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` does not exist.
+    pub fn clone_entity_with(
+        &mut self,
+        source: Entity,
+        f: impl FnOnce(&mut EntityCloneBuilder),
+    ) -> Entity {
+        let mut builder = EntityCloneBuilder::new(self);
+        f(&mut builder);
+        builder.clone_entity(source)
+    }
+
     /// Despawns the given `entity`, if it exists. This will also remove all of the entity's
     /// [`Component`]s. Returns `true` if the `entity` is successfully despawned and `false` if
     /// the `entity` does not exist.
@@ -1124,7 +1387,7 @@ impl World {
     #[track_caller]
     #[inline]
     pub fn despawn(&mut self, entity: Entity) -> bool {
-        self.despawn_with_caller(entity, Location::caller())
+        self.despawn_with_caller(entity, Location::caller(), true)
     }
 
     #[inline]
@@ -1132,13 +1395,16 @@ impl World {
         &mut self,
         entity: Entity,
         caller: &'static Location,
+        log_warning: bool,
     ) -> bool {
         self.flush();
         if let Some(entity) = self.get_entity_mut(entity) {
             entity.despawn();
             true
         } else {
-            warn!("error[B0003]: {caller}: Could not despawn entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", entity);
+            if log_warning {
+                warn!("error[B0003]: {caller}: Could not despawn entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/b0003", entity);
+            }
             false
         }
     }
@@ -1442,9 +1708,78 @@ impl World {
     #[inline]
     pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
         let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
+        let triggered = self.trigger_resource_removal(component_id);
         let (ptr, _, _) = self.storages.resources.get_mut(component_id)?.remove()?;
         // SAFETY: `component_id` was gotten via looking up the `R` type
-        unsafe { Some(ptr.read::<R>()) }
+        let value = unsafe { ptr.read::<R>() };
+        if triggered {
+            self.flush();
+        }
+        Some(value)
+    }
+
+    /// Calls `f` with a mutable reference to the resource `R` and returns its result,
+    /// or returns `None` if the resource doesn't exist.
+    ///
+    /// Unlike mutating the resource through [`World::resource_mut`] or [`ResMut`](crate::system::ResMut),
+    /// this runs the `on_replace` [hook](World::register_resource_hooks) and [observers](World::observe_resource)
+    /// of the resource before calling `f`, and its `on_insert` hook and observers afterwards,
+    /// as if the resource was replaced with its new value.
+    pub fn modify_resource<R: Resource, U>(&mut self, f: impl FnOnce(Mut<R>) -> U) -> Option<U> {
+        let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
+        if !self.contains_resource_by_id(component_id) {
+            return None;
+        }
+        let mut triggered = self.trigger_resource_lifecycle(ON_REPLACE, component_id);
+        // The `on_replace` hook or observers cannot remove the resource from a `DeferredWorld`
+        let value = f(self.resource_mut::<R>());
+        triggered |= self.trigger_resource_lifecycle(ON_INSERT, component_id);
+        if triggered {
+            self.flush();
+        }
+        Some(value)
+    }
+
+    /// Runs the hooks and observers of a resource that is about to be removed, if it exists.
+    /// Returns `true` if any of them ran.
+    fn trigger_resource_removal(&mut self, component_id: ComponentId) -> bool {
+        if !self.contains_resource_by_id(component_id) {
+            return false;
+        }
+        let replaced = self.trigger_resource_lifecycle(ON_REPLACE, component_id);
+        self.trigger_resource_lifecycle(ON_REMOVE, component_id) || replaced
+    }
+
+    /// Runs the hook and observers of the lifecycle `event` for the resource with the given id.
+    /// Returns `true` if any of them ran, in which case the world should be flushed.
+    fn trigger_resource_lifecycle(
+        &mut self,
+        event: ComponentId,
+        component_id: ComponentId,
+    ) -> bool {
+        let hook = self
+            .components
+            .get_info(component_id)
+            .and_then(|info| match event {
+                ON_ADD => info.hooks().on_add,
+                ON_INSERT => info.hooks().on_insert,
+                ON_REPLACE => info.hooks().on_replace,
+                ON_REMOVE => info.hooks().on_remove,
+                _ => None,
+            });
+        let has_observers = self.observers.has_component_observers(event, component_id);
+        if hook.is_none() && !has_observers {
+            return false;
+        }
+
+        let mut world = DeferredWorld::from(self);
+        if let Some(hook) = hook {
+            hook(world.reborrow(), Entity::PLACEHOLDER, component_id);
+        }
+        if has_observers {
+            Observers::invoke_resource(world, event, component_id);
+        }
+        true
     }
 
     /// Removes a `!Send` resource from the world and returns it, if present.
@@ -1480,6 +1815,15 @@ impl World {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if a resource with the given [`ComponentId`] exists. Otherwise returns `false`.
+    #[inline]
+    pub fn contains_resource_by_id(&self, component_id: ComponentId) -> bool {
+        self.storages
+            .resources
+            .get(component_id)
+            .is_some_and(ResourceData::is_present)
+    }
+
     /// Returns `true` if a resource of type `R` exists. Otherwise returns `false`.
     #[inline]
     pub fn contains_non_send<R: 'static>(&self) -> bool {
@@ -1677,6 +2021,13 @@ impl World {
 
     /// Gets a mutable reference to the resource of type `T` if it exists,
     /// otherwise inserts the resource using the result of calling `func`.
+    ///
+    /// Like with [`World::insert_resource`], the `on_add` and `on_insert` [hooks](World::register_resource_hooks)
+    /// and [observers](World::observe_resource) of the resource run when it is inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hooks or observers of the inserted resource remove it.
     #[inline]
     #[track_caller]
     pub fn get_resource_or_insert_with<R: Resource>(
@@ -1685,18 +2036,14 @@ impl World {
     ) -> Mut<'_, R> {
         #[cfg(feature = "track_change_detection")]
         let caller = Location::caller();
-        let change_tick = self.change_tick();
-        let last_change_tick = self.last_change_tick();
-
         let component_id = self.components.init_resource::<R>();
-        let data = self.initialize_resource_internal(component_id);
-        if !data.is_present() {
+        if !self.contains_resource_by_id(component_id) {
             OwningPtr::make(func(), |ptr| {
                 // SAFETY: component_id was just initialized and corresponds to resource of type R.
                 unsafe {
-                    data.insert(
+                    self.insert_resource_by_id(
+                        component_id,
                         ptr,
-                        change_tick,
                         #[cfg(feature = "track_change_detection")]
                         caller,
                     );
@@ -1704,13 +2051,12 @@ impl World {
             });
         }
 
-        // SAFETY: The resource must be present, as we would have inserted it if it was empty.
-        let data = unsafe {
-            data.get_mut(last_change_tick, change_tick)
-                .debug_checked_unwrap()
-        };
-        // SAFETY: The underlying type of the resource is `R`.
-        unsafe { data.with_type::<R>() }
+        self.get_resource_mut::<R>().unwrap_or_else(|| {
+            panic!(
+                "Resource {} was removed by its hooks or observers as soon as it was inserted",
+                std::any::type_name::<R>()
+            )
+        })
     }
 
     /// Gets an immutable reference to the non-send resource of the given type, if it exists.
@@ -2106,6 +2452,10 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let was_present = self.contains_resource_by_id(component_id);
+        let mut triggered =
+            was_present && self.trigger_resource_lifecycle(ON_REPLACE, component_id);
+
         let resource = self.initialize_resource_internal(component_id);
         // SAFETY: `value` is valid for `component_id`, ensured by caller
         unsafe {
@@ -2116,6 +2466,14 @@ impl World {
                 caller,
             );
         }
+
+        if !was_present {
+            triggered |= self.trigger_resource_lifecycle(ON_ADD, component_id);
+        }
+        triggered |= self.trigger_resource_lifecycle(ON_INSERT, component_id);
+        if triggered {
+            self.flush();
+        }
     }
 
     /// Inserts a new `!Send` resource with the given `value`. Will replace the value if it already
@@ -2746,10 +3104,14 @@ impl World {
     /// **You should prefer to use the typed API [`World::remove_resource`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
     pub fn remove_resource_by_id(&mut self, component_id: ComponentId) -> Option<()> {
+        let triggered = self.trigger_resource_removal(component_id);
         self.storages
             .resources
             .get_mut(component_id)?
             .remove_and_drop();
+        if triggered {
+            self.flush();
+        }
         Some(())
     }
 
@@ -2981,12 +3343,14 @@ impl<T: Default> FromWorld for T {
 
 #[cfg(test)]
 mod tests {
-    use super::{FromWorld, World};
+    use super::{FromWorld, OnInsert, OnRemove, World};
     use crate::{
-        change_detection::DetectChangesMut,
+        change_detection::{DetectChangesMut, Mut},
         component::{ComponentDescriptor, ComponentInfo, StorageType},
+        entity::Entity,
+        observer::Trigger,
         ptr::OwningPtr,
-        system::Resource,
+        system::{Res, ResMut, Resource},
     };
     use bevy_ecs_macros::Component;
     use bevy_utils::{HashMap, HashSet};
@@ -3242,6 +3606,7 @@ mod tests {
                     assert_eq!(data, [0, 1, 2, 3, 4, 5, 6, 7]);
                     DROP_COUNT.fetch_add(1, Ordering::SeqCst);
                 }),
+                true,
             )
         };
 
@@ -3309,6 +3674,94 @@ mod tests {
         assert_eq!(resource.0, 0);
     }
 
+    #[derive(Resource, Default)]
+    struct LifecycleLog(Vec<&'static str>);
+
+    #[test]
+    fn resource_hooks() {
+        let mut world = World::new();
+        world.init_resource::<LifecycleLog>();
+        world
+            .register_resource_hooks::<TestResource>()
+            .on_add(|mut world, _, _| world.resource_mut::<LifecycleLog>().0.push("add"))
+            .on_insert(|mut world, _, _| world.resource_mut::<LifecycleLog>().0.push("insert"))
+            .on_replace(|mut world, _, _| world.resource_mut::<LifecycleLog>().0.push("replace"))
+            .on_remove(|mut world, _, _| world.resource_mut::<LifecycleLog>().0.push("remove"));
+
+        world.insert_resource(TestResource(0));
+        assert_eq!(world.resource::<LifecycleLog>().0, ["add", "insert"]);
+
+        world.resource_mut::<LifecycleLog>().0.clear();
+        world.insert_resource(TestResource(1));
+        assert_eq!(world.resource::<LifecycleLog>().0, ["replace", "insert"]);
+
+        world.resource_mut::<LifecycleLog>().0.clear();
+        assert_eq!(
+            world.modify_resource(|mut resource: Mut<TestResource>| {
+                resource.0 += 1;
+                resource.0
+            }),
+            Some(2)
+        );
+        assert_eq!(world.resource::<LifecycleLog>().0, ["replace", "insert"]);
+
+        world.resource_mut::<LifecycleLog>().0.clear();
+        assert_eq!(
+            world.remove_resource::<TestResource>().map(|r| r.0),
+            Some(2)
+        );
+        assert_eq!(world.resource::<LifecycleLog>().0, ["replace", "remove"]);
+
+        world.resource_mut::<LifecycleLog>().0.clear();
+        assert!(world.remove_resource::<TestResource>().is_none());
+        assert_eq!(world.modify_resource(|_: Mut<TestResource>| {}), None);
+        assert!(world.resource::<LifecycleLog>().0.is_empty());
+
+        // Inserting through `get_resource_or_insert_with` runs the hooks too, but only if the resource is missing.
+        world.get_resource_or_insert_with(|| TestResource(3)).0 += 1;
+        assert_eq!(world.resource::<LifecycleLog>().0, ["add", "insert"]);
+        world.get_resource_or_insert_with(|| TestResource(5)).0 += 1;
+        assert_eq!(world.resource::<LifecycleLog>().0, ["add", "insert"]);
+        assert_eq!(world.resource::<TestResource>().0, 5);
+    }
+
+    #[test]
+    fn resource_observers() {
+        let mut world = World::new();
+        world.init_resource::<LifecycleLog>();
+        world.observe_resource::<TestResource, _, _>(
+            |_: Trigger<OnInsert>, resource: Res<TestResource>, mut log: ResMut<LifecycleLog>| {
+                log.0.push(if resource.0 == 0 {
+                    "insert 0"
+                } else {
+                    "insert"
+                });
+            },
+        );
+        world.observe_resource::<TestResource, _, _>(
+            |trigger: Trigger<OnRemove>, mut log: ResMut<LifecycleLog>| {
+                assert_eq!(trigger.entity(), Entity::PLACEHOLDER);
+                log.0.push("remove");
+            },
+        );
+        // Observers that don't target the resource are not run.
+        world.observe(|_: Trigger<OnInsert>, mut log: ResMut<LifecycleLog>| {
+            log.0.push("untargeted");
+        });
+        world.flush();
+
+        world.insert_resource(TestResource(0));
+        world
+            .commands()
+            .modify_resource(|mut resource: Mut<TestResource>| resource.0 = 1);
+        world.commands().remove_resource::<TestResource>();
+        world.flush();
+        assert_eq!(
+            world.resource::<LifecycleLog>().0,
+            ["insert 0", "insert", "remove"]
+        );
+    }
+
     #[derive(Component)]
     struct Foo;
 
@@ -0,0 +1,826 @@
+//! Snapshots of selected components and resources of a [`World`], to restore them later.
+//!
+//! A [`WorldSnapshot`] captures the values and [change ticks](ComponentTicks) of the components and resources
+//! selected by a [`SnapshotConfig`], along with the ids of the entities holding them. Restoring it brings these
+//! entities back exactly as they were: entities despawned since are respawned with the same [`Entity`] ids,
+//! entities spawned since with one of the selected components are despawned, older entities that gained one
+//! of the selected components since lose it again, and every value and change tick is written back.
+//! Components and resources that were not selected are left untouched.
+//!
+//! This can be used for rollback networking, by keeping the last frames in a [`SnapshotHistory`],
+//! restoring the world a few frames back when a late input arrives, and re-simulating the following frames
+//! with [`Schedule::run`](crate::schedule::Schedule::run). It can also be used for "undo" in tools.
+//!
+//! The values of the types selected with [`SnapshotConfig::with_component`] and [`SnapshotConfig::with_resource`]
+//! are cloned into a buffer of their own type. Types that are only known by their [`TypeId`] are captured and
+//! restored through reflection instead, so they must be registered in the [`AppTypeRegistry`] with
+//! [`ReflectComponent`] or [`ReflectResource`] type data.
+//!
+//! ```
+//! use bevy_ecs::{
+//!     prelude::*,
+//!     world::snapshot::{SnapshotConfig, SnapshotHistory},
+//! };
+//!
+//! #[derive(Component, Clone, Copy, PartialEq, Debug)]
+//! struct Position(i32);
+//!
+//! let mut world = World::new();
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(|mut positions: Query<&mut Position>| {
+//!     for mut position in &mut positions {
+//!         position.0 += 1;
+//!     }
+//! });
+//!
+//! let config = SnapshotConfig::new().with_component::<Position>();
+//! let mut history = SnapshotHistory::new(8);
+//! let entity = world.spawn(Position(0)).id();
+//!
+//! for _ in 0..5 {
+//!     history.push(world.snapshot(&config).unwrap());
+//!     schedule.run(&mut world);
+//! }
+//! assert_eq!(world.get::<Position>(entity), Some(&Position(5)));
+//!
+//! // Go back to the state before the third frame, and simulate the last frames again.
+//! history.rollback(&mut world, 2).unwrap();
+//! assert_eq!(world.get::<Position>(entity), Some(&Position(2)));
+//! for _ in 0..3 {
+//!     schedule.run(&mut world);
+//!     history.push(world.snapshot(&config).unwrap());
+//! }
+//! assert_eq!(world.get::<Position>(entity), Some(&Position(5)));
+//! ```
+//!
+//! Note that the [`World`]'s own change tick is never rewound, so that systems keep detecting changes
+//! correctly after a restore. Entities spawned while re-simulating may also get different ids than the first
+//! time, as entities that were not captured keep their ids.
+
+use std::{any::TypeId, collections::VecDeque};
+
+use bevy_reflect::Reflect;
+use thiserror::Error;
+
+use crate::{
+    self as bevy_ecs,
+    archetype::ArchetypeEntity,
+    component::{Component, ComponentId, ComponentTicks, Tick},
+    entity::{Entity, EntityHashSet},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    system::Resource,
+    world::{EntityRef, EntityWorldMut, World},
+};
+
+/// Selects the components and resources captured by [`World::snapshot`].
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotConfig {
+    components: Vec<(TypeId, Option<fn() -> Box<dyn ComponentBuffer>>)>,
+    resources: Vec<(TypeId, Option<CloneResource>)>,
+}
+
+type CloneResource = fn(&World) -> Option<Box<dyn ResourceBuffer>>;
+
+impl SnapshotConfig {
+    /// Creates a configuration that does not capture anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captures the component `C`, by cloning its values.
+    pub fn with_component<C: Component + Clone>(mut self) -> Self {
+        select(
+            &mut self.components,
+            TypeId::of::<C>(),
+            Some(|| Box::<Vec<C>>::default()),
+        );
+        self
+    }
+
+    /// Captures the component with the given [`TypeId`], through reflection.
+    pub fn with_component_type_id(mut self, type_id: TypeId) -> Self {
+        select(&mut self.components, type_id, None);
+        self
+    }
+
+    /// Captures the resource `R`, by cloning its value.
+    pub fn with_resource<R: Resource + Clone>(mut self) -> Self {
+        select(
+            &mut self.resources,
+            TypeId::of::<R>(),
+            Some(|world| {
+                let resource = world.get_resource::<R>()?;
+                Some(Box::new(resource.clone()))
+            }),
+        );
+        self
+    }
+
+    /// Captures the resource with the given [`TypeId`], through reflection.
+    pub fn with_resource_type_id(mut self, type_id: TypeId) -> Self {
+        select(&mut self.resources, type_id, None);
+        self
+    }
+}
+
+/// Adds `type_id` to the selected types, preferring cloning over reflection if it was already selected.
+fn select<T>(selected: &mut Vec<(TypeId, Option<T>)>, type_id: TypeId, clone: Option<T>) {
+    match selected.iter_mut().find(|(id, _)| *id == type_id) {
+        Some((_, existing)) => {
+            if clone.is_some() {
+                *existing = clone;
+            }
+        }
+        None => selected.push((type_id, clone)),
+    }
+}
+
+/// The cloned values of a component type, in the order of the entities of its [`ComponentSnapshot`].
+trait ComponentBuffer: Send + Sync {
+    /// Clones the component of `entity` at the end of the buffer. Returns `false` if `entity` does not have it.
+    fn push(&mut self, entity: EntityRef) -> bool;
+
+    /// Inserts a clone of the value at `index` into `entity`.
+    fn insert(&self, index: usize, entity: &mut EntityWorldMut);
+}
+
+impl<C: Component + Clone> ComponentBuffer for Vec<C> {
+    fn push(&mut self, entity: EntityRef) -> bool {
+        let Some(value) = entity.get::<C>() else {
+            return false;
+        };
+        self.push(value.clone());
+        true
+    }
+
+    fn insert(&self, index: usize, entity: &mut EntityWorldMut) {
+        entity.insert(self[index].clone());
+    }
+}
+
+/// The cloned value of a resource.
+trait ResourceBuffer: Send + Sync {
+    /// Inserts a clone of the value into `world`.
+    fn insert(&self, world: &mut World);
+}
+
+impl<R: Resource + Clone> ResourceBuffer for R {
+    fn insert(&self, world: &mut World) {
+        world.insert_resource(self.clone());
+    }
+}
+
+/// The selected components and resources of a [`World`] at some point in time,
+/// created by [`World::snapshot`] and restored by [`World::restore_snapshot`].
+///
+/// See [the module docs](crate::world::snapshot) for more info.
+pub struct WorldSnapshot {
+    entities: EntityHashSet,
+    /// All the entities of the world when the snapshot was taken, to tell apart the entities spawned since.
+    alive: EntityHashSet,
+    components: Vec<ComponentSnapshot>,
+    resources: Vec<ResourceSnapshot>,
+    change_tick: Tick,
+}
+
+struct ComponentSnapshot {
+    type_id: TypeId,
+    entities: Vec<Entity>,
+    ticks: Vec<ComponentTicks>,
+    values: ComponentValues,
+}
+
+enum ComponentValues {
+    Cloned(Box<dyn ComponentBuffer>),
+    Reflected(ReflectComponent, Vec<Box<dyn Reflect>>),
+}
+
+struct ResourceSnapshot {
+    type_id: TypeId,
+    value: Option<(ResourceValue, ComponentTicks)>,
+}
+
+enum ResourceValue {
+    Cloned(Box<dyn ResourceBuffer>),
+    Reflected(ReflectResource, Box<dyn Reflect>),
+}
+
+impl WorldSnapshot {
+    /// Returns an iterator over the entities holding at least one of the captured components, in arbitrary order.
+    pub fn entities(&self) -> impl ExactSizeIterator<Item = Entity> + '_ {
+        self.entities.iter().copied()
+    }
+
+    /// Returns the change tick of the [`World`] when this snapshot was taken.
+    pub fn change_tick(&self) -> Tick {
+        self.change_tick
+    }
+
+    fn uses_reflection(&self) -> bool {
+        self.components
+            .iter()
+            .any(|column| matches!(column.values, ComponentValues::Reflected(..)))
+            || self
+                .resources
+                .iter()
+                .any(|resource| matches!(resource.value, Some((ResourceValue::Reflected(..), _))))
+    }
+}
+
+/// An error returned when capturing or restoring a [`WorldSnapshot`].
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[error("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// A captured component type is not registered with [`ReflectComponent`] type data.
+    #[error("The component {0:?} has no `ReflectComponent` type data (did you call App::register_type()?)")]
+    MissingReflectComponent(TypeId),
+    /// A captured resource type is not registered with [`ReflectResource`] type data.
+    #[error("The resource {0:?} has no `ReflectResource` type data (did you call App::register_type()?)")]
+    MissingReflectResource(TypeId),
+    /// An entity of the snapshot could not be restored, because its id is used by another entity
+    /// that has none of the captured components.
+    #[error("Cannot restore {0}: its id is used by another entity")]
+    EntityIdInUse(Entity),
+    /// A [`SnapshotHistory`] does not contain enough snapshots to roll back by the given number of frames.
+    #[error("Cannot roll back {0} frames: there are not enough snapshots")]
+    MissingSnapshot(usize),
+}
+
+impl World {
+    /// Captures the components and resources selected by `config`. See [`WorldSnapshot`] for more info.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the selected types is captured through reflection and the
+    /// [`AppTypeRegistry`] resource is missing, or if it does not have [`ReflectComponent`] or
+    /// [`ReflectResource`] type data.
+    pub fn snapshot(&self, config: &SnapshotConfig) -> Result<WorldSnapshot, SnapshotError> {
+        let mut entities = EntityHashSet::default();
+        let mut components = Vec::with_capacity(config.components.len());
+        for &(type_id, new_buffer) in &config.components {
+            let mut values = match new_buffer {
+                Some(new_buffer) => ComponentValues::Cloned(new_buffer()),
+                None => ComponentValues::Reflected(
+                    self.app_type_registry()?
+                        .read()
+                        .get_type_data::<ReflectComponent>(type_id)
+                        .ok_or(SnapshotError::MissingReflectComponent(type_id))?
+                        .clone(),
+                    Vec::new(),
+                ),
+            };
+            let mut column = Vec::new();
+            let mut ticks = Vec::new();
+            if let Some(component_id) = self.components().get_id(type_id) {
+                for entity in self.entities_with(component_id) {
+                    let entity_ref = self.entity(entity);
+                    let Some(entity_ticks) = entity_ref.get_change_ticks_by_id(component_id) else {
+                        continue;
+                    };
+                    let captured = match &mut values {
+                        ComponentValues::Cloned(buffer) => buffer.push(entity_ref),
+                        ComponentValues::Reflected(reflect_component, values) => {
+                            match reflect_component.reflect(entity_ref) {
+                                Some(value) => {
+                                    values.push(value.clone_value());
+                                    true
+                                }
+                                None => false,
+                            }
+                        }
+                    };
+                    if captured {
+                        column.push(entity);
+                        ticks.push(entity_ticks);
+                        entities.insert(entity);
+                    }
+                }
+            }
+            components.push(ComponentSnapshot {
+                type_id,
+                entities: column,
+                ticks,
+                values,
+            });
+        }
+
+        let mut resources = Vec::with_capacity(config.resources.len());
+        for &(type_id, clone) in &config.resources {
+            let value = match clone {
+                Some(clone) => clone(self).map(ResourceValue::Cloned),
+                None => {
+                    let reflect_resource = self
+                        .app_type_registry()?
+                        .read()
+                        .get_type_data::<ReflectResource>(type_id)
+                        .ok_or(SnapshotError::MissingReflectResource(type_id))?
+                        .clone();
+                    reflect_resource.reflect(self).map(|value| {
+                        let value = value.clone_value();
+                        ResourceValue::Reflected(reflect_resource, value)
+                    })
+                }
+            };
+            let ticks = self
+                .components()
+                .get_resource_id(type_id)
+                .and_then(|id| self.get_resource_change_ticks_by_id(id));
+            resources.push(ResourceSnapshot {
+                type_id,
+                value: value.zip(ticks),
+            });
+        }
+
+        Ok(WorldSnapshot {
+            entities,
+            alive: self
+                .archetypes()
+                .iter()
+                .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+                .collect(),
+            components,
+            resources,
+            change_tick: self.read_change_tick(),
+        })
+    }
+
+    /// Restores the components and resources captured in `snapshot`. See [`WorldSnapshot`] for more info.
+    ///
+    /// Hooks and observers run as usual for the components that are inserted, replaced or removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot contains values captured through reflection and the [`AppTypeRegistry`]
+    /// resource is missing, or if an entity of the snapshot cannot be restored because its id was reused by
+    /// an entity that was not spawned with one of the captured components. The world is left unchanged in
+    /// both cases. Only if a hook or observer spawns an entity that takes the id of an entity of the snapshot
+    /// during the restore, the restore stops with [`SnapshotError::EntityIdInUse`] after changing the world.
+    pub fn restore_snapshot(&mut self, snapshot: &WorldSnapshot) -> Result<(), SnapshotError> {
+        // The registry is only locked while reflected values are written back, so that hooks and observers
+        // running in the meantime can still modify it.
+        let type_registry = if snapshot.uses_reflection() {
+            Some(self.app_type_registry()?.clone())
+        } else {
+            None
+        };
+        self.flush();
+
+        // The entities spawned since the snapshot was taken that currently hold a captured component.
+        let mut spawned = EntityHashSet::default();
+        for column in &snapshot.components {
+            if let Some(component_id) = self.components().get_id(column.type_id) {
+                spawned.extend(
+                    self.entities_with(component_id)
+                        .filter(|entity| !snapshot.alive.contains(entity)),
+                );
+            }
+        }
+
+        // Check that every entity can get its id back before changing anything.
+        for &entity in &snapshot.entities {
+            let Some(current) = self.entities().resolve_from_id(entity.index()) else {
+                continue;
+            };
+            if current != entity
+                && self.entities().get(current).is_some()
+                && !spawned.contains(&current)
+            {
+                return Err(SnapshotError::EntityIdInUse(entity));
+            }
+        }
+
+        for entity in spawned {
+            self.despawn(entity);
+        }
+        for &entity in &snapshot.entities {
+            if self.get_or_spawn(entity).is_none() {
+                return Err(SnapshotError::EntityIdInUse(entity));
+            }
+        }
+
+        for column in &snapshot.components {
+            if let Some(component_id) = self.components().get_id(column.type_id) {
+                let captured: EntityHashSet = column.entities.iter().copied().collect();
+                let added: Vec<_> = self
+                    .entities_with(component_id)
+                    .filter(|entity| !captured.contains(entity))
+                    .collect();
+                for entity in added {
+                    if let Some(mut entity) = self.get_entity_mut(entity) {
+                        entity.remove_by_id(component_id);
+                    }
+                }
+            }
+
+            for (index, (&entity, &ticks)) in column.entities.iter().zip(&column.ticks).enumerate()
+            {
+                // Hooks may have despawned the entity in the meantime.
+                let Some(mut entity_mut) = self.get_entity_mut(entity) else {
+                    continue;
+                };
+                match &column.values {
+                    ComponentValues::Cloned(buffer) => buffer.insert(index, &mut entity_mut),
+                    ComponentValues::Reflected(reflect_component, values) => {
+                        let registry = type_registry.as_ref().expect(REGISTRY_FETCHED).read();
+                        reflect_component.apply_or_insert(
+                            &mut entity_mut,
+                            values[index].as_ref(),
+                            &registry,
+                        );
+                    }
+                }
+                if let Some(component_id) = self.components().get_id(column.type_id) {
+                    self.set_component_ticks(entity, component_id, ticks);
+                }
+            }
+        }
+
+        for resource in &snapshot.resources {
+            let Some((value, ticks)) = &resource.value else {
+                if let Some(component_id) = self.components().get_resource_id(resource.type_id) {
+                    self.remove_resource_by_id(component_id);
+                }
+                continue;
+            };
+            match value {
+                ResourceValue::Cloned(buffer) => buffer.insert(self),
+                ResourceValue::Reflected(reflect_resource, value) => {
+                    let registry = type_registry.as_ref().expect(REGISTRY_FETCHED).read();
+                    reflect_resource.apply_or_insert(self, value.as_ref(), &registry);
+                }
+            }
+            if let Some(component_id) = self.components().get_resource_id(resource.type_id) {
+                let (last_run, this_run) = (self.last_change_tick(), self.change_tick());
+                if let Some(resource) = self
+                    .storages
+                    .resources
+                    .get_mut(component_id)
+                    .and_then(|data| data.get_mut(last_run, this_run))
+                {
+                    *resource.ticks.added = ticks.added;
+                    *resource.ticks.changed = ticks.changed;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn app_type_registry(&self) -> Result<&AppTypeRegistry, SnapshotError> {
+        self.get_resource::<AppTypeRegistry>()
+            .ok_or(SnapshotError::MissingAppTypeRegistry)
+    }
+
+    fn entities_with(&self, component_id: ComponentId) -> impl Iterator<Item = Entity> + '_ {
+        self.archetypes()
+            .iter()
+            .filter(move |archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+    }
+
+    fn set_component_ticks(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        ticks: ComponentTicks,
+    ) {
+        if let Some(entity) = self.as_unsafe_world_cell().get_entity(entity) {
+            // SAFETY: we have exclusive access to the world.
+            unsafe { entity.set_change_ticks_by_id(component_id, ticks) };
+        }
+    }
+}
+
+const REGISTRY_FETCHED: &str =
+    "the registry is fetched when the snapshot contains reflected values";
+
+/// The last [`WorldSnapshot`]s of a [`World`], to roll it back by a number of frames.
+///
+/// See [the module docs](crate::world::snapshot) for an example.
+#[derive(Resource)]
+pub struct SnapshotHistory {
+    snapshots: VecDeque<WorldSnapshot>,
+    capacity: usize,
+}
+
+impl SnapshotHistory {
+    /// Creates an empty history keeping at most `capacity` snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "A SnapshotHistory must have a non-zero capacity"
+        );
+        Self {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of snapshots kept by this history.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of snapshots in this history.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns `true` if this history does not contain any snapshot.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Adds a snapshot as the most recent one, dropping the oldest snapshot if the history is full.
+    pub fn push(&mut self, snapshot: WorldSnapshot) {
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    /// Returns the snapshot pushed `frames_back` pushes ago: `0` is the most recent snapshot.
+    pub fn get(&self, frames_back: usize) -> Option<&WorldSnapshot> {
+        let index = self.snapshots.len().checked_sub(frames_back + 1)?;
+        self.snapshots.get(index)
+    }
+
+    /// Restores the snapshot pushed `frames_back` pushes ago, and drops the more recent snapshots
+    /// so that they can be pushed again while re-simulating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are not enough snapshots, or if [`World::restore_snapshot`] fails.
+    pub fn rollback(&mut self, world: &mut World, frames_back: usize) -> Result<(), SnapshotError> {
+        let snapshot = self
+            .get(frames_back)
+            .ok_or(SnapshotError::MissingSnapshot(frames_back))?;
+        world.restore_snapshot(snapshot)?;
+        self.snapshots.truncate(self.snapshots.len() - frames_back);
+        Ok(())
+    }
+
+    /// Removes all snapshots from this history.
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_reflect::Reflect;
+
+    use super::{SnapshotConfig, SnapshotError, SnapshotHistory};
+    use crate::{
+        self as bevy_ecs,
+        component::{ComponentTicks, Tick},
+        prelude::*,
+        reflect::{ReflectComponent, ReflectResource},
+    };
+
+    #[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Position(i32);
+
+    #[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Component)]
+    #[component(storage = "SparseSet")]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Component)]
+    #[component(immutable)]
+    struct Team(u8);
+
+    #[derive(Resource, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct Frame(u32);
+
+    /// Not `Clone`, so it can only be captured through reflection.
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Velocity(i32);
+
+    #[derive(Resource, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct Seed(u64);
+
+    #[derive(Component)]
+    struct Untracked;
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Health>();
+            registry.register::<Team>();
+            registry.register::<Frame>();
+            registry.register::<Velocity>();
+            registry.register::<Seed>();
+        }
+        world
+    }
+
+    fn config() -> SnapshotConfig {
+        SnapshotConfig::new()
+            .with_component::<Position>()
+            .with_component::<Health>()
+            .with_component::<Team>()
+            .with_resource::<Frame>()
+    }
+
+    #[test]
+    fn restore_values_and_entities() {
+        let mut world = world();
+        let a = world.spawn((Position(1), Health(10), Team(0))).id();
+        let b = world.spawn((Position(2), Untracked)).id();
+        let untracked = world.spawn(Untracked).id();
+        world.insert_resource(Frame(1));
+
+        let snapshot = world.snapshot(&config()).unwrap();
+        assert_eq!(snapshot.entities().len(), 2);
+
+        world.entity_mut(a).insert((Position(5), Team(1)));
+        world.entity_mut(a).remove::<Health>();
+        world.entity_mut(b).insert(Health(3));
+        world.despawn(b);
+        let c = world.spawn(Position(3)).id();
+        world.entity_mut(untracked).insert(Position(4));
+        world.remove_resource::<Frame>();
+
+        world.restore_snapshot(&snapshot).unwrap();
+
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Health>(a), Some(&Health(10)));
+        assert_eq!(world.get::<Team>(a), Some(&Team(0)));
+        // `b` is back with the same id, but its untracked components are lost.
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+        assert_eq!(world.get::<Health>(b), None);
+        assert!(world.get::<Untracked>(b).is_none());
+        // Entities spawned since are despawned, older entities only lose the tracked components.
+        assert!(world.get_entity(c).is_none());
+        assert_eq!(world.get::<Position>(untracked), None);
+        assert!(world.get::<Untracked>(untracked).is_some());
+        assert_eq!(world.get_resource::<Frame>(), Some(&Frame(1)));
+    }
+
+    #[test]
+    fn restore_reflected_types() {
+        let config = SnapshotConfig::new()
+            .with_component_type_id(TypeId::of::<Velocity>())
+            .with_resource_type_id(TypeId::of::<Seed>());
+        let mut world = world();
+        let a = world.spawn(Velocity(1)).id();
+        let b = world.spawn(Velocity(2)).id();
+        world.insert_resource(Seed(7));
+
+        let snapshot = world.snapshot(&config).unwrap();
+        world.entity_mut(a).insert(Velocity(3));
+        world.despawn(b);
+        world.insert_resource(Seed(8));
+        world.restore_snapshot(&snapshot).unwrap();
+
+        assert_eq!(world.get::<Velocity>(a), Some(&Velocity(1)));
+        assert_eq!(world.get::<Velocity>(b), Some(&Velocity(2)));
+        assert_eq!(world.get_resource::<Seed>(), Some(&Seed(7)));
+
+        let unregistered = SnapshotConfig::new().with_component_type_id(TypeId::of::<Untracked>());
+        assert!(matches!(
+            world.snapshot(&unregistered),
+            Err(SnapshotError::MissingReflectComponent(_))
+        ));
+    }
+
+    #[test]
+    fn restore_without_type_registry() {
+        let mut world = World::new();
+        let entity = world.spawn(Position(1)).id();
+        world.insert_resource(Frame(1));
+
+        let snapshot = world.snapshot(&config()).unwrap();
+        world.entity_mut(entity).insert(Position(2));
+        world.insert_resource(Frame(2));
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.get::<Position>(entity), Some(&Position(1)));
+        assert_eq!(world.get_resource::<Frame>(), Some(&Frame(1)));
+
+        let reflected = SnapshotConfig::new().with_component_type_id(TypeId::of::<Velocity>());
+        assert!(matches!(
+            world.snapshot(&reflected),
+            Err(SnapshotError::MissingAppTypeRegistry)
+        ));
+    }
+
+    #[test]
+    fn restore_change_ticks() {
+        fn ticks(ticks: Option<ComponentTicks>) -> Option<(Tick, Tick)> {
+            ticks.map(|ticks| (ticks.added, ticks.changed))
+        }
+
+        let mut world = world();
+        let entity = world.spawn((Position(1), Team(0))).id();
+        world.insert_resource(Frame(0));
+        world.increment_change_tick();
+        world.entity_mut(entity).get_mut::<Position>().unwrap().0 = 2;
+        let position_ticks = ticks(world.entity(entity).get_change_ticks::<Position>());
+        let team_ticks = ticks(world.entity(entity).get_change_ticks::<Team>());
+        let frame_ticks = ticks(world.get_resource_change_ticks::<Frame>());
+
+        let snapshot = world.snapshot(&config()).unwrap();
+        world.increment_change_tick();
+        world.entity_mut(entity).insert((Position(3), Team(1)));
+        world.resource_mut::<Frame>().0 = 1;
+        world.restore_snapshot(&snapshot).unwrap();
+
+        assert_eq!(
+            ticks(world.entity(entity).get_change_ticks::<Position>()),
+            position_ticks
+        );
+        assert_eq!(
+            ticks(world.entity(entity).get_change_ticks::<Team>()),
+            team_ticks
+        );
+        assert_eq!(
+            ticks(world.get_resource_change_ticks::<Frame>()),
+            frame_ticks
+        );
+    }
+
+    #[test]
+    fn rollback_and_resimulate() {
+        fn simulate(
+            mut commands: Commands,
+            mut query: Query<(Entity, &mut Position)>,
+            mut frame: ResMut<Frame>,
+        ) {
+            frame.0 += 1;
+            for (entity, mut position) in &mut query {
+                position.0 += 1;
+                if position.0 % 4 == 0 {
+                    commands.entity(entity).despawn();
+                    commands.spawn(Position(0));
+                }
+            }
+        }
+
+        let mut world = world();
+        world.insert_resource(Frame(0));
+        world.spawn(Position(0));
+        world.spawn(Position(2));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(simulate);
+        let mut history = SnapshotHistory::new(4);
+
+        let mut states = Vec::new();
+        for _ in 0..6 {
+            history.push(world.snapshot(&config()).unwrap());
+            schedule.run(&mut world);
+            let mut positions: Vec<_> = world.query::<&Position>().iter(&world).copied().collect();
+            positions.sort_by_key(|position| position.0);
+            states.push(positions);
+        }
+        assert_eq!(history.len(), 4);
+        assert!(matches!(
+            history.rollback(&mut world, 4),
+            Err(SnapshotError::MissingSnapshot(4))
+        ));
+
+        history.rollback(&mut world, 3).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(world.resource::<Frame>(), &Frame(2));
+        for state in &states[2..] {
+            schedule.run(&mut world);
+            history.push(world.snapshot(&config()).unwrap());
+            let mut positions: Vec<_> = world.query::<&Position>().iter(&world).copied().collect();
+            positions.sort_by_key(|position| position.0);
+            assert_eq!(&positions, state);
+        }
+    }
+
+    #[test]
+    fn restore_fails_if_id_is_reused() {
+        let mut world = world();
+        let entity = world.spawn(Position(0)).id();
+        let snapshot = world.snapshot(&config()).unwrap();
+        world.despawn(entity);
+        let reused = world.spawn(Untracked).id();
+        assert_eq!(reused.index(), entity.index());
+
+        assert!(matches!(
+            world.restore_snapshot(&snapshot),
+            Err(SnapshotError::EntityIdInUse(e)) if e == entity
+        ));
+        assert!(world.get_entity(reused).is_some());
+    }
+}
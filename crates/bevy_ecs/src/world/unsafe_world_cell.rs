@@ -7,7 +7,7 @@ use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
     change_detection::{MaybeUnsafeCellLocation, MutUntyped, Ticks, TicksMut},
-    component::{ComponentId, ComponentTicks, Components, StorageType, Tick, TickCells},
+    component::{ComponentId, ComponentTicks, Components, Mutable, StorageType, Tick, TickCells},
     entity::{Entities, Entity, EntityLocation},
     observer::Observers,
     prelude::Component,
@@ -818,12 +818,64 @@ impl<'w> UnsafeEntityCell<'w> {
         }
     }
 
+    /// Overwrites the [`ComponentTicks`] of the component with the given id, including for
+    /// [immutable](Component#immutable-components) components.
+    /// Returns `false` if the entity does not have the component.
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the component mutably
+    /// - no other references to the component's ticks exist at the same time
+    #[inline]
+    #[cfg_attr(not(feature = "bevy_reflect"), allow(dead_code))]
+    pub(crate) unsafe fn set_change_ticks_by_id(
+        self,
+        component_id: ComponentId,
+        ticks: ComponentTicks,
+    ) -> bool {
+        let Some(info) = self.world.components().get_info(component_id) else {
+            return false;
+        };
+        // SAFETY: entity location is valid, component_id is valid as checked by the line above
+        let Some((_, cells, _caller)) = (unsafe {
+            get_component_and_ticks(
+                self.world,
+                component_id,
+                info.storage_type(),
+                self.entity,
+                self.location,
+            )
+        }) else {
+            return false;
+        };
+        // SAFETY: the caller ensures that no other references to the ticks exist
+        unsafe {
+            *cells.added.get() = ticks.added;
+            *cells.changed.get() = ticks.changed;
+        }
+        true
+    }
+
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the component mutably
+    /// - no other references to the component exist at the same time
+    #[inline]
+    pub unsafe fn get_mut<T: Component<Mutability = Mutable>>(self) -> Option<Mut<'w, T>> {
+        // SAFETY: same safety requirements
+        unsafe { self.get_mut_assume_mutable() }
+    }
+
+    /// Like [`UnsafeEntityCell::get_mut`], but for components that may be [immutable](Component#immutable-components).
+    ///
     /// # Safety
     /// It is the callers responsibility to ensure that
     /// - the [`UnsafeEntityCell`] has permission to access the component mutably
     /// - no other references to the component exist at the same time
+    /// - the component `T` is mutable, or the returned reference is not used to change its value in a way
+    ///   that its hooks and observers need to know about
     #[inline]
-    pub unsafe fn get_mut<T: Component>(self) -> Option<Mut<'w, T>> {
+    pub unsafe fn get_mut_assume_mutable<T: Component>(self) -> Option<Mut<'w, T>> {
         // SAFETY: same safety requirements
         unsafe { self.get_mut_using_ticks(self.world.last_change_tick(), self.world.change_tick()) }
     }
@@ -893,7 +945,8 @@ impl<'w> UnsafeEntityCell<'w> {
     }
 
     /// Retrieves a mutable untyped reference to the given `entity`'s [`Component`] of the given [`ComponentId`].
-    /// Returns `None` if the `entity` does not have a [`Component`] of the given type.
+    /// Returns `None` if the `entity` does not have a [`Component`] of the given type,
+    /// or if the component is [immutable](Component#immutable-components).
     ///
     /// **You should prefer to use the typed API [`UnsafeEntityCell::get_mut`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
@@ -905,6 +958,9 @@ impl<'w> UnsafeEntityCell<'w> {
     #[inline]
     pub unsafe fn get_mut_by_id(self, component_id: ComponentId) -> Option<MutUntyped<'w>> {
         let info = self.world.components().get_info(component_id)?;
+        if !info.mutable() {
+            return None;
+        }
         // SAFETY: entity_location is valid, component_id is valid as checked by the line above
         unsafe {
             get_component_and_ticks(
@@ -2,10 +2,12 @@ use crate::{Children, HierarchyEvent, Parent};
 use bevy_ecs::{
     bundle::Bundle,
     entity::Entity,
+    error::{self, ErrorHandler},
     prelude::Events,
-    system::{Commands, EntityCommands},
+    system::{Commands, EntityCommands, EntityMissingError},
     world::{Command, EntityWorldMut, World},
 };
+use core::panic::Location;
 use smallvec::{smallvec, SmallVec};
 
 // Do not use `world.send_event_batch` as it prints error message when the Events are not available in the world,
@@ -252,6 +254,116 @@ impl Command for RemoveParent {
     }
 }
 
+/// A hierarchy [`Command`] that refers to entities which may not exist anymore when it is applied.
+pub(crate) trait HierarchyCommand: Command {
+    /// The name of the command, passed to the [`ErrorHandler`] with [`EntityMissingError`].
+    const NAME: &'static str;
+
+    /// The [`ErrorHandler`] used when no other one is set. See [`EntityMissingError::handle`].
+    const DEFAULT_HANDLER: Option<ErrorHandler> = None;
+
+    /// Returns an entity that the command needs but doesn't exist, if any.
+    fn missing_entity(&self, world: &World) -> Option<Entity>;
+}
+
+fn missing_entity(world: &World, entities: &[Entity]) -> Option<Entity> {
+    entities
+        .iter()
+        .copied()
+        .find(|&entity| world.get_entity(entity).is_none())
+}
+
+impl HierarchyCommand for PushChild {
+    const NAME: &'static str = "PushChild";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent, self.child])
+    }
+}
+
+impl HierarchyCommand for InsertChildren {
+    const NAME: &'static str = "InsertChildren";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent]).or_else(|| missing_entity(world, &self.children))
+    }
+}
+
+impl HierarchyCommand for PushChildren {
+    const NAME: &'static str = "PushChildren";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent]).or_else(|| missing_entity(world, &self.children))
+    }
+}
+
+impl HierarchyCommand for RemoveChildren {
+    const NAME: &'static str = "RemoveChildren";
+    const DEFAULT_HANDLER: Option<ErrorHandler> = Some(error::ignore);
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent])
+    }
+}
+
+impl HierarchyCommand for ClearChildren {
+    const NAME: &'static str = "ClearChildren";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent])
+    }
+}
+
+impl HierarchyCommand for ReplaceChildren {
+    const NAME: &'static str = "ReplaceChildren";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.parent]).or_else(|| missing_entity(world, &self.children))
+    }
+}
+
+impl HierarchyCommand for RemoveParent {
+    const NAME: &'static str = "RemoveParent";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        missing_entity(world, &[self.child])
+    }
+}
+
+/// A [`HierarchyCommand`] queued through [`EntityCommands`], which follows its rules for missing entities.
+struct EntityHierarchyCommand<C> {
+    command: C,
+    caller: &'static Location<'static>,
+    handler: Option<ErrorHandler>,
+}
+
+impl<C: HierarchyCommand> Command for EntityHierarchyCommand<C> {
+    fn apply(self, world: &mut World) {
+        if let Some(entity) = self.command.missing_entity(world) {
+            EntityMissingError {
+                entity,
+                command: C::NAME,
+                caller: self.caller,
+            }
+            .handle(world, self.handler, C::DEFAULT_HANDLER);
+        } else {
+            self.command.apply(world);
+        }
+    }
+}
+
+/// Queues `command`, handing an [`EntityMissingError`] to the error handler chosen from `commands`
+/// if an entity it needs doesn't exist when it is applied. See [`EntityCommands`] for more details.
+#[track_caller]
+pub(crate) fn add_hierarchy_command(commands: &mut EntityCommands, command: impl HierarchyCommand) {
+    let command = EntityHierarchyCommand {
+        command,
+        caller: Location::caller(),
+        handler: commands.missing_entity_handler(),
+    };
+    commands.commands().add(command);
+}
+
 /// Struct for building children entities and adding them to a parent entity.
 ///
 /// # Example
@@ -413,6 +525,7 @@ pub trait BuildChildren {
 impl BuildChildren for EntityCommands<'_> {
     type Builder<'a> = ChildBuilder<'a>;
 
+    #[track_caller]
     fn with_children(&mut self, spawn_children: impl FnOnce(&mut Self::Builder<'_>)) -> &mut Self {
         let parent = self.id();
         let mut builder = ChildBuilder {
@@ -428,83 +541,199 @@ impl BuildChildren for EntityCommands<'_> {
         if children.children.contains(&parent) {
             panic!("Entity cannot be a child of itself.");
         }
-        self.commands().add(children);
+        add_hierarchy_command(self, children);
         self
     }
 
+    #[track_caller]
     fn push_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         if children.contains(&parent) {
             panic!("Cannot push entity as a child of itself.");
         }
-        self.commands().add(PushChildren {
-            children: SmallVec::from(children),
-            parent,
-        });
+        add_hierarchy_command(
+            self,
+            PushChildren {
+                children: SmallVec::from(children),
+                parent,
+            },
+        );
         self
     }
 
+    #[track_caller]
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         if children.contains(&parent) {
             panic!("Cannot insert entity as a child of itself.");
         }
-        self.commands().add(InsertChildren {
-            children: SmallVec::from(children),
-            index,
-            parent,
-        });
+        add_hierarchy_command(
+            self,
+            InsertChildren {
+                children: SmallVec::from(children),
+                index,
+                parent,
+            },
+        );
         self
     }
 
+    #[track_caller]
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
-        self.commands().add(RemoveChildren {
-            children: SmallVec::from(children),
-            parent,
-        });
+        add_hierarchy_command(
+            self,
+            RemoveChildren {
+                children: SmallVec::from(children),
+                parent,
+            },
+        );
         self
     }
 
+    #[track_caller]
     fn add_child(&mut self, child: Entity) -> &mut Self {
         let parent = self.id();
         if child == parent {
             panic!("Cannot add entity as a child of itself.");
         }
-        self.commands().add(PushChild { child, parent });
+        add_hierarchy_command(self, PushChild { child, parent });
         self
     }
 
+    #[track_caller]
     fn clear_children(&mut self) -> &mut Self {
         let parent = self.id();
-        self.commands().add(ClearChildren { parent });
+        add_hierarchy_command(self, ClearChildren { parent });
         self
     }
 
+    #[track_caller]
     fn replace_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         if children.contains(&parent) {
             panic!("Cannot replace entity as a child of itself.");
         }
-        self.commands().add(ReplaceChildren {
-            children: SmallVec::from(children),
-            parent,
-        });
+        add_hierarchy_command(
+            self,
+            ReplaceChildren {
+                children: SmallVec::from(children),
+                parent,
+            },
+        );
         self
     }
 
+    #[track_caller]
     fn set_parent(&mut self, parent: Entity) -> &mut Self {
         let child = self.id();
         if child == parent {
             panic!("Cannot set parent to itself");
         }
-        self.commands().add(PushChild { child, parent });
+        add_hierarchy_command(self, PushChild { child, parent });
         self
     }
 
+    #[track_caller]
     fn remove_parent(&mut self) -> &mut Self {
         let child = self.id();
-        self.commands().add(RemoveParent { child });
+        add_hierarchy_command(self, RemoveParent { child });
+        self
+    }
+}
+
+/// Variants of the [`BuildChildren`] commands of [`EntityCommands`] that do nothing if an entity they
+/// refer to doesn't exist when they are applied, regardless of the [`ErrorHandler`] that would otherwise be used.
+///
+/// See [`EntityCommands`] for how missing entities are handled by the other commands.
+pub trait TryBuildChildren {
+    /// Like [`BuildChildren::push_children`], but ignores missing entities.
+    fn try_push_children(&mut self, children: &[Entity]) -> &mut Self;
+
+    /// Like [`BuildChildren::insert_children`], but ignores missing entities.
+    fn try_insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self;
+
+    /// Like [`BuildChildren::remove_children`], but ignores missing entities.
+    fn try_remove_children(&mut self, children: &[Entity]) -> &mut Self;
+
+    /// Like [`BuildChildren::add_child`], but ignores missing entities.
+    fn try_add_child(&mut self, child: Entity) -> &mut Self;
+
+    /// Like [`BuildChildren::clear_children`], but ignores missing entities.
+    fn try_clear_children(&mut self) -> &mut Self;
+
+    /// Like [`BuildChildren::replace_children`], but ignores missing entities.
+    fn try_replace_children(&mut self, children: &[Entity]) -> &mut Self;
+
+    /// Like [`BuildChildren::set_parent`], but ignores missing entities.
+    fn try_set_parent(&mut self, parent: Entity) -> &mut Self;
+
+    /// Like [`BuildChildren::remove_parent`], but ignores missing entities.
+    fn try_remove_parent(&mut self) -> &mut Self;
+}
+
+impl TryBuildChildren for EntityCommands<'_> {
+    #[track_caller]
+    fn try_push_children(&mut self, children: &[Entity]) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .push_children(children);
+        self
+    }
+
+    #[track_caller]
+    fn try_insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .insert_children(index, children);
+        self
+    }
+
+    #[track_caller]
+    fn try_remove_children(&mut self, children: &[Entity]) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .remove_children(children);
+        self
+    }
+
+    #[track_caller]
+    fn try_add_child(&mut self, child: Entity) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .add_child(child);
+        self
+    }
+
+    #[track_caller]
+    fn try_clear_children(&mut self) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .clear_children();
+        self
+    }
+
+    #[track_caller]
+    fn try_replace_children(&mut self, children: &[Entity]) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .replace_children(children);
+        self
+    }
+
+    #[track_caller]
+    fn try_set_parent(&mut self, parent: Entity) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .set_parent(parent);
+        self
+    }
+
+    #[track_caller]
+    fn try_remove_parent(&mut self) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .remove_parent();
         self
     }
 }
@@ -667,7 +896,7 @@ impl BuildChildren for EntityWorldMut<'_> {
 
 #[cfg(test)]
 mod tests {
-    use super::{BuildChildren, ChildBuild};
+    use super::{BuildChildren, ChildBuild, TryBuildChildren};
     use crate::{
         components::{Children, Parent},
         HierarchyEvent::{self, ChildAdded, ChildMoved, ChildRemoved},
@@ -1228,4 +1457,45 @@ mod tests {
         let children = query.get(&world, parent);
         assert!(children.is_err());
     }
+
+    #[test]
+    fn try_hierarchy_commands_ignore_missing_entities() {
+        let mut world = World::default();
+        let entities = world
+            .spawn_batch(vec![C(1), C(2), C(3)])
+            .collect::<Vec<Entity>>();
+        let (parent, child, despawned) = (entities[0], entities[1], entities[2]);
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(despawned).despawn();
+            commands
+                .entity(parent)
+                .try_push_children(&[child, despawned])
+                .try_add_child(despawned);
+            commands.entity(despawned).try_set_parent(parent);
+            commands.entity(child).try_set_parent(parent);
+        }
+        queue.apply(&mut world);
+
+        assert_eq!(**world.get::<Children>(parent).unwrap(), [child]);
+        assert_eq!(*world.get::<Parent>(child).unwrap(), Parent(parent));
+    }
+
+    #[test]
+    #[should_panic]
+    fn hierarchy_commands_panic_on_missing_entities() {
+        let mut world = World::default();
+        let parent = world.spawn_empty().id();
+        let child = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(child).despawn();
+            commands.entity(parent).add_child(child);
+        }
+        queue.apply(&mut world);
+    }
 }
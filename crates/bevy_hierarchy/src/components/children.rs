@@ -1,9 +1,11 @@
+use crate::Parent;
 #[cfg(feature = "reflect")]
 use bevy_ecs::reflect::{ReflectComponent, ReflectMapEntities};
 use bevy_ecs::{
-    component::Component,
+    component::{Component, ComponentCloneHandler, ComponentHooks, Mutable, StorageType},
     entity::{Entity, EntityMapper, MapEntities},
     prelude::FromWorld,
+    relationship::{RelationshipDespawnBehavior, RelationshipTarget},
     world::World,
 };
 use core::slice;
@@ -13,6 +15,8 @@ use std::ops::Deref;
 /// Contains references to the child entities of this entity.
 ///
 /// Each child must contain a [`Parent`] component that points back to this entity.
+/// [`Children`] is the [`RelationshipTarget`](bevy_ecs::relationship::RelationshipTarget) of the hierarchy:
+/// when it is removed or its entity is despawned, the [`Parent`] component of the children is removed.
 /// This component rarely needs to be created manually,
 /// consider using higher level utilities like [`BuildChildren::with_children`]
 /// which are safer and easier to use.
@@ -23,11 +27,48 @@ use std::ops::Deref;
 /// [`Query`]: bevy_ecs::system::Query
 /// [`Parent`]: crate::components::parent::Parent
 /// [`BuildChildren::with_children`]: crate::child_builder::BuildChildren::with_children
-#[derive(Component, Debug)]
+#[derive(Debug)]
 #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "reflect", reflect(Component, MapEntities))]
 pub struct Children(pub(crate) SmallVec<[Entity; 8]>);
 
+// Children are not cloned along with their parent by default, since a child can only have one parent.
+// See `CloneEntityHierarchyExt::recursive` to clone an entity along with its children.
+impl Component for Children {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_replace(<Self as RelationshipTarget>::on_replace);
+    }
+
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::Ignore
+    }
+}
+
+impl RelationshipTarget for Children {
+    type Relationship = Parent;
+    type Collection = SmallVec<[Entity; 8]>;
+
+    const DESPAWN_BEHAVIOR: RelationshipDespawnBehavior = RelationshipDespawnBehavior::Orphan;
+
+    #[inline]
+    fn collection(&self) -> &Self::Collection {
+        &self.0
+    }
+
+    #[inline]
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+        &mut self.0
+    }
+
+    #[inline]
+    fn from_collection_risky(collection: Self::Collection) -> Self {
+        Children(collection)
+    }
+}
+
 impl MapEntities for Children {
     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
         for entity in &mut self.0 {
@@ -1,9 +1,10 @@
+use crate::Children;
 #[cfg(feature = "reflect")]
 use bevy_ecs::reflect::{ReflectComponent, ReflectMapEntities};
 use bevy_ecs::{
-    component::Component,
+    component::{Component, ComponentCloneHandler, ComponentHooks, Mutable, StorageType},
     entity::{Entity, EntityMapper, MapEntities},
-    traversal::Traversal,
+    relationship::Relationship,
     world::{FromWorld, World},
 };
 use std::ops::Deref;
@@ -12,20 +13,58 @@ use std::ops::Deref;
 /// This component should only be present on entities that actually have a parent entity.
 ///
 /// Parent entity must have this entity stored in its [`Children`] component.
-/// It is hard to set up parent/child relationships manually,
-/// consider using higher level utilities like [`BuildChildren::with_children`].
+/// [`Parent`] is the [`Relationship`] of the hierarchy: inserting or removing it keeps
+/// the [`Children`] of the parent in sync once the world is flushed.
+/// Consider using higher level utilities like [`BuildChildren::with_children`], which update both sides
+/// immediately and preserve the order of children.
+///
+/// `Parent` can also be used as the [`Traversal`](bevy_ecs::traversal::Traversal) of
+/// [event propagation](bevy_ecs::observer::Trigger::propagate). It will never form loops in
+/// properly-constructed hierarchies.
 ///
 /// See [`HierarchyQueryExt`] for hierarchy related methods on [`Query`].
 ///
 /// [`HierarchyQueryExt`]: crate::query_extension::HierarchyQueryExt
 /// [`Query`]: bevy_ecs::system::Query
 /// [`Children`]: super::children::Children
+/// [`Relationship`]: bevy_ecs::relationship::Relationship
 /// [`BuildChildren::with_children`]: crate::child_builder::BuildChildren::with_children
-#[derive(Component, Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "reflect", reflect(Component, MapEntities, PartialEq))]
 pub struct Parent(pub(crate) Entity);
 
+// A clone is not added to the children of the original's parent by default, as that would
+// silently change the parent's `Children`. See `CloneEntityHierarchyExt::as_child`.
+impl Component for Parent {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks
+            .on_insert(<Self as Relationship>::on_insert)
+            .on_replace(<Self as Relationship>::on_replace);
+    }
+
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::Ignore
+    }
+}
+
+impl Relationship for Parent {
+    type RelationshipTarget = Children;
+
+    #[inline(always)]
+    fn get(&self) -> Entity {
+        self.0
+    }
+
+    #[inline]
+    fn from(entity: Entity) -> Self {
+        Parent(entity)
+    }
+}
+
 impl Parent {
     /// Gets the [`Entity`] ID of the parent.
     #[inline(always)]
@@ -70,14 +109,3 @@ impl Deref for Parent {
         &self.0
     }
 }
-
-/// This provides generalized hierarchy traversal for use in [event propagation].
-///
-/// `Parent::traverse` will never form loops in properly-constructed hierarchies.
-///
-/// [event propagation]: bevy_ecs::observer::Trigger::propagate
-impl Traversal for Parent {
-    fn traverse(&self) -> Option<Entity> {
-        Some(self.0)
-    }
-}
@@ -1,6 +1,12 @@
-use crate::components::{Children, Parent};
+use crate::{
+    child_builder::{add_hierarchy_command, HierarchyCommand},
+    components::{Children, Parent},
+    BuildChildren,
+};
 use bevy_ecs::{
-    entity::Entity,
+    component::ComponentCloneHandler,
+    entity::{ComponentCloneCtx, Entity, EntityCloneBuilder},
+    error::{self, ErrorHandler},
     system::EntityCommands,
     world::{Command, EntityWorldMut, World},
 };
@@ -47,7 +53,10 @@ fn despawn_with_children_recursive_inner(world: &mut World, entity: Entity) {
 }
 
 fn despawn_children_recursive(world: &mut World, entity: Entity) {
-    if let Some(children) = world.entity_mut(entity).take::<Children>() {
+    let children = world
+        .get_entity_mut(entity)
+        .and_then(|mut entity| entity.take::<Children>());
+    if let Some(children) = children {
         for e in children.0 {
             despawn_with_children_recursive_inner(world, e);
         }
@@ -80,6 +89,29 @@ impl Command for DespawnChildrenRecursive {
     }
 }
 
+impl HierarchyCommand for DespawnRecursive {
+    const NAME: &'static str = "DespawnRecursive";
+    const DEFAULT_HANDLER: Option<ErrorHandler> = Some(error::debug);
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        world
+            .get_entity(self.entity)
+            .is_none()
+            .then_some(self.entity)
+    }
+}
+
+impl HierarchyCommand for DespawnChildrenRecursive {
+    const NAME: &'static str = "DespawnChildrenRecursive";
+
+    fn missing_entity(&self, world: &World) -> Option<Entity> {
+        world
+            .get_entity(self.entity)
+            .is_none()
+            .then_some(self.entity)
+    }
+}
+
 /// Trait that holds functions for despawning recursively down the transform hierarchy
 pub trait DespawnRecursiveExt {
     /// Despawns the provided entity alongside all descendants.
@@ -87,19 +119,53 @@ pub trait DespawnRecursiveExt {
 
     /// Despawns all descendants of the given entity.
     fn despawn_descendants(&mut self) -> &mut Self;
+
+    /// Like [`despawn_recursive`](Self::despawn_recursive), but ignores a missing entity.
+    ///
+    /// The default implementation calls [`despawn_recursive`](Self::despawn_recursive).
+    fn try_despawn_recursive(self)
+    where
+        Self: Sized,
+    {
+        self.despawn_recursive();
+    }
+
+    /// Like [`despawn_descendants`](Self::despawn_descendants), but ignores a missing entity.
+    ///
+    /// The default implementation calls [`despawn_descendants`](Self::despawn_descendants).
+    fn try_despawn_descendants(&mut self) -> &mut Self {
+        self.despawn_descendants()
+    }
 }
 
 impl DespawnRecursiveExt for EntityCommands<'_> {
     /// Despawns the provided entity and its children.
-    /// This will emit warnings for any entity that does not exist.
+    /// This will log a debug message if the entity does not exist, unless another
+    /// [`ErrorHandler`] is set. See [`EntityCommands`] for more details.
+    #[track_caller]
     fn despawn_recursive(mut self) {
         let entity = self.id();
-        self.commands().add(DespawnRecursive { entity });
+        add_hierarchy_command(&mut self, DespawnRecursive { entity });
     }
 
+    #[track_caller]
     fn despawn_descendants(&mut self) -> &mut Self {
         let entity = self.id();
-        self.commands().add(DespawnChildrenRecursive { entity });
+        add_hierarchy_command(self, DespawnChildrenRecursive { entity });
+        self
+    }
+
+    #[track_caller]
+    fn try_despawn_recursive(mut self) {
+        self.on_missing_entity(error::ignore);
+        self.despawn_recursive();
+    }
+
+    #[track_caller]
+    fn try_despawn_descendants(&mut self) -> &mut Self {
+        self.reborrow()
+            .on_missing_entity(error::ignore)
+            .despawn_descendants();
         self
     }
 }
@@ -137,18 +203,79 @@ impl<'w> DespawnRecursiveExt for EntityWorldMut<'w> {
     }
 }
 
+/// Trait that holds functions for cloning entities along with their hierarchy.
+pub trait CloneEntityHierarchyExt {
+    /// Sets the option to recursively clone the children of the cloned entity.
+    ///
+    /// Each child is cloned with the same configuration, and the clones are added as children of the
+    /// parent's clone in the same order.
+    fn recursive(&mut self, recursive: bool) -> &mut Self;
+
+    /// Sets the option to add the clone as a child of the parent of the cloned entity.
+    fn as_child(&mut self, as_child: bool) -> &mut Self;
+}
+
+impl CloneEntityHierarchyExt for EntityCloneBuilder<'_> {
+    fn recursive(&mut self, recursive: bool) -> &mut Self {
+        if recursive {
+            self.override_component_clone_handler::<Children>(ComponentCloneHandler::Custom(
+                component_clone_children,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Children>()
+        }
+    }
+
+    fn as_child(&mut self, as_child: bool) -> &mut Self {
+        if as_child {
+            self.override_component_clone_handler::<Parent>(ComponentCloneHandler::Custom(
+                component_clone_parent,
+            ))
+        } else {
+            self.remove_component_clone_handler_override::<Parent>()
+        }
+    }
+}
+
+/// Clone handler for the [`Children`] component. Allows to clone the entity recursively.
+fn component_clone_children(world: &mut World, ctx: &mut ComponentCloneCtx) {
+    let Some(children) = world.get::<Children>(ctx.source()).map(|c| c.0.clone()) else {
+        return;
+    };
+    let clones = children
+        .into_iter()
+        .map(|child| ctx.queue_clone(world, child))
+        .collect::<Vec<_>>();
+    world.entity_mut(ctx.target()).push_children(&clones);
+}
+
+/// Clone handler for the [`Parent`] component. Allows to add the clone as a child to the parent entity.
+fn component_clone_parent(world: &mut World, ctx: &mut ComponentCloneCtx) {
+    let Some(parent) = world.get::<Parent>(ctx.source()).map(Parent::get) else {
+        return;
+    };
+    // The parent is cloned as well, the clone is added to its children by `component_clone_children`.
+    if ctx.is_cloned(parent) {
+        return;
+    }
+    world.entity_mut(ctx.target()).set_parent(parent);
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use bevy_ecs::{
         component::Component,
-        system::Commands,
+        error::{Error, ErrorContext},
+        system::{Commands, EntityMissingError},
         world::{CommandQueue, World},
     };
 
-    use super::DespawnRecursiveExt;
+    use super::{CloneEntityHierarchyExt, DespawnRecursiveExt};
     use crate::{
         child_builder::{BuildChildren, ChildBuild},
-        components::Children,
+        components::{Children, Parent},
     };
 
     #[derive(Component, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
@@ -213,13 +340,13 @@ mod tests {
             .collect::<Vec<_>>();
         results.sort_unstable_by_key(|(_, index)| *index);
 
-        {
-            let children = world.get::<Children>(grandparent_entity).unwrap();
-            assert!(
-                !children.iter().any(|&i| i == parent_entity),
-                "grandparent should no longer know about its child which has been removed"
-            );
-        }
+        // The grandparent's `Children` is removed along with its last child.
+        assert!(
+            !world
+                .get::<Children>(grandparent_entity)
+                .is_some_and(|children| children.contains(&parent_entity)),
+            "grandparent should no longer know about its child which has been removed"
+        );
 
         assert_eq!(
             results,
@@ -281,4 +408,113 @@ mod tests {
         // The original child should be despawned.
         assert!(world.get_entity(child).is_none());
     }
+
+    #[test]
+    fn despawn_commands_handle_missing_entities() {
+        static MISSING: AtomicUsize = AtomicUsize::new(0);
+        fn count(error: Error, _: ErrorContext) {
+            assert!(error.is::<EntityMissingError>());
+            MISSING.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.set_entity_command_error_handler(Some(count));
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands.entity(entity).try_despawn_descendants();
+            commands.entity(entity).try_despawn_recursive();
+            commands.entity(entity).despawn_descendants();
+            commands.entity(entity).despawn_recursive();
+        }
+        queue.apply(&mut world);
+
+        assert_eq!(MISSING.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn despawn_descendants_panics_on_missing_entity() {
+        let mut world = World::default();
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands.entity(entity).despawn_descendants();
+        }
+        queue.apply(&mut world);
+    }
+
+    #[test]
+    fn clone_entity_recursive() {
+        let mut world = World::default();
+        let root = world.spawn(Idx(0)).id();
+        let child1 = world.spawn(Idx(1)).set_parent(root).id();
+        let grandchild = world.spawn(Idx(2)).set_parent(child1).id();
+        let child2 = world.spawn(Idx(3)).set_parent(root).id();
+
+        let root_clone = world.clone_entity_with(root, |builder| {
+            builder.recursive(true);
+        });
+
+        let clone_children = world.get::<Children>(root_clone).unwrap().to_vec();
+        assert_eq!(clone_children.len(), 2);
+        assert!(!clone_children.contains(&child1) && !clone_children.contains(&child2));
+        assert_eq!(world.get::<Idx>(clone_children[0]), Some(&Idx(1)));
+        assert_eq!(world.get::<Idx>(clone_children[1]), Some(&Idx(3)));
+        for child in &clone_children {
+            assert_eq!(world.get::<Parent>(*child).unwrap().get(), root_clone);
+        }
+
+        let grandchildren = world.get::<Children>(clone_children[0]).unwrap().to_vec();
+        assert_eq!(grandchildren.len(), 1);
+        assert_ne!(grandchildren[0], grandchild);
+        assert_eq!(world.get::<Idx>(grandchildren[0]), Some(&Idx(2)));
+
+        // The original hierarchy is untouched.
+        assert_eq!(
+            world.get::<Children>(root).unwrap().to_vec(),
+            [child1, child2]
+        );
+        assert_eq!(
+            world.get::<Children>(child1).unwrap().to_vec(),
+            [grandchild]
+        );
+    }
+
+    #[test]
+    fn clone_entity_not_recursive() {
+        let mut world = World::default();
+        let root = world.spawn(Idx(0)).id();
+        let child = world.spawn(Idx(1)).set_parent(root).id();
+
+        let child_clone = world.clone_entity(child);
+        let root_clone = world.clone_entity(root);
+
+        assert!(world.get::<Parent>(child_clone).is_none());
+        assert!(world.get::<Children>(root_clone).is_none());
+        assert_eq!(world.get::<Children>(root).unwrap().to_vec(), [child]);
+    }
+
+    #[test]
+    fn clone_entity_as_child() {
+        let mut world = World::default();
+        let root = world.spawn(Idx(0)).id();
+        let child = world.spawn(Idx(1)).set_parent(root).id();
+
+        let child_clone = world.clone_entity_with(child, |builder| {
+            builder.as_child(true);
+        });
+
+        assert_eq!(world.get::<Parent>(child_clone).unwrap().get(), root);
+        assert_eq!(
+            world.get::<Children>(root).unwrap().to_vec(),
+            [child, child_clone]
+        );
+    }
 }
@@ -9,6 +9,9 @@ use bevy_ecs::{
 use crate::{Children, Parent};
 
 /// An extension trait for [`Query`] that adds hierarchy related methods.
+///
+/// To walk other [`Traversal`](bevy_ecs::traversal::Traversal) components, use
+/// [`TraversalQueryExt`](bevy_ecs::traversal::TraversalQueryExt).
 pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     /// Returns an [`Iterator`] of [`Entity`]s over all of `entity`s descendants.
     ///
@@ -4,7 +4,7 @@ use std::ops::{Div, DivAssign, Mul, MulAssign};
 use crate::primitives::Frustum;
 use crate::view::VisibilitySystems;
 use bevy_app::{App, Plugin, PostStartup, PostUpdate};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{component::Mutable, prelude::*};
 use bevy_math::{AspectRatio, Mat4, Rect, Vec2, Vec3A};
 use bevy_reflect::{
     std_traits::ReflectDefault, GetTypeRegistration, Reflect, ReflectDeserialize, ReflectSerialize,
@@ -16,10 +16,12 @@ use serde::{Deserialize, Serialize};
 /// Adds [`Camera`](crate::camera::Camera) driver systems for a given projection type.
 ///
 /// If you are using `bevy_pbr`, then you need to add `PbrProjectionPlugin` along with this.
-pub struct CameraProjectionPlugin<T: CameraProjection + Component + GetTypeRegistration>(
-    PhantomData<T>,
-);
-impl<T: CameraProjection + Component + GetTypeRegistration> Plugin for CameraProjectionPlugin<T> {
+pub struct CameraProjectionPlugin<
+    T: CameraProjection + Component<Mutability = Mutable> + GetTypeRegistration,
+>(PhantomData<T>);
+impl<T: CameraProjection + Component<Mutability = Mutable> + GetTypeRegistration> Plugin
+    for CameraProjectionPlugin<T>
+{
     fn build(&self, app: &mut App) {
         app.register_type::<T>()
             .add_systems(
@@ -52,7 +54,9 @@ impl<T: CameraProjection + Component + GetTypeRegistration> Plugin for CameraPro
             );
     }
 }
-impl<T: CameraProjection + Component + GetTypeRegistration> Default for CameraProjectionPlugin<T> {
+impl<T: CameraProjection + Component<Mutability = Mutable> + GetTypeRegistration> Default
+    for CameraProjectionPlugin<T>
+{
     fn default() -> Self {
         Self(Default::default())
     }
@@ -2,10 +2,13 @@ use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
     entity::Entity,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    reflect::{
+        AppTypeRegistry, DynamicComponentError, DynamicComponents, ReflectComponent,
+        ReflectMapEntities,
+    },
     world::World,
 };
-use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+use bevy_reflect::{DynamicStruct, Reflect, TypePath, TypeRegistry};
 use bevy_utils::TypeIdMap;
 
 #[cfg(feature = "serialize")]
@@ -42,6 +45,9 @@ pub struct DynamicEntity {
     /// A vector of boxed components that belong to the given entity and
     /// implement the [`Reflect`] trait.
     pub components: Vec<Box<dyn Reflect>>,
+    /// The [dynamic components](World::register_dynamic_component) of the entity,
+    /// as pairs of component name and value.
+    pub dynamic_components: Vec<(String, DynamicStruct)>,
 }
 
 impl DynamicScene {
@@ -119,6 +125,25 @@ impl DynamicScene {
                 // component to the entity.
                 reflect_component.apply_or_insert(entity_mut, &**component, &type_registry);
             }
+
+            // Dynamic components are matched by name with the ones registered in the world.
+            for (name, component) in &scene_entity.dynamic_components {
+                let dynamic_component = entity_mut
+                    .world()
+                    .get_resource::<DynamicComponents>()
+                    .and_then(|components| components.get_by_name(name))
+                    .cloned()
+                    .ok_or_else(|| SceneSpawnError::DynamicComponent {
+                        name: name.clone(),
+                        error: DynamicComponentError::NotRegistered(name.clone()),
+                    })?;
+                dynamic_component
+                    .apply_or_insert(entity_mut, component)
+                    .map_err(|error| SceneSpawnError::DynamicComponent {
+                        name: name.clone(),
+                        error,
+                    })?;
+            }
         }
 
         // Updates references to entities in the scene to entities in the world
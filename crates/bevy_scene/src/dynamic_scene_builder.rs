@@ -3,10 +3,10 @@ use bevy_ecs::component::{Component, ComponentId};
 use bevy_ecs::system::Resource;
 use bevy_ecs::{
     prelude::Entity,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, DynamicComponents, ReflectComponent, ReflectResource},
     world::World,
 };
-use bevy_reflect::Reflect;
+use bevy_reflect::{Reflect, Struct};
 use bevy_utils::default;
 use std::collections::BTreeMap;
 
@@ -18,6 +18,7 @@ use std::collections::BTreeMap;
 /// (this type data is added automatically during registration if [`Reflect`] is derived with the `#[reflect(Component)]` attribute).
 /// This can be changed by [specifying a filter](DynamicSceneBuilder::with_filter) or by explicitly
 /// [allowing](DynamicSceneBuilder::allow)/[denying](DynamicSceneBuilder::deny) certain components.
+/// [Dynamic components](World::register_dynamic_component) are always extracted, as they have no type to filter by.
 ///
 /// Extraction happens immediately and uses the filter as it exists during the time of extraction.
 ///
@@ -203,8 +204,9 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// These were likely created because none of their components were present in the provided type registry upon extraction.
     #[must_use]
     pub fn remove_empty_entities(mut self) -> Self {
-        self.extracted_scene
-            .retain(|_, entity| !entity.components.is_empty());
+        self.extracted_scene.retain(|_, entity| {
+            !entity.components.is_empty() || !entity.dynamic_components.is_empty()
+        });
 
         self
     }
@@ -245,6 +247,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     #[must_use]
     pub fn extract_entities(mut self, entities: impl Iterator<Item = Entity>) -> Self {
         let type_registry = self.original_world.resource::<AppTypeRegistry>().read();
+        let dynamic_components = self.original_world.get_resource::<DynamicComponents>();
 
         for entity in entities {
             if self.extracted_scene.contains_key(&entity) {
@@ -254,16 +257,26 @@ impl<'w> DynamicSceneBuilder<'w> {
             let mut entry = DynamicEntity {
                 entity,
                 components: Vec::new(),
+                dynamic_components: Vec::new(),
             };
 
             let original_entity = self.original_world.entity(entity);
             for component_id in original_entity.archetype().components() {
                 let mut extract_and_push = || {
-                    let type_id = self
+                    let Some(type_id) = self
                         .original_world
                         .components()
                         .get_info(component_id)?
-                        .type_id()?;
+                        .type_id()
+                    else {
+                        let dynamic_component = dynamic_components?.get(component_id)?;
+                        let component = dynamic_component.reflect(original_entity)?;
+                        entry.dynamic_components.push((
+                            dynamic_component.name().to_string(),
+                            component.clone_dynamic(),
+                        ));
+                        return Some(());
+                    };
 
                     let is_denied = self.component_filter.is_denied_by_id(type_id);
 
@@ -2,10 +2,14 @@ use crate::{DynamicScene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::entity::{Entity, EntityHashMap};
 use bevy_ecs::{
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+    component::StorageType,
+    reflect::{
+        AppTypeRegistry, DynamicComponents, ReflectComponent, ReflectMapEntities, ReflectResource,
+    },
     world::World,
 };
-use bevy_reflect::TypePath;
+use bevy_reflect::{Struct, TypePath};
+use std::any::TypeId;
 
 /// To spawn a scene, you can use either:
 /// * [`SceneSpawner::spawn`](crate::SceneSpawner::spawn)
@@ -31,6 +35,28 @@ impl Scene {
         type_registry: &AppTypeRegistry,
     ) -> Result<Scene, SceneSpawnError> {
         let mut world = World::new();
+
+        // The scene world only holds the dynamic components until they are written to another world,
+        // where they are matched by name with the registered ones, so their values are used as prototypes.
+        for (name, component) in dynamic_scene
+            .entities
+            .iter()
+            .flat_map(|entity| &entity.dynamic_components)
+        {
+            let is_registered = world
+                .get_resource::<DynamicComponents>()
+                .is_some_and(|components| components.get_by_name(name).is_some());
+            if !is_registered {
+                world
+                    .register_dynamic_component(
+                        name.clone(),
+                        component.clone_dynamic(),
+                        StorageType::Table,
+                    )
+                    .expect("the dynamic component is not registered yet");
+            }
+        }
+
         let mut entity_map = EntityHashMap::default();
         dynamic_scene.write_to_world_with(&mut world, &mut entity_map, type_registry)?;
 
@@ -43,6 +69,23 @@ impl Scene {
     /// provided [`AppTypeRegistry`] or doesn't reflect the [`Component`](bevy_ecs::component::Component) trait.
     pub fn clone_with(&self, type_registry: &AppTypeRegistry) -> Result<Scene, SceneSpawnError> {
         let mut new_world = World::new();
+        if let Some(dynamic_components) = self.world.get_resource::<DynamicComponents>() {
+            for component in dynamic_components.iter() {
+                let storage_type = self
+                    .world
+                    .components()
+                    .get_info(component.id())
+                    .expect("dynamic components should have ComponentInfo")
+                    .storage_type();
+                new_world
+                    .register_dynamic_component(
+                        component.name(),
+                        component.prototype().clone_dynamic(),
+                        storage_type,
+                    )
+                    .expect("the new world has no dynamic component yet");
+            }
+        }
         let mut entity_map = EntityHashMap::default();
         self.write_to_world_with(&mut new_world, &mut entity_map, type_registry)?;
         Ok(Self { world: new_world })
@@ -75,6 +118,10 @@ impl Scene {
             let type_id = component_info
                 .type_id()
                 .expect("reflected resources must have a type_id");
+            // Dynamic components are registered in the destination world instead.
+            if type_id == TypeId::of::<DynamicComponents>() {
+                continue;
+            }
 
             let registration =
                 type_registry
@@ -102,8 +149,24 @@ impl Scene {
                         .get_info(component_id)
                         .expect("component_ids in archetypes should have ComponentInfo");
 
+                    let Some(type_id) = component_info.type_id() else {
+                        let dynamic_component = self
+                            .world
+                            .get_resource::<DynamicComponents>()
+                            .and_then(|components| components.get(component_id))
+                            .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                                std_type_name: component_info.name().to_string(),
+                            })?;
+                        dynamic_component
+                            .copy(&self.world, world, scene_entity.id(), *entity)
+                            .map_err(|error| SceneSpawnError::DynamicComponent {
+                                name: dynamic_component.name().to_string(),
+                                error,
+                            })?;
+                        continue;
+                    };
                     let reflect_component = type_registry
-                        .get(component_info.type_id().unwrap())
+                        .get(type_id)
                         .ok_or_else(|| SceneSpawnError::UnregisteredType {
                             std_type_name: component_info.name().to_string(),
                         })
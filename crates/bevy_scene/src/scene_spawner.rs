@@ -4,7 +4,7 @@ use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
     entity::Entity,
     event::{Event, EventCursor, Events},
-    reflect::AppTypeRegistry,
+    reflect::{AppTypeRegistry, DynamicComponentError},
     system::Resource,
     world::{Command, Mut, World},
 };
@@ -106,6 +106,15 @@ pub enum SceneSpawnError {
         /// The unregistered type.
         type_path: String,
     },
+    /// Scene contains a dynamic component that could not be written to the world.
+    #[error("could not write the dynamic component `{name}` of the scene: {error}")]
+    DynamicComponent {
+        /// The name of the dynamic component.
+        name: String,
+        /// The error returned when writing the component.
+        #[source]
+        error: DynamicComponentError,
+    },
     /// Scene contains a proxy without a represented type.
     #[error("scene contains dynamic type `{type_path}` without a represented type. consider changing this using `set_represented_type`.")]
     NoRepresentedType {
@@ -4,8 +4,8 @@ use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{
-    serde::{ReflectDeserializer, TypeRegistrationDeserializer},
-    Reflect, TypeRegistry,
+    serde::{ReflectDeserializer, ReflectSerializer, TypeRegistrationDeserializer},
+    DynamicStruct, Reflect, Struct, TypeRegistry,
 };
 use bevy_utils::HashSet;
 use serde::ser::SerializeMap;
@@ -27,6 +27,11 @@ pub const SCENE_ENTITIES: &str = "entities";
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
+/// Name of the serialized dynamic component field in an entity struct.
+///
+/// Formats that are not human-readable serialize the dynamic components as an entry of the component map
+/// with this key instead, so that entities without dynamic components keep their original layout.
+pub const ENTITY_FIELD_DYNAMIC_COMPONENTS: &str = "dynamic_components";
 
 /// Serializer for a [`DynamicScene`].
 ///
@@ -136,7 +141,21 @@ impl<'a> Serialize for EntitySerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+            state.serialize_field(
+                ENTITY_FIELD_COMPONENTS,
+                &EntityComponentsSerializer {
+                    entity: self.entity,
+                    registry: self.registry,
+                },
+            )?;
+            return state.end();
+        }
+
+        let has_dynamic_components = !self.entity.dynamic_components.is_empty();
+        let mut state =
+            serializer.serialize_struct(ENTITY_STRUCT, 1 + usize::from(has_dynamic_components))?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
             &SceneMapSerializer {
@@ -144,6 +163,103 @@ impl<'a> Serialize for EntitySerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if has_dynamic_components {
+            state.serialize_field(
+                ENTITY_FIELD_DYNAMIC_COMPONENTS,
+                &DynamicComponentsSerializer {
+                    entries: &self.entity.dynamic_components,
+                    registry: self.registry,
+                },
+            )?;
+        } else {
+            state.skip_field(ENTITY_FIELD_DYNAMIC_COMPONENTS)?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the components of an entity for formats that are not human-readable,
+/// with its dynamic components as a last [`ENTITY_FIELD_DYNAMIC_COMPONENTS`] entry.
+struct EntityComponentsSerializer<'a> {
+    entity: &'a DynamicEntity,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityComponentsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let dynamic_components = &self.entity.dynamic_components;
+        let len = self.entity.components.len() + usize::from(!dynamic_components.is_empty());
+        let mut state = serializer.serialize_map(Some(len))?;
+        for reflect in &self.entity.components {
+            state.serialize_entry(
+                reflect.get_represented_type_info().unwrap().type_path(),
+                &TypedReflectSerializer::new(&**reflect, self.registry),
+            )?;
+        }
+        if !dynamic_components.is_empty() {
+            state.serialize_entry(
+                ENTITY_FIELD_DYNAMIC_COMPONENTS,
+                &DynamicComponentsSerializer {
+                    entries: dynamic_components,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of the [dynamic components](bevy_ecs::world::World::register_dynamic_component)
+/// of an entity as a map of component name to component value.
+///
+/// Each component value is serialized as a map of field name to field value, where the values
+/// are serialized with a [`ReflectSerializer`] and must have a type registered in `registry`.
+pub struct DynamicComponentsSerializer<'a> {
+    /// The dynamic components to serialize, as pairs of component name and value.
+    pub entries: &'a [(String, DynamicStruct)],
+    /// Type registry in which the field types of the components are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DynamicComponentsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        for (name, component) in self.entries {
+            state.serialize_entry(
+                name,
+                &DynamicStructSerializer {
+                    value: component,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct DynamicStructSerializer<'a> {
+    value: &'a DynamicStruct,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for DynamicStructSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.value.field_len()))?;
+        for (index, field) in self.value.iter_fields().enumerate() {
+            state.serialize_entry(
+                self.value.name_at(index).unwrap(),
+                &ReflectSerializer::new(field, self.registry),
+            )?;
+        }
         state.end()
     }
 }
@@ -184,9 +300,10 @@ enum SceneField {
 }
 
 #[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
+#[serde(field_identifier, rename_all = "snake_case")]
 enum EntityField {
     Components,
+    DynamicComponents,
 }
 
 /// Handles scene deserialization.
@@ -344,9 +461,15 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
+        // Formats that are not human-readable may rely on the number of fields.
+        let fields: &[&str] = if deserializer.is_human_readable() {
+            &[ENTITY_FIELD_COMPONENTS, ENTITY_FIELD_DYNAMIC_COMPONENTS]
+        } else {
+            &[ENTITY_FIELD_COMPONENTS]
+        };
         deserializer.deserialize_struct(
             ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
+            fields,
             SceneEntityVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
@@ -371,8 +494,8 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        let components = seq
-            .next_element_seed(SceneMapDeserializer {
+        let (components, dynamic_components) = seq
+            .next_element_seed(EntityComponentsDeserializer {
                 registry: self.registry,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
@@ -380,6 +503,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            dynamic_components,
         })
     }
 
@@ -388,6 +512,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut dynamic_components = None;
         while let Some(key) = map.next_key()? {
             match key {
                 EntityField::Components => {
@@ -399,6 +524,16 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                         registry: self.registry,
                     })?);
                 }
+                EntityField::DynamicComponents => {
+                    if dynamic_components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_DYNAMIC_COMPONENTS));
+                    }
+
+                    dynamic_components =
+                        Some(map.next_value_seed(DynamicComponentsDeserializer {
+                            registry: self.registry,
+                        })?);
+                }
             }
         }
 
@@ -408,6 +543,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            dynamic_components: dynamic_components.unwrap_or_default(),
         })
     }
 }
@@ -479,18 +615,200 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// Deserializes the components of an entity serialized by an [`EntityComponentsSerializer`],
+/// along with its dynamic components if there is an [`ENTITY_FIELD_DYNAMIC_COMPONENTS`] entry.
+struct EntityComponentsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityComponentsDeserializer<'a> {
+    type Value = (Vec<Box<dyn Reflect>>, Vec<(String, DynamicStruct)>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(EntityComponentsVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct EntityComponentsVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for EntityComponentsVisitor<'a> {
+    type Value = (Vec<Box<dyn Reflect>>, Vec<(String, DynamicStruct)>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let components = SceneMapVisitor {
+            registry: self.registry,
+        }
+        .visit_seq(seq)?;
+        Ok((components, Vec::new()))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut entries = Vec::new();
+        let mut dynamic_components = None;
+        while let Some(type_path) = map.next_key::<String>()? {
+            if type_path == ENTITY_FIELD_DYNAMIC_COMPONENTS {
+                if dynamic_components.is_some() {
+                    return Err(Error::duplicate_field(ENTITY_FIELD_DYNAMIC_COMPONENTS));
+                }
+
+                dynamic_components = Some(map.next_value_seed(DynamicComponentsDeserializer {
+                    registry: self.registry,
+                })?);
+                continue;
+            }
+
+            let registration = self
+                .registry
+                .get_with_type_path(&type_path)
+                .ok_or_else(|| {
+                    Error::custom(format_args!("No registration found for `{type_path}`"))
+                })?;
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{type_path}`"
+                )));
+            }
+
+            entries.push(
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
+            );
+        }
+
+        Ok((entries, dynamic_components.unwrap_or_default()))
+    }
+}
+
+/// Handles deserialization of the [dynamic components](bevy_ecs::world::World::register_dynamic_component)
+/// of an entity, serialized by a [`DynamicComponentsSerializer`].
+pub struct DynamicComponentsDeserializer<'a> {
+    /// Type registry in which the field types of the components to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DynamicComponentsDeserializer<'a> {
+    type Value = Vec<(String, DynamicStruct)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DynamicComponentsVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct DynamicComponentsVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for DynamicComponentsVisitor<'a> {
+    type Value = Vec<(String, DynamicStruct)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of dynamic components")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut entries = Vec::new();
+        while let Some(name) = map.next_key::<String>()? {
+            if !added.insert(name.clone()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate dynamic component: `{name}`"
+                )));
+            }
+
+            let component = map.next_value_seed(DynamicStructDeserializer {
+                registry: self.registry,
+            })?;
+            entries.push((name, component));
+        }
+
+        Ok(entries)
+    }
+}
+
+struct DynamicStructDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DynamicStructDeserializer<'a> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DynamicStructVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct DynamicStructVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for DynamicStructVisitor<'a> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of dynamic component fields")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut component = DynamicStruct::default();
+        while let Some(name) = map.next_key::<String>()? {
+            if component.index_of(&name).is_some() {
+                return Err(Error::custom(format_args!("duplicate field: `{name}`")));
+            }
+
+            let value = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+            component.insert_boxed(name, value);
+        }
+
+        Ok(component)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ron;
     use crate::serde::{SceneDeserializer, SceneSerializer};
-    use crate::{DynamicScene, DynamicSceneBuilder};
+    use crate::{DynamicScene, DynamicSceneBuilder, Scene, SceneSpawnError};
+    use bevy_ecs::component::StorageType;
     use bevy_ecs::entity::EntityHashMap;
     use bevy_ecs::entity::{Entity, EntityMapper, MapEntities};
     use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
-    use bevy_ecs::query::{With, Without};
-    use bevy_ecs::reflect::{AppTypeRegistry, ReflectMapEntities};
-    use bevy_ecs::world::FromWorld;
-    use bevy_reflect::{Reflect, ReflectSerialize};
+    use bevy_ecs::query::{QueryBuilder, With, Without};
+    use bevy_ecs::reflect::{AppTypeRegistry, ReflectDynamicComponent, ReflectMapEntities};
+    use bevy_ecs::world::{FilteredEntityRef, FromWorld};
+    use bevy_reflect::{DynamicStruct, GetField, Reflect, ReflectSerialize};
     use bincode::Options;
     use serde::de::DeserializeSeed;
     use serde::Serialize;
@@ -616,6 +934,92 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_roundtrip_dynamic_components() {
+        fn health_prototype(world: &mut World) -> ReflectDynamicComponent {
+            let mut health = DynamicStruct::default();
+            health.insert("current", 100.0f32);
+            health.insert("max", 100.0f32);
+            world
+                .register_dynamic_component("Health", health, StorageType::Table)
+                .unwrap()
+        }
+
+        let mut world = create_world();
+        let health = health_prototype(&mut world);
+        let mut current = DynamicStruct::default();
+        current.insert("current", 40.0f32);
+        let mut entity = world.spawn(Foo(123));
+        health.insert(&mut entity, &current).unwrap();
+
+        let scene = DynamicScene::from_world(&world);
+        let expected = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+      },
+      dynamic_components: {
+        "Health": {
+          "current": {
+            "f32": 40.0,
+          },
+          "max": {
+            "f32": 100.0,
+          },
+        },
+      },
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let output = scene.serialize(&registry).unwrap();
+        assert_eq!(expected, output);
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+        drop(registry);
+
+        // Dynamic components must be registered in the destination world.
+        let mut dst_world = create_world();
+        assert!(matches!(
+            deserialized_scene.write_to_world(&mut dst_world, &mut EntityHashMap::default()),
+            Err(SceneSpawnError::DynamicComponent { .. })
+        ));
+
+        // Scenes can hold dynamic components before they are written to the destination world.
+        let registry = dst_world.resource::<AppTypeRegistry>().clone();
+        let scene = Scene::from_dynamic_scene(&deserialized_scene, &registry).unwrap();
+        let scene = scene.clone_with(&registry).unwrap();
+
+        let mut dst_world = create_world();
+        let dst_health = health_prototype(&mut dst_world);
+        for _ in 0..2 {
+            let mut entity_map = EntityHashMap::default();
+            scene
+                .write_to_world_with(&mut dst_world, &mut entity_map, &registry)
+                .unwrap();
+            deserialized_scene
+                .write_to_world(&mut dst_world, &mut entity_map)
+                .unwrap();
+        }
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut dst_world)
+            .ref_id(dst_health.id())
+            .with::<Foo>()
+            .build();
+        assert_eq!(2, query.iter(&dst_world).count());
+        for entity in query.iter(&dst_world) {
+            let value = dst_health.reflect(entity).unwrap();
+            assert_eq!(Some(&40.0), value.get_field::<f32>("current"));
+            assert_eq!(Some(&100.0), value.get_field::<f32>("max"));
+        }
+    }
+
     #[test]
     fn should_deserialize() {
         let world = create_world();
@@ -855,6 +1259,67 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_dynamic_components_in_binary_formats() {
+        let mut world = create_world();
+        let mut health = DynamicStruct::default();
+        health.insert("current", 100.0f32);
+        let health = world
+            .register_dynamic_component("Health", health, StorageType::Table)
+            .unwrap();
+        let mut current = DynamicStruct::default();
+        current.insert("current", 40.0f32);
+        let mut entity = world.spawn(Foo(123));
+        health.insert(&mut entity, &current).unwrap();
+        world.spawn(Foo(456));
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = &registry.read();
+        let scene = DynamicScene::from_world(&world);
+        let scene_serializer = SceneSerializer::new(&scene, registry);
+        let scene_deserializer = || SceneDeserializer {
+            type_registry: registry,
+        };
+
+        let postcard_bytes = postcard::to_allocvec(&scene_serializer).unwrap();
+        let mut msgpack_bytes = Vec::new();
+        scene_serializer
+            .serialize(&mut rmp_serde::Serializer::new(&mut msgpack_bytes))
+            .unwrap();
+        let bincode_bytes = bincode::serialize(&scene_serializer).unwrap();
+
+        let deserialized_scenes = [
+            scene_deserializer()
+                .deserialize(&mut postcard::Deserializer::from_bytes(&postcard_bytes))
+                .unwrap(),
+            scene_deserializer()
+                .deserialize(&mut rmp_serde::Deserializer::new(msgpack_bytes.as_slice()))
+                .unwrap(),
+            bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .deserialize_seed(scene_deserializer(), &bincode_bytes)
+                .unwrap(),
+        ];
+        for deserialized_scene in &deserialized_scenes {
+            assert_scene_eq(&scene, deserialized_scene);
+            let dynamic_components: Vec<_> = deserialized_scene
+                .entities
+                .iter()
+                .map(|entity| entity.dynamic_components.as_slice())
+                .collect();
+            assert_eq!(
+                1,
+                dynamic_components.iter().filter(|c| !c.is_empty()).count()
+            );
+            let (name, value) = dynamic_components
+                .iter()
+                .find_map(|components| components.first())
+                .unwrap();
+            assert_eq!("Health", name);
+            assert_eq!(Some(&40.0), value.get_field::<f32>("current"));
+        }
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
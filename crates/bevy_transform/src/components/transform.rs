@@ -12,7 +12,7 @@ use std::ops::Mul;
 /// * To place or move an entity, you should set its [`Transform`].
 /// * To get the global transform of an entity, you should get its [`GlobalTransform`].
 /// * To be displayed, an entity must have both a [`Transform`] and a [`GlobalTransform`].
-///   * You may use the [`TransformBundle`](crate::bundles::TransformBundle) to guarantee this.
+///   * [`GlobalTransform`] is a required component of [`Transform`], so it is inserted automatically when missing.
 ///
 /// ## [`Transform`] and [`GlobalTransform`]
 ///
@@ -38,6 +38,7 @@ use std::ops::Mul;
 #[cfg_attr(
     feature = "bevy-support",
     derive(Component, Reflect),
+    require(GlobalTransform),
     reflect(Component, Default, PartialEq)
 )]
 pub struct Transform {
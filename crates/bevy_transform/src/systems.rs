@@ -478,6 +478,8 @@ mod test {
         app.world_mut()
             .spawn(TransformBundle::IDENTITY)
             .push_children(&[child]);
+        // Apply the pending hierarchy updates first, so that they don't repair the cycle.
+        app.world_mut().flush();
         std::mem::swap(
             &mut *app.world_mut().get_mut::<Parent>(child).unwrap(),
             &mut *temp.get_mut::<Parent>(grandchild).unwrap(),
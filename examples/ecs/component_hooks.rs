@@ -13,7 +13,7 @@
 //! - Enforcing structural rules: When you have systems that depend on specific relationships
 //!     between components (like hierarchies or parent-child links) and need to maintain correctness.
 
-use bevy::ecs::component::{ComponentHooks, StorageType};
+use bevy::ecs::component::{ComponentHooks, Mutable, StorageType};
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -28,6 +28,7 @@ struct MyComponent(KeyCode);
 
 impl Component for MyComponent {
     const STORAGE_TYPE: StorageType = StorageType::Table;
+    type Mutability = Mutable;
 
     /// Hooks can also be registered during component initialisation by
     /// implementing `register_component_hooks`